@@ -0,0 +1,358 @@
+use cosmwasm_std::{
+    entry_point, to_binary, AnyMsg, Deps, DepsMut, Env, IbcMsg, MessageInfo, Order, QueryResponse,
+    RegisterInterchainAccountResponse, Reply, Response, SendInterchainTxResponse, StdError,
+    StdResult, SubMsg, SubMsgResponse, SubMsgResult,
+};
+
+use crate::ibc::PACKET_LIFETIME;
+use crate::msg::{
+    AccountInfo, AccountResponse, AdminResponse, ExecuteMsg, InstantiateMsg, ListAccountsResponse,
+    QueryMsg, TxResponse,
+};
+use crate::state::{
+    accounts, accounts_read, config, config_read, pending, txs, txs_read, AccountData, Config,
+    TxStatus,
+};
+
+pub const REGISTER_REPLY_ID: u64 = 1;
+pub const SEND_MSGS_REPLY_ID: u64 = 2;
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    let cfg = Config { admin: info.sender };
+    config(deps.storage).save(&cfg)?;
+
+    Ok(Response::new().add_attribute("action", "instantiate"))
+}
+
+#[entry_point]
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+    match msg {
+        ExecuteMsg::UpdateAdmin { admin } => handle_update_admin(deps, info, admin),
+        ExecuteMsg::Register { connection_id } => handle_register(deps, info, connection_id),
+        ExecuteMsg::SendMsgs {
+            connection_id,
+            msgs,
+            memo,
+        } => handle_send_msgs(deps, env, info, connection_id, msgs, memo),
+    }
+}
+
+pub fn handle_update_admin(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_admin: String,
+) -> StdResult<Response> {
+    // auth check
+    let mut cfg = config(deps.storage).load()?;
+    if info.sender != cfg.admin {
+        return Err(StdError::generic_err("Only admin may set new admin"));
+    }
+    cfg.admin = deps.api.addr_validate(&new_admin)?;
+    config(deps.storage).save(&cfg)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "handle_update_admin")
+        .add_attribute("new_admin", cfg.admin))
+}
+
+pub fn handle_register(
+    deps: DepsMut,
+    info: MessageInfo,
+    connection_id: String,
+) -> StdResult<Response> {
+    // auth check
+    let cfg = config(deps.storage).load()?;
+    if info.sender != cfg.admin {
+        return Err(StdError::generic_err("Only admin may register accounts"));
+    }
+    if let Some(account) = accounts_read(deps.storage).may_load(connection_id.as_bytes())? {
+        if account.channel_open {
+            return Err(StdError::generic_err(
+                "Account already registered for this connection",
+            ));
+        }
+    }
+
+    // remember the connection for the reply
+    pending(deps.storage).save(&connection_id)?;
+    let msg = IbcMsg::RegisterInterchainAccount {
+        connection_id: connection_id.clone(),
+        version: None,
+    };
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(msg, REGISTER_REPLY_ID))
+        .add_attribute("action", "handle_register")
+        .add_attribute("connection_id", connection_id))
+}
+
+pub fn handle_send_msgs(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    connection_id: String,
+    msgs: Vec<AnyMsg>,
+    memo: Option<String>,
+) -> StdResult<Response> {
+    // auth check
+    let cfg = config(deps.storage).load()?;
+    if info.sender != cfg.admin {
+        return Err(StdError::generic_err("Only admin may send messages"));
+    }
+    // ensure the account exists (not found if not registered)
+    let account = accounts(deps.storage).load(connection_id.as_bytes())?;
+    if !account.channel_open {
+        return Err(StdError::generic_err(
+            "The channel of this account is not open",
+        ));
+    }
+
+    // remember the connection for the reply
+    pending(deps.storage).save(&connection_id)?;
+    let msg = IbcMsg::SendInterchainTx {
+        connection_id,
+        msgs,
+        memo,
+        timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
+    };
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(msg, SEND_MSGS_REPLY_ID))
+        .add_attribute("action", "handle_send_msgs"))
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> StdResult<Response> {
+    match (reply.id, reply.result) {
+        (REGISTER_REPLY_ID, SubMsgResult::Ok(response)) => handle_register_reply(deps, response),
+        (SEND_MSGS_REPLY_ID, SubMsgResult::Ok(response)) => handle_send_msgs_reply(deps, response),
+        _ => Err(StdError::generic_err("invalid reply id or result")),
+    }
+}
+
+fn take_pending(deps: &mut DepsMut) -> StdResult<String> {
+    let connection_id = pending(deps.storage).load()?;
+    pending(deps.storage).remove();
+    Ok(connection_id)
+}
+
+// stores the channel that is being opened for the interchain account
+fn handle_register_reply(mut deps: DepsMut, response: SubMsgResponse) -> StdResult<Response> {
+    let data = response
+        .data
+        .ok_or_else(|| StdError::generic_err("no data in register reply"))?;
+    let RegisterInterchainAccountResponse {
+        channel_id,
+        port_id,
+    } = RegisterInterchainAccountResponse::from_reply_data(&data)?;
+    let connection_id = take_pending(&mut deps)?;
+
+    // keep the address if we re-register an account after a timeout
+    let address = accounts_read(deps.storage)
+        .may_load(connection_id.as_bytes())?
+        .and_then(|account| account.address);
+    let account = AccountData {
+        port_id,
+        channel_id: channel_id.clone(),
+        address,
+        channel_open: false,
+    };
+    accounts(deps.storage).save(connection_id.as_bytes(), &account)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "handle_register_reply")
+        .add_attribute("channel_id", channel_id))
+}
+
+// stores the sequence of the sent packet so we can match the acknowledgement
+fn handle_send_msgs_reply(mut deps: DepsMut, response: SubMsgResponse) -> StdResult<Response> {
+    let data = response
+        .data
+        .ok_or_else(|| StdError::generic_err("no data in send reply"))?;
+    let SendInterchainTxResponse { sequence } = SendInterchainTxResponse::from_reply_data(&data)?;
+    let connection_id = take_pending(&mut deps)?;
+
+    let account = accounts(deps.storage).load(connection_id.as_bytes())?;
+    txs(deps.storage, &account.channel_id).save(&sequence.to_be_bytes(), &TxStatus::Pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "handle_send_msgs_reply")
+        .add_attribute("sequence", sequence.to_string()))
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
+    match msg {
+        QueryMsg::Admin {} => to_binary(&query_admin(deps)?),
+        QueryMsg::Account { connection_id } => to_binary(&query_account(deps, connection_id)?),
+        QueryMsg::ListAccounts {} => to_binary(&query_list_accounts(deps)?),
+        QueryMsg::Tx {
+            channel_id,
+            sequence,
+        } => to_binary(&query_tx(deps, channel_id, sequence)?),
+    }
+}
+
+fn query_account(deps: Deps, connection_id: String) -> StdResult<AccountResponse> {
+    let account = accounts_read(deps.storage).load(connection_id.as_bytes())?;
+    Ok(account.into())
+}
+
+fn query_list_accounts(deps: Deps) -> StdResult<ListAccountsResponse> {
+    let accounts: StdResult<Vec<_>> = accounts_read(deps.storage)
+        .range(None, None, Order::Ascending)
+        .map(|r| {
+            let (k, account) = r?;
+            let connection_id = String::from_utf8(k)?;
+            Ok(AccountInfo::convert(connection_id, account))
+        })
+        .collect();
+    Ok(ListAccountsResponse {
+        accounts: accounts?,
+    })
+}
+
+fn query_tx(deps: Deps, channel_id: String, sequence: u64) -> StdResult<TxResponse> {
+    let status = txs_read(deps.storage, &channel_id).load(&sequence.to_be_bytes())?;
+    Ok(TxResponse { status })
+}
+
+fn query_admin(deps: Deps) -> StdResult<AdminResponse> {
+    let Config { admin } = config_read(deps.storage).load()?;
+    Ok(AdminResponse {
+        admin: admin.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{CosmosMsg, OwnedDeps};
+
+    const CREATOR: &str = "creator";
+    const CONNECTION_ID: &str = "connection-2";
+
+    fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {};
+        let info = mock_info(CREATOR, &[]);
+        let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(0, res.messages.len());
+        deps
+    }
+
+    fn reply_with_data(deps: DepsMut, id: u64, data: &[u8]) -> StdResult<Response> {
+        let result = SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(data.into()),
+        });
+        reply(deps, mock_env(), Reply { id, result })
+    }
+
+    #[test]
+    fn instantiate_works() {
+        let deps = setup();
+        let admin = query_admin(deps.as_ref()).unwrap();
+        assert_eq!(CREATOR, admin.admin.as_str());
+    }
+
+    #[test]
+    fn register_works() {
+        let mut deps = setup();
+
+        // only admin can register
+        let msg = ExecuteMsg::Register {
+            connection_id: CONNECTION_ID.to_string(),
+        };
+        let info = mock_info("someone", &[]);
+        execute(deps.as_mut(), mock_env(), info, msg.clone()).unwrap_err();
+
+        let info = mock_info(CREATOR, &[]);
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(1, res.messages.len());
+        assert_eq!(res.messages[0].id, REGISTER_REPLY_ID);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Ibc(IbcMsg::RegisterInterchainAccount {
+                connection_id: CONNECTION_ID.to_string(),
+                version: None,
+            })
+        );
+
+        // MsgRegisterInterchainAccountResponse { channel_id: "channel-7", port_id: "icacontroller-cosmos2contract" }
+        let data = b"\x0a\x09channel-7\x12\x1dicacontroller-cosmos2contract";
+        reply_with_data(deps.as_mut(), REGISTER_REPLY_ID, data).unwrap();
+
+        let account = query_account(deps.as_ref(), CONNECTION_ID.to_string()).unwrap();
+        assert_eq!(
+            account,
+            AccountResponse {
+                port_id: "icacontroller-cosmos2contract".to_string(),
+                channel_id: "channel-7".to_string(),
+                address: None,
+                channel_open: false,
+            }
+        );
+
+        // the reply must not be processed twice
+        reply_with_data(deps.as_mut(), REGISTER_REPLY_ID, data).unwrap_err();
+    }
+
+    #[test]
+    fn send_msgs_requires_open_channel() {
+        let mut deps = setup();
+        let msg = ExecuteMsg::SendMsgs {
+            connection_id: CONNECTION_ID.to_string(),
+            msgs: vec![AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", b"".to_vec())],
+            memo: None,
+        };
+
+        // not registered
+        let info = mock_info(CREATOR, &[]);
+        execute(deps.as_mut(), mock_env(), info.clone(), msg.clone()).unwrap_err();
+
+        // registered, but channel not open yet
+        let account = AccountData {
+            port_id: "icacontroller-cosmos2contract".to_string(),
+            channel_id: "channel-7".to_string(),
+            address: None,
+            channel_open: false,
+        };
+        accounts(deps.as_mut().storage)
+            .save(CONNECTION_ID.as_bytes(), &account)
+            .unwrap();
+        let err = execute(deps.as_mut(), mock_env(), info.clone(), msg.clone()).unwrap_err();
+        assert!(err.to_string().contains("not open"));
+
+        // channel open
+        let account = AccountData {
+            address: Some("cosmos1ica".to_string()),
+            channel_open: true,
+            ..account
+        };
+        accounts(deps.as_mut().storage)
+            .save(CONNECTION_ID.as_bytes(), &account)
+            .unwrap();
+        let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+        assert_eq!(1, res.messages.len());
+        assert_eq!(res.messages[0].id, SEND_MSGS_REPLY_ID);
+        assert!(matches!(
+            res.messages[0].msg,
+            CosmosMsg::Ibc(IbcMsg::SendInterchainTx { .. })
+        ));
+
+        // MsgSendTxResponse { sequence: 5 }
+        reply_with_data(deps.as_mut(), SEND_MSGS_REPLY_ID, b"\x08\x05").unwrap();
+        let tx = query_tx(deps.as_ref(), "channel-7".to_string(), 5).unwrap();
+        assert_eq!(tx.status, TxStatus::Pending);
+    }
+}
@@ -0,0 +1,421 @@
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{
+    entry_point, from_slice, DepsMut, Env, IbcAcknowledgement, IbcBasicResponse,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcDestinationCallbackMsg,
+    IbcOrder, IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg,
+    IbcReceiveResponse, IbcSourceCallbackMsg, IcaAcknowledgement, StdError, StdResult, Storage,
+};
+
+use crate::state::{accounts, txs, TxStatus};
+
+pub const ICA_VERSION: &str = "ics27-1";
+pub const ICA_ENCODING: &str = "proto3";
+pub const ICA_TX_TYPE: &str = "sdk_multi_msg";
+
+/// packets live one hour
+pub const PACKET_LIFETIME: u64 = 60 * 60;
+
+/// The channel version of interchain accounts, which is JSON encoded metadata
+/// (see https://github.com/cosmos/ibc-go/blob/v7.0.0/proto/ibc/applications/interchain_accounts/v1/metadata.proto)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IcaMetadata {
+    pub version: String,
+    pub controller_connection_id: String,
+    pub host_connection_id: String,
+    /// The interchain account address. This is set by the host chain in the handshake.
+    #[serde(default)]
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+fn parse_metadata(version: &str) -> StdResult<IcaMetadata> {
+    let metadata: IcaMetadata = from_slice(version.as_bytes())?;
+    if metadata.version != ICA_VERSION {
+        return Err(StdError::generic_err(format!(
+            "Must set version to `{}`",
+            ICA_VERSION
+        )));
+    }
+    if metadata.encoding != ICA_ENCODING || metadata.tx_type != ICA_TX_TYPE {
+        return Err(StdError::generic_err(format!(
+            "Only supports encoding `{}` and tx type `{}`",
+            ICA_ENCODING, ICA_TX_TYPE
+        )));
+    }
+    Ok(metadata)
+}
+
+#[entry_point]
+/// enforces ordering and versioning constraints
+pub fn ibc_channel_open(_deps: DepsMut, _env: Env, msg: IbcChannelOpenMsg) -> StdResult<()> {
+    let channel = msg.channel();
+
+    if channel.order != IbcOrder::Ordered {
+        return Err(StdError::generic_err("Only supports ordered channels"));
+    }
+    // an empty version is replaced by the default metadata in the controller module
+    if !channel.version.is_empty() {
+        parse_metadata(&channel.version)?;
+    }
+    if let Some(counter_version) = msg.counterparty_version() {
+        parse_metadata(counter_version)?;
+    }
+
+    Ok(())
+}
+
+#[entry_point]
+/// once it's established, we store the address of the interchain account
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> StdResult<IbcBasicResponse> {
+    // the host sets the address in the counterparty version of the OpenAck
+    let counter_version = msg
+        .counterparty_version()
+        .ok_or_else(|| StdError::generic_err("Missing counterparty version"))?;
+    let metadata = parse_metadata(counter_version)?;
+    if metadata.address.is_empty() {
+        return Err(StdError::generic_err("Missing interchain account address"));
+    }
+
+    let channel = msg.channel();
+    let channel_id = &channel.endpoint.channel_id;
+    accounts(deps.storage).update(channel.connection_id.as_bytes(), |acct| -> StdResult<_> {
+        match acct {
+            Some(mut acct) => {
+                acct.channel_id = channel_id.clone();
+                acct.address = Some(metadata.address.clone());
+                acct.channel_open = true;
+                Ok(acct)
+            }
+            None => Err(StdError::generic_err("no account to update")),
+        }
+    })?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_connect")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("address", metadata.address))
+}
+
+#[entry_point]
+/// On closed channel, mark the account as unusable until it is registered again
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> StdResult<IbcBasicResponse> {
+    let channel = msg.channel();
+
+    let channel_id = &channel.endpoint.channel_id;
+    let mut accounts = accounts(deps.storage);
+    if let Some(mut acct) = accounts.may_load(channel.connection_id.as_bytes())? {
+        if &acct.channel_id == channel_id {
+            acct.channel_open = false;
+            accounts.save(channel.connection_id.as_bytes(), &acct)?;
+        }
+    }
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_close")
+        .add_attribute("channel_id", channel_id))
+}
+
+#[entry_point]
+/// never should be called as the host never sends packets
+pub fn ibc_packet_receive(
+    _deps: DepsMut,
+    _env: Env,
+    _packet: IbcPacketReceiveMsg,
+) -> StdResult<IbcReceiveResponse> {
+    Err(StdError::generic_err(
+        "Interchain account controllers do not receive packets",
+    ))
+}
+
+#[entry_point]
+pub fn ibc_packet_ack(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> StdResult<IbcBasicResponse> {
+    let success = acknowledge_tx(deps.storage, &msg.original_packet, &msg.acknowledgement)?;
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "acknowledge_tx")
+        .add_attribute("sequence", msg.original_packet.sequence.to_string())
+        .add_attribute("success", success.to_string()))
+}
+
+#[entry_point]
+/// the ordered channel is closed by the timeout, which triggers ibc_channel_close
+pub fn ibc_packet_timeout(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> StdResult<IbcBasicResponse> {
+    let packet = msg.packet;
+    txs(deps.storage, &packet.src.channel_id)
+        .save(&packet.sequence.to_be_bytes(), &TxStatus::Timeout)?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_packet_timeout")
+        .add_attribute("sequence", packet.sequence.to_string()))
+}
+
+#[entry_point]
+/// Called by the IBC callbacks middleware for interchain txs that were sent with an
+/// `IbcCallbackRequest` for this contract in their memo. This updates the tx status
+/// just like ibc_packet_ack and ibc_packet_timeout.
+pub fn ibc_source_callback(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcSourceCallbackMsg,
+) -> StdResult<IbcBasicResponse> {
+    let res = IbcBasicResponse::new().add_attribute("action", "ibc_source_callback");
+    match msg {
+        IbcSourceCallbackMsg::Acknowledgement(msg) => {
+            let success = acknowledge_tx(deps.storage, &msg.original_packet, &msg.acknowledgement)?;
+            Ok(res
+                .add_attribute("sequence", msg.original_packet.sequence.to_string())
+                .add_attribute("success", success.to_string()))
+        }
+        IbcSourceCallbackMsg::Timeout(msg) => {
+            let packet = msg.packet;
+            txs(deps.storage, &packet.src.channel_id)
+                .save(&packet.sequence.to_be_bytes(), &TxStatus::Timeout)?;
+            Ok(res
+                .add_attribute("sequence", packet.sequence.to_string())
+                .add_attribute("success", "false"))
+        }
+    }
+}
+
+#[entry_point]
+/// never should be called as the host never sends packets
+pub fn ibc_destination_callback(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcDestinationCallbackMsg,
+) -> StdResult<IbcBasicResponse> {
+    Err(StdError::generic_err(
+        "Interchain account controllers do not receive packets",
+    ))
+}
+
+/// Stores the result of the interchain tx sent in `packet` and returns whether it succeeded
+fn acknowledge_tx(
+    storage: &mut dyn Storage,
+    packet: &IbcPacket,
+    acknowledgement: &IbcAcknowledgement,
+) -> StdResult<bool> {
+    let ack: IcaAcknowledgement = from_slice(&acknowledgement.data)?;
+    let status = match &ack {
+        IcaAcknowledgement::Result(_) => TxStatus::Success {
+            msg_responses: ack.msg_responses()?,
+        },
+        IcaAcknowledgement::Error(error) => TxStatus::Error {
+            error: error.clone(),
+        },
+    };
+    txs(storage, &packet.src.channel_id).save(&packet.sequence.to_be_bytes(), &status)?;
+    Ok(ack.is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::{instantiate, query};
+    use crate::msg::{AccountResponse, InstantiateMsg, QueryMsg, TxResponse};
+    use crate::state::AccountData;
+
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_ibc_channel_close_confirm, mock_ibc_channel_connect_ack,
+        mock_ibc_channel_open_init, mock_ibc_packet_ack, mock_ibc_packet_timeout, mock_info,
+        MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{
+        to_binary, Addr, AnyMsg, Binary, IbcAckCallbackMsg, IbcTimeoutCallbackMsg, IcaPacketData,
+        OwnedDeps,
+    };
+
+    const CREATOR: &str = "creator";
+    const CONNECTION_ID: &str = "connection-2";
+    const CHANNEL_ID: &str = "channel-7";
+    const ICA_ADDRESS: &str = "cosmos1interchainaccount";
+
+    fn metadata(address: &str) -> String {
+        let metadata = IcaMetadata {
+            version: ICA_VERSION.to_string(),
+            controller_connection_id: CONNECTION_ID.to_string(),
+            host_connection_id: "connection-0".to_string(),
+            address: address.to_string(),
+            encoding: ICA_ENCODING.to_string(),
+            tx_type: ICA_TX_TYPE.to_string(),
+        };
+        String::from_utf8(to_binary(&metadata).unwrap().0).unwrap()
+    }
+
+    fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {};
+        let info = mock_info(CREATOR, &[]);
+        instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // state after the reply of the registration
+        let account = AccountData {
+            port_id: "icacontroller-cosmos2contract".to_string(),
+            channel_id: CHANNEL_ID.to_string(),
+            address: None,
+            channel_open: false,
+        };
+        accounts(deps.as_mut().storage)
+            .save(CONNECTION_ID.as_bytes(), &account)
+            .unwrap();
+        deps
+    }
+
+    fn get_account(deps: DepsMut) -> AccountResponse {
+        let msg = QueryMsg::Account {
+            connection_id: CONNECTION_ID.to_string(),
+        };
+        from_slice(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+    }
+
+    fn get_tx(deps: DepsMut, sequence: u64) -> TxStatus {
+        let msg = QueryMsg::Tx {
+            channel_id: CHANNEL_ID.to_string(),
+            sequence,
+        };
+        let res: TxResponse = from_slice(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.status
+    }
+
+    #[test]
+    fn enforce_version_in_handshake() {
+        let mut deps = setup();
+
+        let wrong_order = mock_ibc_channel_open_init(CHANNEL_ID, IbcOrder::Unordered, "");
+        ibc_channel_open(deps.as_mut(), mock_env(), wrong_order).unwrap_err();
+
+        let wrong_version = mock_ibc_channel_open_init(CHANNEL_ID, IbcOrder::Ordered, "ics20-1");
+        ibc_channel_open(deps.as_mut(), mock_env(), wrong_version).unwrap_err();
+
+        let default_version = mock_ibc_channel_open_init(CHANNEL_ID, IbcOrder::Ordered, "");
+        ibc_channel_open(deps.as_mut(), mock_env(), default_version).unwrap();
+
+        let valid_handshake =
+            mock_ibc_channel_open_init(CHANNEL_ID, IbcOrder::Ordered, &metadata(""));
+        ibc_channel_open(deps.as_mut(), mock_env(), valid_handshake).unwrap();
+    }
+
+    #[test]
+    fn connect_and_close_works() {
+        let mut deps = setup();
+        assert!(!get_account(deps.as_mut()).channel_open);
+
+        // the address is required
+        let handshake = mock_ibc_channel_connect_ack(CHANNEL_ID, IbcOrder::Ordered, &metadata(""));
+        ibc_channel_connect(deps.as_mut(), mock_env(), handshake).unwrap_err();
+
+        let handshake =
+            mock_ibc_channel_connect_ack(CHANNEL_ID, IbcOrder::Ordered, &metadata(ICA_ADDRESS));
+        ibc_channel_connect(deps.as_mut(), mock_env(), handshake).unwrap();
+        let account = get_account(deps.as_mut());
+        assert_eq!(account.address.as_deref(), Some(ICA_ADDRESS));
+        assert!(account.channel_open);
+
+        let close = mock_ibc_channel_close_confirm(CHANNEL_ID, IbcOrder::Ordered, ICA_VERSION);
+        ibc_channel_close(deps.as_mut(), mock_env(), close).unwrap();
+        let account = get_account(deps.as_mut());
+        // the address is kept for re-registration
+        assert_eq!(account.address.as_deref(), Some(ICA_ADDRESS));
+        assert!(!account.channel_open);
+    }
+
+    #[test]
+    fn ack_and_timeout_update_tx_status() {
+        let mut deps = setup();
+        let packet = IcaPacketData::execute_tx(
+            &[AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", b"".to_vec())],
+            "",
+        );
+
+        // TxMsgData { msg_responses: [Any { type_url: "/cosmos.bank.v1beta1.MsgSendResponse" }] }
+        let tx_msg_data =
+            Binary::from(b"\x12\x26\x0a\x24/cosmos.bank.v1beta1.MsgSendResponse".to_vec());
+        let ack =
+            IbcAcknowledgement::encode_json(&IcaAcknowledgement::Result(tx_msg_data)).unwrap();
+        // mock packets have sequence 29
+        let msg = mock_ibc_packet_ack(CHANNEL_ID, &packet, ack).unwrap();
+        ibc_packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(
+            get_tx(deps.as_mut(), 29),
+            TxStatus::Success {
+                msg_responses: vec![AnyMsg::new(
+                    "/cosmos.bank.v1beta1.MsgSendResponse",
+                    b"".to_vec()
+                )]
+            }
+        );
+
+        let error = "ABCI code: 5: error handling packet: see events for details".to_string();
+        let ack =
+            IbcAcknowledgement::encode_json(&IcaAcknowledgement::Error(error.clone())).unwrap();
+        let msg = mock_ibc_packet_ack(CHANNEL_ID, &packet, ack).unwrap();
+        ibc_packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(get_tx(deps.as_mut(), 29), TxStatus::Error { error });
+
+        let msg = mock_ibc_packet_timeout(CHANNEL_ID, &packet).unwrap();
+        ibc_packet_timeout(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(get_tx(deps.as_mut(), 29), TxStatus::Timeout);
+    }
+
+    #[test]
+    fn source_callbacks_update_tx_status() {
+        let mut deps = setup();
+        let packet = IcaPacketData::execute_tx(
+            &[AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", b"".to_vec())],
+            "",
+        );
+        let relayer = Addr::unchecked("relayer");
+
+        let ack = IbcAcknowledgement::encode_json(&IcaAcknowledgement::Result(Binary::default()))
+            .unwrap();
+        let original_packet = mock_ibc_packet_ack(CHANNEL_ID, &packet, ack.clone())
+            .unwrap()
+            .original_packet;
+        let msg = IbcSourceCallbackMsg::Acknowledgement(IbcAckCallbackMsg::new(
+            ack,
+            original_packet.clone(),
+            relayer.clone(),
+        ));
+        let res = ibc_source_callback(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(res.attributes[2].value, "true");
+        assert_eq!(
+            get_tx(deps.as_mut(), 29),
+            TxStatus::Success {
+                msg_responses: vec![]
+            }
+        );
+
+        let msg =
+            IbcSourceCallbackMsg::Timeout(IbcTimeoutCallbackMsg::new(original_packet, relayer));
+        ibc_source_callback(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(get_tx(deps.as_mut(), 29), TxStatus::Timeout);
+    }
+
+    #[test]
+    fn destination_callback_fails() {
+        let mut deps = setup();
+        let ack = IbcAcknowledgement::new(b"{}");
+        let packet = mock_ibc_packet_ack(CHANNEL_ID, b"{}", ack.clone())
+            .unwrap()
+            .original_packet;
+        let msg = IbcDestinationCallbackMsg::new(packet, ack);
+        ibc_destination_callback(deps.as_mut(), mock_env(), msg).unwrap_err();
+    }
+}
@@ -0,0 +1,4 @@
+pub mod contract;
+pub mod ibc;
+pub mod msg;
+pub mod state;
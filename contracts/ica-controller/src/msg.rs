@@ -0,0 +1,99 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::AnyMsg;
+
+use crate::state::{AccountData, TxStatus};
+
+/// This needs no info. Owner of the contract is whoever signed the InstantiateMsg.
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Changes the admin
+    UpdateAdmin { admin: String },
+    /// Registers an interchain account on the chain at the other end of the connection.
+    /// Can also be used to reopen the channel of an account after a timeout.
+    Register { connection_id: String },
+    /// Executes the given messages with the interchain account on the chain at the other
+    /// end of the connection. The account must have been registered before.
+    SendMsgs {
+        connection_id: String,
+        /// Protobuf encoded Cosmos SDK messages
+        msgs: Vec<AnyMsg>,
+        memo: Option<String>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Returns current admin
+    #[returns(AdminResponse)]
+    Admin {},
+    // Shows all registered accounts
+    #[returns(ListAccountsResponse)]
+    ListAccounts {},
+    // Get account for one connection
+    #[returns(AccountResponse)]
+    Account { connection_id: String },
+    // Get the status of a transaction, identified by the packet sent over the account's channel
+    #[returns(TxResponse)]
+    Tx { channel_id: String, sequence: u64 },
+}
+
+#[cw_serde]
+pub struct AdminResponse {
+    pub admin: String,
+}
+
+#[cw_serde]
+pub struct ListAccountsResponse {
+    pub accounts: Vec<AccountInfo>,
+}
+
+#[cw_serde]
+pub struct AccountInfo {
+    pub connection_id: String,
+    pub port_id: String,
+    pub channel_id: String,
+    /// The address of the interchain account. This is empty until the channel handshake is complete.
+    pub address: Option<String>,
+    pub channel_open: bool,
+}
+
+impl AccountInfo {
+    pub fn convert(connection_id: String, input: AccountData) -> Self {
+        AccountInfo {
+            connection_id,
+            port_id: input.port_id,
+            channel_id: input.channel_id,
+            address: input.address,
+            channel_open: input.channel_open,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct AccountResponse {
+    pub port_id: String,
+    pub channel_id: String,
+    /// The address of the interchain account. This is empty until the channel handshake is complete.
+    pub address: Option<String>,
+    pub channel_open: bool,
+}
+
+impl From<AccountData> for AccountResponse {
+    fn from(input: AccountData) -> Self {
+        AccountResponse {
+            port_id: input.port_id,
+            channel_id: input.channel_id,
+            address: input.address,
+            channel_open: input.channel_open,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct TxResponse {
+    pub status: TxStatus,
+}
@@ -0,0 +1,80 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{Addr, AnyMsg, Storage};
+use cosmwasm_storage::{
+    bucket, bucket_read, singleton, singleton_read, Bucket, ReadonlyBucket, ReadonlySingleton,
+    Singleton,
+};
+
+pub const KEY_CONFIG: &[u8] = b"config";
+pub const KEY_PENDING: &[u8] = b"pending";
+pub const PREFIX_ACCOUNTS: &[u8] = b"accounts";
+pub const PREFIX_TXS: &[u8] = b"txs";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub admin: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AccountData {
+    /// The controller port of this contract
+    pub port_id: String,
+    /// The channel used to control the interchain account
+    pub channel_id: String,
+    /// The address of the interchain account on the host chain.
+    /// This is set once the channel handshake is complete.
+    ///
+    /// Since we do not have a way to validate the remote address format, this
+    /// must not be of type `Addr`.
+    pub address: Option<String>,
+    /// Interchain accounts use ordered channels, which are closed on a timeout.
+    /// The account must be registered again in that case.
+    pub channel_open: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TxStatus {
+    /// The packet was sent but we did not get an acknowledgement yet
+    Pending,
+    Success {
+        msg_responses: Vec<AnyMsg>,
+    },
+    Error {
+        error: String,
+    },
+    Timeout,
+}
+
+/// accounts is lookup of connection_id to interchain account
+pub fn accounts(storage: &mut dyn Storage) -> Bucket<AccountData> {
+    bucket(storage, PREFIX_ACCOUNTS)
+}
+
+pub fn accounts_read(storage: &dyn Storage) -> ReadonlyBucket<AccountData> {
+    bucket_read(storage, PREFIX_ACCOUNTS)
+}
+
+/// txs is lookup of packet sequence to transaction status for the given channel
+pub fn txs<'a>(storage: &'a mut dyn Storage, channel_id: &str) -> Bucket<'a, TxStatus> {
+    Bucket::multilevel(storage, &[PREFIX_TXS, channel_id.as_bytes()])
+}
+
+pub fn txs_read<'a>(storage: &'a dyn Storage, channel_id: &str) -> ReadonlyBucket<'a, TxStatus> {
+    ReadonlyBucket::multilevel(storage, &[PREFIX_TXS, channel_id.as_bytes()])
+}
+
+pub fn config(storage: &mut dyn Storage) -> Singleton<Config> {
+    singleton(storage, KEY_CONFIG)
+}
+
+pub fn config_read(storage: &dyn Storage) -> ReadonlySingleton<Config> {
+    singleton_read(storage, KEY_CONFIG)
+}
+
+/// The connection of the interchain accounts message that is waiting for its reply
+pub fn pending(storage: &mut dyn Storage) -> Singleton<String> {
+    singleton(storage, KEY_PENDING)
+}
@@ -0,0 +1,276 @@
+//! This integration test tries to run and call the generated wasm.
+//! It depends on a Wasm build being available, which you can create with `cargo wasm`.
+//! Then running `cargo integration-test` will validate we can properly call into that generated Wasm.
+//!
+//! You can easily convert unit tests to integration tests.
+//! 1. First copy them over verbatum,
+//! 2. Then change
+//!      let mut deps = mock_dependencies(20, &[]);
+//!    to
+//!      let mut deps = mock_instance(WASM, &[]);
+//! 3. If you access raw storage, where ever you see something like:
+//!      deps.storage.get(CONFIG_KEY).expect("no data stored");
+//!    replace it with:
+//!      deps.with_storage(|store| {
+//!          let data = store.get(CONFIG_KEY).expect("no data stored");
+//!          //...
+//!      });
+//! 4. Anywhere you see query(&deps, ...) you must replace it with query(&mut deps, ...)
+
+use cosmwasm_std::testing::{
+    mock_ibc_channel_connect_ack, mock_ibc_channel_open_init, mock_ibc_packet_ack,
+    mock_ibc_packet_timeout,
+};
+use cosmwasm_std::{
+    Addr, AnyMsg, Binary, ContractResult, CosmosMsg, IbcAckCallbackMsg, IbcAcknowledgement,
+    IbcBasicResponse, IbcCallbackRequest, IbcDestinationCallbackMsg, IbcMsg, IbcOrder,
+    IbcSourceCallbackMsg, IbcSrcCallback, IbcTimeoutCallbackMsg, IcaAcknowledgement, IcaPacketData,
+    Reply, Response, SubMsgResponse, SubMsgResult,
+};
+use cosmwasm_vm::testing::{
+    execute, ibc_channel_connect, ibc_channel_open, ibc_destination_callback, ibc_packet_ack,
+    ibc_packet_timeout, ibc_source_callback, instantiate, mock_env, mock_info, mock_instance,
+    query, reply, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_vm::{from_slice, Instance};
+
+use ica_controller::contract::{REGISTER_REPLY_ID, SEND_MSGS_REPLY_ID};
+use ica_controller::ibc::{IcaMetadata, ICA_ENCODING, ICA_TX_TYPE, ICA_VERSION};
+use ica_controller::msg::{
+    AccountResponse, AdminResponse, ExecuteMsg, InstantiateMsg, QueryMsg, TxResponse,
+};
+use ica_controller::state::TxStatus;
+
+// This line will test the output of cargo wasm
+static WASM: &[u8] = include_bytes!("../target/wasm32-unknown-unknown/release/ica_controller.wasm");
+
+const CREATOR: &str = "creator";
+const CONNECTION_ID: &str = "connection-2";
+const CHANNEL_ID: &str = "channel-7";
+const ICA_ADDRESS: &str = "cosmos1interchainaccount";
+
+const DESERIALIZATION_LIMIT: usize = 20_000;
+
+fn setup() -> Instance<MockApi, MockStorage, MockQuerier> {
+    let mut deps = mock_instance(WASM, &[]);
+    let msg = InstantiateMsg {};
+    let info = mock_info(CREATOR, &[]);
+    let res: Response = instantiate(&mut deps, mock_env(), info, msg).unwrap();
+    assert_eq!(0, res.messages.len());
+    deps
+}
+
+fn metadata(address: &str) -> String {
+    let metadata = IcaMetadata {
+        version: ICA_VERSION.to_string(),
+        controller_connection_id: CONNECTION_ID.to_string(),
+        host_connection_id: "connection-0".to_string(),
+        address: address.to_string(),
+        encoding: ICA_ENCODING.to_string(),
+        tx_type: ICA_TX_TYPE.to_string(),
+    };
+    String::from_utf8(cosmwasm_std::to_vec(&metadata).unwrap()).unwrap()
+}
+
+fn reply_with_data(deps: &mut Instance<MockApi, MockStorage, MockQuerier>, id: u64, data: &[u8]) {
+    let result = SubMsgResult::Ok(SubMsgResponse {
+        events: vec![],
+        data: Some(Binary::from(data)),
+    });
+    let _: Response = reply(deps, mock_env(), Reply { id, result }).unwrap();
+}
+
+// register will run through the registration and the entire handshake to set up
+// an account (tested in detail in `proper_registration_flow`)
+fn register(deps: &mut Instance<MockApi, MockStorage, MockQuerier>) {
+    let msg = ExecuteMsg::Register {
+        connection_id: CONNECTION_ID.to_string(),
+    };
+    let res: Response = execute(deps, mock_env(), mock_info(CREATOR, &[]), msg).unwrap();
+    assert_eq!(1, res.messages.len());
+    assert_eq!(REGISTER_REPLY_ID, res.messages[0].id);
+
+    // MsgRegisterInterchainAccountResponse { channel_id: "channel-7", port_id: "icacontroller-cosmos2contract" }
+    reply_with_data(
+        deps,
+        REGISTER_REPLY_ID,
+        b"\x0a\x09channel-7\x12\x1dicacontroller-cosmos2contract",
+    );
+
+    let handshake_open = mock_ibc_channel_open_init(CHANNEL_ID, IbcOrder::Ordered, &metadata(""));
+    ibc_channel_open(deps, mock_env(), handshake_open).unwrap();
+
+    let handshake_connect =
+        mock_ibc_channel_connect_ack(CHANNEL_ID, IbcOrder::Ordered, &metadata(ICA_ADDRESS));
+    let res: IbcBasicResponse = ibc_channel_connect(deps, mock_env(), handshake_connect).unwrap();
+    assert_eq!(0, res.messages.len());
+}
+
+fn get_account(deps: &mut Instance<MockApi, MockStorage, MockQuerier>) -> AccountResponse {
+    let msg = QueryMsg::Account {
+        connection_id: CONNECTION_ID.into(),
+    };
+    let r = query(deps, mock_env(), msg).unwrap();
+    from_slice(&r, DESERIALIZATION_LIMIT).unwrap()
+}
+
+fn get_tx(deps: &mut Instance<MockApi, MockStorage, MockQuerier>, sequence: u64) -> TxStatus {
+    let msg = QueryMsg::Tx {
+        channel_id: CHANNEL_ID.into(),
+        sequence,
+    };
+    let r = query(deps, mock_env(), msg).unwrap();
+    let res: TxResponse = from_slice(&r, DESERIALIZATION_LIMIT).unwrap();
+    res.status
+}
+
+#[test]
+fn instantiate_works() {
+    let mut deps = setup();
+    let r = query(&mut deps, mock_env(), QueryMsg::Admin {}).unwrap();
+    let admin: AdminResponse = from_slice(&r, DESERIALIZATION_LIMIT).unwrap();
+    assert_eq!(CREATOR, admin.admin.as_str());
+}
+
+#[test]
+fn proper_registration_flow() {
+    let mut deps = setup();
+    register(&mut deps);
+
+    let account = get_account(&mut deps);
+    assert_eq!(account.port_id, "icacontroller-cosmos2contract");
+    assert_eq!(account.channel_id, CHANNEL_ID);
+    assert_eq!(account.address.as_deref(), Some(ICA_ADDRESS));
+    assert!(account.channel_open);
+}
+
+#[test]
+fn send_msgs_and_ack() {
+    let mut deps = setup();
+    register(&mut deps);
+
+    let msgs = vec![AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", b"".to_vec())];
+    let execute_msg = ExecuteMsg::SendMsgs {
+        connection_id: CONNECTION_ID.into(),
+        msgs: msgs.clone(),
+        memo: None,
+    };
+    let info = mock_info(CREATOR, &[]);
+    let res: Response = execute(&mut deps, mock_env(), info, execute_msg).unwrap();
+    assert_eq!(1, res.messages.len());
+    match &res.messages[0].msg {
+        CosmosMsg::Ibc(IbcMsg::SendInterchainTx {
+            connection_id,
+            msgs: sent,
+            ..
+        }) => {
+            assert_eq!(connection_id, CONNECTION_ID);
+            assert_eq!(sent, &msgs);
+        }
+        o => panic!("Unexpected message: {:?}", o),
+    };
+
+    // MsgSendTxResponse { sequence: 29 }, the sequence of mock packets
+    reply_with_data(&mut deps, SEND_MSGS_REPLY_ID, b"\x08\x1d");
+    assert_eq!(get_tx(&mut deps, 29), TxStatus::Pending);
+
+    let packet = IcaPacketData::execute_tx(&msgs, "");
+    let ack =
+        IbcAcknowledgement::encode_json(&IcaAcknowledgement::Result(Binary::default())).unwrap();
+    let msg = mock_ibc_packet_ack(CHANNEL_ID, &packet, ack).unwrap();
+    let res: IbcBasicResponse = ibc_packet_ack(&mut deps, mock_env(), msg).unwrap();
+    assert_eq!(0, res.messages.len());
+    assert_eq!(
+        get_tx(&mut deps, 29),
+        TxStatus::Success {
+            msg_responses: vec![]
+        }
+    );
+}
+
+#[test]
+fn send_msgs_and_timeout() {
+    let mut deps = setup();
+    register(&mut deps);
+
+    let msgs = vec![AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", b"".to_vec())];
+    let execute_msg = ExecuteMsg::SendMsgs {
+        connection_id: CONNECTION_ID.into(),
+        msgs: msgs.clone(),
+        memo: Some("hello".to_string()),
+    };
+    let info = mock_info(CREATOR, &[]);
+    let _: Response = execute(&mut deps, mock_env(), info, execute_msg).unwrap();
+    reply_with_data(&mut deps, SEND_MSGS_REPLY_ID, b"\x08\x1d");
+
+    let packet = IcaPacketData::execute_tx(&msgs, "hello");
+    let msg = mock_ibc_packet_timeout(CHANNEL_ID, &packet).unwrap();
+    let res: IbcBasicResponse = ibc_packet_timeout(&mut deps, mock_env(), msg).unwrap();
+    assert_eq!(0, res.messages.len());
+    assert_eq!(get_tx(&mut deps, 29), TxStatus::Timeout);
+}
+
+#[test]
+fn send_msgs_with_source_callback() {
+    let mut deps = setup();
+    register(&mut deps);
+
+    let callback = IbcCallbackRequest::source(IbcSrcCallback {
+        address: mock_env().contract.address,
+        gas_limit: None,
+    });
+    let memo = callback.to_memo().unwrap();
+    let msgs = vec![AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", b"".to_vec())];
+    let execute_msg = ExecuteMsg::SendMsgs {
+        connection_id: CONNECTION_ID.into(),
+        msgs: msgs.clone(),
+        memo: Some(memo.clone()),
+    };
+    let info = mock_info(CREATOR, &[]);
+    let _: Response = execute(&mut deps, mock_env(), info, execute_msg).unwrap();
+    reply_with_data(&mut deps, SEND_MSGS_REPLY_ID, b"\x08\x1d");
+
+    let ack =
+        IbcAcknowledgement::encode_json(&IcaAcknowledgement::Error("failed".to_string())).unwrap();
+    let packet = IcaPacketData::execute_tx(&msgs, &memo);
+    let original_packet = mock_ibc_packet_ack(CHANNEL_ID, &packet, ack.clone())
+        .unwrap()
+        .original_packet;
+    let msg = IbcSourceCallbackMsg::Acknowledgement(IbcAckCallbackMsg::new(
+        ack,
+        original_packet.clone(),
+        Addr::unchecked("relayer"),
+    ));
+    let res: IbcBasicResponse = ibc_source_callback(&mut deps, mock_env(), msg).unwrap();
+    assert_eq!(0, res.messages.len());
+    assert_eq!(
+        get_tx(&mut deps, 29),
+        TxStatus::Error {
+            error: "failed".to_string()
+        }
+    );
+
+    let msg = IbcSourceCallbackMsg::Timeout(IbcTimeoutCallbackMsg::new(
+        original_packet,
+        Addr::unchecked("relayer"),
+    ));
+    let res: IbcBasicResponse = ibc_source_callback(&mut deps, mock_env(), msg).unwrap();
+    assert_eq!(0, res.messages.len());
+    assert_eq!(get_tx(&mut deps, 29), TxStatus::Timeout);
+}
+
+#[test]
+fn destination_callback_fails() {
+    let mut deps = setup();
+
+    let ack = IbcAcknowledgement::new(b"{}");
+    let packet = mock_ibc_packet_ack(CHANNEL_ID, b"{}", ack.clone())
+        .unwrap()
+        .original_packet;
+    let msg = IbcDestinationCallbackMsg::new(packet, ack);
+    let res: ContractResult<IbcBasicResponse> =
+        ibc_destination_callback(&mut deps, mock_env(), msg);
+    assert!(res
+        .unwrap_err()
+        .contains("Interchain account controllers do not receive packets"));
+}
@@ -1,7 +1,9 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
+use crate::errors::CoinFromStrError;
 use crate::math::Uint128;
 
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, JsonSchema)]
@@ -19,6 +21,48 @@ impl Coin {
     }
 }
 
+impl FromStr for Coin {
+    type Err = CoinFromStrError;
+
+    /// Parses a coin in the format `<amount><denom>`, e.g. `"123ucosm"`,
+    /// which is the inverse of the [`Display`](fmt::Display) implementation.
+    ///
+    /// The denom must be valid according to the Cosmos SDK, i.e. match
+    /// `[a-zA-Z][a-zA-Z0-9/:._-]{2,127}`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(CoinFromStrError::MissingAmount);
+        }
+
+        let pos = s
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or(CoinFromStrError::MissingDenom)?;
+        let (amount, denom) = s.split_at(pos);
+
+        if amount.is_empty() {
+            return Err(CoinFromStrError::MissingAmount);
+        }
+        if !is_valid_denom(denom) {
+            return Err(CoinFromStrError::InvalidDenom(denom.to_string()));
+        }
+
+        Ok(Coin {
+            amount: amount.parse::<u128>()?.into(),
+            denom: denom.to_string(),
+        })
+    }
+}
+
+/// Checks a denom against the rule of the Cosmos SDK: `[a-zA-Z][a-zA-Z0-9/:._-]{2,127}`
+/// (see https://github.com/cosmos/cosmos-sdk/blob/v0.47.0/types/coin.go#L838).
+fn is_valid_denom(denom: &str) -> bool {
+    let mut chars = denom.chars();
+    let first_is_letter = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic());
+    first_is_letter
+        && (3..=128).contains(&denom.len())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | ':' | '.' | '_' | '-'))
+}
+
 impl fmt::Display for Coin {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // We use the formatting without a space between amount and denom,
@@ -99,6 +143,103 @@ mod tests {
         assert_eq!(a.to_string(), "123ucosm");
     }
 
+    #[test]
+    fn coin_from_str_works() {
+        assert_eq!(Coin::from_str("123ucosm").unwrap(), coin(123, "ucosm"));
+        assert_eq!(Coin::from_str("0ucosm").unwrap(), coin(0, "ucosm"));
+        assert_eq!(
+            Coin::from_str("340282366920938463463374607431768211455ucosm").unwrap(),
+            coin(u128::MAX, "ucosm")
+        );
+        // denoms can contain digits and other characters after the first one
+        assert_eq!(
+            Coin::from_str("5ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2")
+                .unwrap(),
+            coin(
+                5,
+                "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+            )
+        );
+
+        // round trip
+        let original = coin(42, "uatom");
+        assert_eq!(Coin::from_str(&original.to_string()).unwrap(), original);
+    }
+
+    #[test]
+    fn coin_from_str_errors() {
+        assert_eq!(
+            Coin::from_str("123").unwrap_err(),
+            CoinFromStrError::MissingDenom
+        );
+        assert_eq!(
+            Coin::from_str("").unwrap_err(),
+            CoinFromStrError::MissingAmount
+        );
+        assert_eq!(
+            Coin::from_str("ucosm").unwrap_err(),
+            CoinFromStrError::MissingAmount
+        );
+        assert_eq!(
+            Coin::from_str("-1ucosm").unwrap_err(),
+            CoinFromStrError::MissingAmount
+        );
+        assert!(matches!(
+            Coin::from_str("340282366920938463463374607431768211456ucosm").unwrap_err(),
+            CoinFromStrError::InvalidAmount(_)
+        ));
+    }
+
+    #[test]
+    fn coin_from_str_rejects_invalid_denoms() {
+        // decimal amounts
+        assert_eq!(
+            Coin::from_str("1.5uatom").unwrap_err(),
+            CoinFromStrError::InvalidDenom(".5uatom".to_string())
+        );
+        // spaces
+        assert_eq!(
+            Coin::from_str("100 uatom").unwrap_err(),
+            CoinFromStrError::InvalidDenom(" uatom".to_string())
+        );
+        assert_eq!(
+            Coin::from_str("100uatom ").unwrap_err(),
+            CoinFromStrError::InvalidDenom("uatom ".to_string())
+        );
+        // trailing comma
+        assert_eq!(
+            Coin::from_str("100uatom,").unwrap_err(),
+            CoinFromStrError::InvalidDenom("uatom,".to_string())
+        );
+        // too short and too long
+        assert_eq!(
+            Coin::from_str("1ab").unwrap_err(),
+            CoinFromStrError::InvalidDenom("ab".to_string())
+        );
+        let long_denom = format!("u{}", "a".repeat(128));
+        assert_eq!(
+            Coin::from_str(&format!("1{}", long_denom)).unwrap_err(),
+            CoinFromStrError::InvalidDenom(long_denom)
+        );
+        // non-ascii characters
+        assert_eq!(
+            Coin::from_str("1uatöm").unwrap_err(),
+            CoinFromStrError::InvalidDenom("uatöm".to_string())
+        );
+
+        // the limits are inclusive
+        let max_denom = format!("u{}", "a".repeat(127));
+        assert_eq!(
+            Coin::from_str(&format!("1{}", max_denom)).unwrap(),
+            coin(1, max_denom)
+        );
+        assert_eq!(Coin::from_str("1abc").unwrap(), coin(1, "abc"));
+        assert_eq!(
+            Coin::from_str("1factory/osmo1abc:x.y_z-0").unwrap(),
+            coin(1, "factory/osmo1abc:x.y_z-0")
+        );
+    }
+
     #[test]
     fn coin_works() {
         let a = coin(123, "ucosm");
@@ -0,0 +1,429 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::{CoinsError, OverflowError, OverflowOperation, StdResult};
+use crate::{Coin, Uint128};
+
+/// A collection of coins, similar to Cosmos SDK's `sdk.Coins` struct.
+///
+/// Differently from `sdk.Coins`, which is a vector of `sdk.Coin`, here we
+/// implement Coins as a BTreeMap that maps from coin denoms to `Coin`.
+/// This has a number of advantages:
+///
+/// - coins are naturally sorted alphabetically by denom
+/// - denoms are guaranteed to be unique
+/// - cheaper for searching/inserting/deleting: O(log(n)) compared to O(n)
+///
+/// Coins with a zero amount are never stored.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct Coins(BTreeMap<String, Coin>);
+
+/// Casting a Vec<Coin> to Coins.
+/// The Vec can be out of order, but must not contain duplicate denoms or zero amounts.
+impl TryFrom<Vec<Coin>> for Coins {
+    type Error = CoinsError;
+
+    fn try_from(vec: Vec<Coin>) -> Result<Self, CoinsError> {
+        let mut map = BTreeMap::new();
+        for coin in vec {
+            if coin.amount.is_zero() {
+                return Err(CoinsError::ZeroAmount { denom: coin.denom });
+            }
+
+            if map.contains_key(&coin.denom) {
+                return Err(CoinsError::DuplicateDenom { denom: coin.denom });
+            }
+            map.insert(coin.denom.clone(), coin);
+        }
+
+        Ok(Self(map))
+    }
+}
+
+impl TryFrom<&[Coin]> for Coins {
+    type Error = CoinsError;
+
+    fn try_from(slice: &[Coin]) -> Result<Self, CoinsError> {
+        slice.to_vec().try_into()
+    }
+}
+
+impl TryFrom<Coin> for Coins {
+    type Error = CoinsError;
+
+    fn try_from(coin: Coin) -> Result<Self, CoinsError> {
+        vec![coin].try_into()
+    }
+}
+
+impl From<Coins> for Vec<Coin> {
+    fn from(coins: Coins) -> Self {
+        coins.into_vec()
+    }
+}
+
+/// Parses a comma separated list of coins like `"100uatom,5ujuno"`.
+/// The empty string is parsed as an empty collection.
+impl FromStr for Coins {
+    type Err = CoinsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+
+        s.split(',')
+            .map(Coin::from_str)
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+    }
+}
+
+impl fmt::Display for Coins {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = self
+            .0
+            .values()
+            .map(|coin| coin.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}", s)
+    }
+}
+
+impl Coins {
+    /// Conversion to Vec<Coin>, while NOT consuming the original object.
+    ///
+    /// The coins are sorted by denom.
+    pub fn to_vec(&self) -> Vec<Coin> {
+        self.0.values().cloned().collect()
+    }
+
+    /// Conversion to Vec<Coin>, consuming the original object.
+    ///
+    /// The coins are sorted by denom.
+    pub fn into_vec(self) -> Vec<Coin> {
+        self.0.into_values().collect()
+    }
+
+    /// Returns the number of different denoms in this collection.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if this collection contains no coins.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the denoms as a vector of strings.
+    /// The vector is guaranteed to not contain duplicates and sorted alphabetically.
+    pub fn denoms(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+
+    /// Returns the amount of the given denom or zero if the denom is not present.
+    pub fn amount_of(&self, denom: &str) -> Uint128 {
+        self.0
+            .get(denom)
+            .map(|coin| coin.amount)
+            .unwrap_or_else(Uint128::zero)
+    }
+
+    /// Returns an iterator over the coins, sorted by denom.
+    pub fn iter(&self) -> impl Iterator<Item = &Coin> {
+        self.0.values()
+    }
+
+    /// Adds the given coin to this collection.
+    ///
+    /// Adding a zero amount is a no-op. Returns an error if the amount of the denom
+    /// overflows, in which case the collection is not modified.
+    pub fn add(&mut self, coin: Coin) -> StdResult<()> {
+        if coin.amount.is_zero() {
+            return Ok(());
+        }
+
+        match self.0.get_mut(&coin.denom) {
+            Some(existing) => {
+                existing.amount = existing.amount.checked_add(coin.amount)?;
+            }
+            None => {
+                self.0.insert(coin.denom.clone(), coin);
+            }
+        }
+        Ok(())
+    }
+
+    /// Subtracts the given coin from this collection.
+    ///
+    /// Denoms whose amount reaches zero are removed. Returns an error if the
+    /// collection does not contain enough of the denom, in which case it is not modified.
+    pub fn sub(&mut self, coin: Coin) -> StdResult<()> {
+        if coin.amount.is_zero() {
+            return Ok(());
+        }
+
+        match self.0.get_mut(&coin.denom) {
+            Some(existing) => {
+                existing.amount = existing.amount.checked_sub(coin.amount)?;
+                if existing.amount.is_zero() {
+                    self.0.remove(&coin.denom);
+                }
+                Ok(())
+            }
+            None => Err(OverflowError::new(OverflowOperation::Sub, 0, coin.amount).into()),
+        }
+    }
+}
+
+impl IntoIterator for Coins {
+    type Item = Coin;
+    type IntoIter = std::collections::btree_map::IntoValues<String, Coin>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coin, CoinFromStrError, StdError};
+
+    /// Sort a Vec<Coin> by denom alphabetically
+    fn sort_by_denom(vec: &mut [Coin]) {
+        vec.sort_by(|a, b| a.denom.cmp(&b.denom));
+    }
+
+    /// Returns a mockup Vec<Coin>. In this example, the coins are not in order
+    fn mock_vec() -> Vec<Coin> {
+        vec![
+            coin(12345, "uatom"),
+            coin(69420, "ibc/1234ABCD"),
+            coin(88888, "factory/osmo1234abcd/subdenom"),
+        ]
+    }
+
+    /// Return a mockup Coins that contains the same coins as in `mock_vec`
+    fn mock_coins() -> Coins {
+        let mut coins = Coins::default();
+        for coin in mock_vec() {
+            coins.add(coin).unwrap();
+        }
+        coins
+    }
+
+    #[test]
+    fn converting_vec() {
+        let mut vec = mock_vec();
+        let coins = mock_coins();
+
+        // &[Coin] --> Coins
+        assert_eq!(Coins::try_from(vec.as_slice()).unwrap(), coins);
+        // Vec<Coin> --> Coins
+        assert_eq!(Coins::try_from(vec.clone()).unwrap(), coins);
+
+        sort_by_denom(&mut vec);
+
+        // &Coins --> Vec<Coins>
+        // NOTE: the returned vec should be sorted
+        assert_eq!(coins.to_vec(), vec);
+        // Coins --> Vec<Coins>
+        // NOTE: the returned vec should be sorted
+        assert_eq!(coins.into_vec(), vec);
+    }
+
+    #[test]
+    fn converting_vec_rejects_duplicates_and_zero_amounts() {
+        let mut vec = mock_vec();
+        vec.push(coin(1, "uatom"));
+        assert_eq!(
+            Coins::try_from(vec).unwrap_err(),
+            CoinsError::DuplicateDenom {
+                denom: "uatom".to_string()
+            }
+        );
+
+        let mut vec = mock_vec();
+        vec.push(coin(0, "ujuno"));
+        assert_eq!(
+            Coins::try_from(vec).unwrap_err(),
+            CoinsError::ZeroAmount {
+                denom: "ujuno".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn converting_coin() {
+        let coins = Coins::try_from(coin(123, "ucosm")).unwrap();
+        assert_eq!(coins.to_vec(), vec![coin(123, "ucosm")]);
+
+        assert_eq!(
+            Coins::try_from(coin(0, "ucosm")).unwrap_err(),
+            CoinsError::ZeroAmount {
+                denom: "ucosm".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn from_str_works() {
+        assert_eq!(Coins::from_str("").unwrap(), Coins::default());
+
+        let coins = Coins::from_str("100uatom,5ujuno").unwrap();
+        assert_eq!(coins.to_vec(), vec![coin(100, "uatom"), coin(5, "ujuno")]);
+
+        // order does not matter
+        let coins = Coins::from_str("5ujuno,100uatom").unwrap();
+        assert_eq!(coins.to_vec(), vec![coin(100, "uatom"), coin(5, "ujuno")]);
+
+        assert_eq!(
+            Coins::from_str("100uatom,5uatom").unwrap_err(),
+            CoinsError::DuplicateDenom {
+                denom: "uatom".to_string()
+            }
+        );
+        assert_eq!(
+            Coins::from_str("100uatom,0ujuno").unwrap_err(),
+            CoinsError::ZeroAmount {
+                denom: "ujuno".to_string()
+            }
+        );
+        assert!(matches!(
+            Coins::from_str("100uatom,,5ujuno").unwrap_err(),
+            CoinsError::InvalidCoin(_)
+        ));
+        assert_eq!(
+            Coins::from_str("100uatom, 5ujuno").unwrap_err(),
+            CoinsError::InvalidCoin(CoinFromStrError::MissingAmount)
+        );
+        assert_eq!(
+            Coins::from_str("100 uatom,5ujuno").unwrap_err(),
+            CoinsError::InvalidCoin(CoinFromStrError::InvalidDenom(" uatom".to_string()))
+        );
+        assert_eq!(
+            Coins::from_str("1.5uatom").unwrap_err(),
+            CoinsError::InvalidCoin(CoinFromStrError::InvalidDenom(".5uatom".to_string()))
+        );
+        // trailing comma
+        assert_eq!(
+            Coins::from_str("1uatom,").unwrap_err(),
+            CoinsError::InvalidCoin(CoinFromStrError::MissingAmount)
+        );
+    }
+
+    #[test]
+    fn display_works() {
+        assert_eq!(Coins::default().to_string(), "");
+        assert_eq!(
+            mock_coins().to_string(),
+            "88888factory/osmo1234abcd/subdenom,69420ibc/1234ABCD,12345uatom"
+        );
+
+        // round trip
+        let coins = mock_coins();
+        assert_eq!(Coins::from_str(&coins.to_string()).unwrap(), coins);
+    }
+
+    #[test]
+    fn handling_zero_amount() {
+        let mut coins = mock_coins();
+
+        // adding and subtracting zero are no-ops
+        coins.add(coin(0, "ujuno")).unwrap();
+        coins.sub(coin(0, "ujuno")).unwrap();
+        coins.sub(coin(0, "uatom")).unwrap();
+        assert_eq!(coins, mock_coins());
+        assert_eq!(coins.amount_of("ujuno"), Uint128::zero());
+    }
+
+    #[test]
+    fn length() {
+        let coins = Coins::default();
+        assert_eq!(coins.len(), 0);
+        assert!(coins.is_empty());
+
+        let coins = mock_coins();
+        assert_eq!(coins.len(), 3);
+        assert!(!coins.is_empty());
+    }
+
+    #[test]
+    fn add_works() {
+        let mut coins = mock_coins();
+
+        // existing denom
+        coins.add(coin(12345, "uatom")).unwrap();
+        assert_eq!(coins.len(), 3);
+        assert_eq!(coins.amount_of("uatom").u128(), 24690);
+
+        // new denom
+        coins.add(coin(123, "uusd")).unwrap();
+        assert_eq!(coins.len(), 4);
+        assert_eq!(coins.amount_of("uusd").u128(), 123);
+
+        // overflow does not modify the collection
+        let err = coins.add(coin(u128::MAX, "uatom")).unwrap_err();
+        assert!(matches!(err, StdError::Overflow { .. }));
+        assert_eq!(coins.amount_of("uatom").u128(), 24690);
+    }
+
+    #[test]
+    fn sub_works() {
+        let mut coins = mock_coins();
+
+        // subtracting less than the amount
+        coins.sub(coin(2345, "uatom")).unwrap();
+        assert_eq!(coins.len(), 3);
+        assert_eq!(coins.amount_of("uatom").u128(), 10000);
+
+        // subtracting the full amount removes the denom
+        coins.sub(coin(10000, "uatom")).unwrap();
+        assert_eq!(coins.len(), 2);
+        assert_eq!(coins.amount_of("uatom"), Uint128::zero());
+        assert!(!coins.denoms().contains(&"uatom".to_string()));
+
+        // subtracting more than the amount
+        let err = coins.sub(coin(69421, "ibc/1234ABCD")).unwrap_err();
+        assert!(matches!(err, StdError::Overflow { .. }));
+        assert_eq!(coins.amount_of("ibc/1234ABCD").u128(), 69420);
+
+        // subtracting a missing denom
+        let err = coins.sub(coin(1, "uatom")).unwrap_err();
+        assert!(matches!(err, StdError::Overflow { .. }));
+    }
+
+    #[test]
+    fn amount_of_works() {
+        let coins = mock_coins();
+
+        assert_eq!(coins.amount_of("uatom").u128(), 12345);
+        assert_eq!(coins.amount_of("ibc/1234ABCD").u128(), 69420);
+        assert_eq!(
+            coins.amount_of("factory/osmo1234abcd/subdenom").u128(),
+            88888
+        );
+        assert_eq!(coins.amount_of("ujuno"), Uint128::zero());
+    }
+
+    #[test]
+    fn denoms_works() {
+        assert_eq!(
+            mock_coins().denoms(),
+            vec!["factory/osmo1234abcd/subdenom", "ibc/1234ABCD", "uatom"]
+        );
+    }
+
+    #[test]
+    fn iter_works() {
+        let coins = mock_coins();
+        let mut vec = mock_vec();
+        sort_by_denom(&mut vec);
+
+        assert_eq!(coins.iter().cloned().collect::<Vec<_>>(), vec);
+        assert_eq!(coins.into_iter().collect::<Vec<_>>(), vec);
+    }
+}
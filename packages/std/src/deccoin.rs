@@ -0,0 +1,172 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::errors::ConversionOverflowError;
+use crate::{Coin, Decimal256, Uint128};
+
+/// A coin type with decimal amount.
+/// Modeled after the Cosmos SDK's [DecCoin] type, which is used for
+/// e.g. distribution rewards that are not yet truncated to whole units.
+///
+/// The amount is serialized as a decimal string with up to 18 fractional digits.
+///
+/// [DecCoin]: https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/base/v1beta1/coin.proto#L28-L38
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DecCoin {
+    pub denom: String,
+    /// An amount in the base denom of the distributed token.
+    ///
+    /// Some chains have choosen atto (10^-18) for their token's base denomination. If we used `Decimal` here, we could only store 340282366920938463463.374607431768211455atoken which is 340.28 TOKEN.
+    pub amount: Decimal256,
+}
+
+impl DecCoin {
+    pub fn new(amount: impl Into<Decimal256>, denom: impl Into<String>) -> Self {
+        Self {
+            denom: denom.into(),
+            amount: amount.into(),
+        }
+    }
+
+    /// Truncates the decimal amount to a [`Coin`] and returns it together with the
+    /// remaining change, which has an amount smaller than 1.
+    ///
+    /// This is modeled after the Cosmos SDK's `DecCoin.TruncateDecimal`.
+    /// Returns an error if the truncated amount does not fit into a [`Uint128`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::{coin, DecCoin, Decimal256};
+    /// let dec_coin = DecCoin::new(Decimal256::permille(1500), "ucosm");
+    /// let (truncated, change) = dec_coin.truncate().unwrap();
+    /// assert_eq!(truncated, coin(1, "ucosm"));
+    /// assert_eq!(change, DecCoin::new(Decimal256::permille(500), "ucosm"));
+    /// ```
+    pub fn truncate(&self) -> Result<(Coin, DecCoin), ConversionOverflowError> {
+        let whole = self.amount.floor();
+        let amount =
+            Uint128::try_from(whole.atomics() / Decimal256::one().atomics()).map_err(|_| {
+                ConversionOverflowError::new("Decimal256", "Uint128", self.amount.to_string())
+            })?;
+        let truncated = Coin {
+            denom: self.denom.clone(),
+            amount,
+        };
+        let change = DecCoin::new(self.amount - whole, self.denom.clone());
+        Ok((truncated, change))
+    }
+}
+
+impl From<Coin> for DecCoin {
+    fn from(coin: Coin) -> Self {
+        DecCoin::new(
+            Decimal256::from_atomics(coin.amount, 0).unwrap(),
+            coin.denom,
+        )
+    }
+}
+
+impl fmt::Display for DecCoin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Same format as `Coin`, e.g. "1.5ucosm"
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coin, from_slice, to_vec, Decimal};
+
+    #[test]
+    fn dec_coin_new_works() {
+        let dec_coin = DecCoin::new(Decimal256::one(), "ucosm");
+        assert_eq!(dec_coin.amount, Decimal256::one());
+        assert_eq!(dec_coin.denom, "ucosm");
+
+        // accepts a Decimal
+        let dec_coin = DecCoin::new(Decimal::percent(50), "ucosm");
+        assert_eq!(dec_coin.amount, Decimal256::percent(50));
+    }
+
+    #[test]
+    fn dec_coin_truncate_works() {
+        let dec_coin = DecCoin::new(Decimal256::permille(1500), "ucosm");
+        let (truncated, change) = dec_coin.truncate().unwrap();
+        assert_eq!(truncated, coin(1, "ucosm"));
+        assert_eq!(change, DecCoin::new(Decimal256::permille(500), "ucosm"));
+
+        // whole amounts have no change
+        let dec_coin = DecCoin::new(Decimal256::percent(4200), "ucosm");
+        let (truncated, change) = dec_coin.truncate().unwrap();
+        assert_eq!(truncated, coin(42, "ucosm"));
+        assert_eq!(change, DecCoin::new(Decimal256::zero(), "ucosm"));
+
+        // amounts below one are truncated to zero
+        let dec_coin = DecCoin::new(Decimal256::from_atomics(1u128, 18).unwrap(), "uatom");
+        let (truncated, change) = dec_coin.truncate().unwrap();
+        assert_eq!(truncated, coin(0, "uatom"));
+        assert_eq!(change, dec_coin);
+
+        // the largest amount that fits into a Coin
+        let dec_coin = DecCoin::new(
+            Decimal256::from_atomics(u128::MAX, 0).unwrap() + Decimal256::percent(99),
+            "ucosm",
+        );
+        let (truncated, change) = dec_coin.truncate().unwrap();
+        assert_eq!(truncated, coin(u128::MAX, "ucosm"));
+        assert_eq!(change, DecCoin::new(Decimal256::percent(99), "ucosm"));
+    }
+
+    #[test]
+    fn dec_coin_truncate_fails_for_large_amounts() {
+        let dec_coin = DecCoin::new(
+            Decimal256::from_atomics(u128::MAX, 0).unwrap() + Decimal256::one(),
+            "ucosm",
+        );
+        let err = dec_coin.truncate().unwrap_err();
+        assert_eq!(
+            err,
+            ConversionOverflowError::new(
+                "Decimal256",
+                "Uint128",
+                "340282366920938463463374607431768211456"
+            )
+        );
+    }
+
+    #[test]
+    fn dec_coin_from_coin_works() {
+        let dec_coin = DecCoin::from(coin(123, "ucosm"));
+        assert_eq!(dec_coin, DecCoin::new(Decimal256::percent(12300), "ucosm"));
+
+        let dec_coin = DecCoin::from(coin(u128::MAX, "ucosm"));
+        assert_eq!(
+            dec_coin.truncate().unwrap(),
+            (
+                coin(u128::MAX, "ucosm"),
+                DecCoin::new(Decimal256::zero(), "ucosm")
+            )
+        );
+    }
+
+    #[test]
+    fn dec_coin_implements_display() {
+        let dec_coin = DecCoin::new(Decimal256::permille(1500), "ucosm");
+        assert_eq!(dec_coin.to_string(), "1.5ucosm");
+
+        let dec_coin = DecCoin::new(Decimal256::percent(4200), "ucosm");
+        assert_eq!(dec_coin.to_string(), "42ucosm");
+    }
+
+    #[test]
+    fn dec_coin_serialization_works() {
+        let dec_coin = DecCoin::new(Decimal256::permille(1234), "ucosm");
+        let json = to_vec(&dec_coin).unwrap();
+        assert_eq!(json, br#"{"denom":"ucosm","amount":"1.234"}"#);
+        let deserialized: DecCoin = from_slice(&json).unwrap();
+        assert_eq!(deserialized, dec_coin);
+    }
+}
@@ -0,0 +1,388 @@
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::errors::{
+    CoinsError, ConversionOverflowError, OverflowError, OverflowOperation, StdResult,
+};
+use crate::{Coins, DecCoin, Decimal256};
+
+/// A collection of decimal coins, similar to Cosmos SDK's `sdk.DecCoins` struct.
+///
+/// Like [`Coins`], the coins are sorted alphabetically by denom, denoms are unique
+/// and coins with a zero amount are never stored.
+///
+/// The collection is serialized as a list of [`DecCoin`]s, which matches the
+/// JSON representation of `sdk.DecCoins`.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(try_from = "Vec<DecCoin>", into = "Vec<DecCoin>")]
+pub struct DecCoins(BTreeMap<String, DecCoin>);
+
+impl JsonSchema for DecCoins {
+    fn schema_name() -> String {
+        Vec::<DecCoin>::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        Vec::<DecCoin>::json_schema(gen)
+    }
+}
+
+/// Casting a Vec<DecCoin> to DecCoins.
+/// The Vec can be out of order, but must not contain duplicate denoms or zero amounts.
+impl TryFrom<Vec<DecCoin>> for DecCoins {
+    type Error = CoinsError;
+
+    fn try_from(vec: Vec<DecCoin>) -> Result<Self, CoinsError> {
+        let mut map = BTreeMap::new();
+        for coin in vec {
+            if coin.amount.is_zero() {
+                return Err(CoinsError::ZeroAmount { denom: coin.denom });
+            }
+
+            if map.contains_key(&coin.denom) {
+                return Err(CoinsError::DuplicateDenom { denom: coin.denom });
+            }
+            map.insert(coin.denom.clone(), coin);
+        }
+
+        Ok(Self(map))
+    }
+}
+
+impl TryFrom<&[DecCoin]> for DecCoins {
+    type Error = CoinsError;
+
+    fn try_from(slice: &[DecCoin]) -> Result<Self, CoinsError> {
+        slice.to_vec().try_into()
+    }
+}
+
+impl From<DecCoins> for Vec<DecCoin> {
+    fn from(coins: DecCoins) -> Self {
+        coins.into_vec()
+    }
+}
+
+impl From<Coins> for DecCoins {
+    fn from(coins: Coins) -> Self {
+        Self(
+            coins
+                .into_iter()
+                .map(|coin| (coin.denom.clone(), DecCoin::from(coin)))
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Display for DecCoins {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = self
+            .0
+            .values()
+            .map(|coin| coin.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}", s)
+    }
+}
+
+impl DecCoins {
+    /// Conversion to Vec<DecCoin>, while NOT consuming the original object.
+    ///
+    /// The coins are sorted by denom.
+    pub fn to_vec(&self) -> Vec<DecCoin> {
+        self.0.values().cloned().collect()
+    }
+
+    /// Conversion to Vec<DecCoin>, consuming the original object.
+    ///
+    /// The coins are sorted by denom.
+    pub fn into_vec(self) -> Vec<DecCoin> {
+        self.0.into_values().collect()
+    }
+
+    /// Returns the number of different denoms in this collection.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if this collection contains no coins.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the denoms as a vector of strings.
+    /// The vector is guaranteed to not contain duplicates and sorted alphabetically.
+    pub fn denoms(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+
+    /// Returns the amount of the given denom or zero if the denom is not present.
+    pub fn amount_of(&self, denom: &str) -> Decimal256 {
+        self.0
+            .get(denom)
+            .map(|coin| coin.amount)
+            .unwrap_or_else(Decimal256::zero)
+    }
+
+    /// Returns an iterator over the coins, sorted by denom.
+    pub fn iter(&self) -> impl Iterator<Item = &DecCoin> {
+        self.0.values()
+    }
+
+    /// Adds the given coin to this collection.
+    ///
+    /// Adding a zero amount is a no-op. Returns an error if the amount of the denom
+    /// overflows, in which case the collection is not modified.
+    pub fn add(&mut self, coin: DecCoin) -> StdResult<()> {
+        if coin.amount.is_zero() {
+            return Ok(());
+        }
+
+        match self.0.get_mut(&coin.denom) {
+            Some(existing) => {
+                existing.amount = existing.amount.checked_add(coin.amount)?;
+            }
+            None => {
+                self.0.insert(coin.denom.clone(), coin);
+            }
+        }
+        Ok(())
+    }
+
+    /// Subtracts the given coin from this collection.
+    ///
+    /// Denoms whose amount reaches zero are removed. Returns an error if the
+    /// collection does not contain enough of the denom, in which case it is not modified.
+    pub fn sub(&mut self, coin: DecCoin) -> StdResult<()> {
+        if coin.amount.is_zero() {
+            return Ok(());
+        }
+
+        match self.0.get_mut(&coin.denom) {
+            Some(existing) => {
+                existing.amount = existing.amount.checked_sub(coin.amount)?;
+                if existing.amount.is_zero() {
+                    self.0.remove(&coin.denom);
+                }
+                Ok(())
+            }
+            None => {
+                Err(
+                    OverflowError::new(OverflowOperation::Sub, Decimal256::zero(), coin.amount)
+                        .into(),
+                )
+            }
+        }
+    }
+
+    /// Truncates all decimal amounts to [`Coins`] and returns them together with
+    /// the remaining change.
+    ///
+    /// This is modeled after the Cosmos SDK's `DecCoins.TruncateDecimal`.
+    /// Denoms with an amount smaller than 1 only show up in the change, and denoms
+    /// with a whole amount only show up in the truncated coins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::{coin, Coins, DecCoin, DecCoins, Decimal256};
+    /// let dec_coins = DecCoins::try_from(vec![
+    ///     DecCoin::new(Decimal256::permille(1500), "uatom"),
+    ///     DecCoin::new(Decimal256::permille(500), "ucosm"),
+    /// ])
+    /// .unwrap();
+    /// let (truncated, change) = dec_coins.truncate().unwrap();
+    /// assert_eq!(truncated, Coins::try_from(coin(1, "uatom")).unwrap());
+    /// assert_eq!(change.amount_of("uatom"), Decimal256::permille(500));
+    /// assert_eq!(change.amount_of("ucosm"), Decimal256::permille(500));
+    /// ```
+    pub fn truncate(&self) -> Result<(Coins, DecCoins), ConversionOverflowError> {
+        let mut truncated = Coins::default();
+        let mut change = DecCoins::default();
+        for coin in self.0.values() {
+            let (whole, rest) = coin.truncate()?;
+            // Neither operation can fail, since the denoms are unique and the amounts
+            // are added to empty collections.
+            truncated.add(whole).unwrap();
+            change.add(rest).unwrap();
+        }
+        Ok((truncated, change))
+    }
+}
+
+impl IntoIterator for DecCoins {
+    type Item = DecCoin;
+    type IntoIter = std::collections::btree_map::IntoValues<String, DecCoin>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coin, from_slice, to_vec, StdError};
+
+    fn dec_coin(amount: &str, denom: &str) -> DecCoin {
+        DecCoin::new(amount.parse::<Decimal256>().unwrap(), denom)
+    }
+
+    /// Returns a mockup Vec<DecCoin>. In this example, the coins are not in order
+    fn mock_vec() -> Vec<DecCoin> {
+        vec![
+            dec_coin("12.345", "uatom"),
+            dec_coin("0.69420", "ibc/1234ABCD"),
+            dec_coin("88888", "factory/osmo1234abcd/subdenom"),
+        ]
+    }
+
+    fn mock_dec_coins() -> DecCoins {
+        DecCoins::try_from(mock_vec()).unwrap()
+    }
+
+    #[test]
+    fn converting_vec() {
+        let mut vec = mock_vec();
+        let dec_coins = mock_dec_coins();
+
+        assert_eq!(DecCoins::try_from(vec.as_slice()).unwrap(), dec_coins);
+
+        // NOTE: the returned vec should be sorted
+        vec.sort_by(|a, b| a.denom.cmp(&b.denom));
+        assert_eq!(dec_coins.to_vec(), vec);
+        assert_eq!(Vec::<DecCoin>::from(dec_coins), vec);
+    }
+
+    #[test]
+    fn converting_vec_rejects_duplicates_and_zero_amounts() {
+        let mut vec = mock_vec();
+        vec.push(dec_coin("1", "uatom"));
+        assert_eq!(
+            DecCoins::try_from(vec).unwrap_err(),
+            CoinsError::DuplicateDenom {
+                denom: "uatom".to_string()
+            }
+        );
+
+        let mut vec = mock_vec();
+        vec.push(dec_coin("0", "ujuno"));
+        assert_eq!(
+            DecCoins::try_from(vec).unwrap_err(),
+            CoinsError::ZeroAmount {
+                denom: "ujuno".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn converting_coins() {
+        let coins = Coins::try_from(vec![coin(123, "ucosm"), coin(1, "uatom")]).unwrap();
+        let dec_coins = DecCoins::from(coins);
+        assert_eq!(
+            dec_coins.to_vec(),
+            vec![dec_coin("1", "uatom"), dec_coin("123", "ucosm")]
+        );
+    }
+
+    #[test]
+    fn serialization_works() {
+        let dec_coins = mock_dec_coins();
+        let json = to_vec(&dec_coins).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"[{"denom":"factory/osmo1234abcd/subdenom","amount":"88888"},{"denom":"ibc/1234ABCD","amount":"0.6942"},{"denom":"uatom","amount":"12.345"}]"#
+        );
+        let deserialized: DecCoins = from_slice(&json).unwrap();
+        assert_eq!(deserialized, dec_coins);
+
+        // same format as Vec<DecCoin>
+        assert_eq!(to_vec(&mock_vec()).unwrap().len(), json.len());
+
+        // invalid collections are rejected
+        let err = from_slice::<DecCoins>(
+            br#"[{"denom":"uatom","amount":"1"},{"denom":"uatom","amount":"2"}]"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Duplicate denom: uatom"));
+    }
+
+    #[test]
+    fn display_works() {
+        assert_eq!(DecCoins::default().to_string(), "");
+        assert_eq!(
+            mock_dec_coins().to_string(),
+            "88888factory/osmo1234abcd/subdenom,0.6942ibc/1234ABCD,12.345uatom"
+        );
+    }
+
+    #[test]
+    fn add_and_sub_work() {
+        let mut dec_coins = mock_dec_coins();
+
+        dec_coins.add(dec_coin("0.655", "uatom")).unwrap();
+        assert_eq!(dec_coins.amount_of("uatom"), Decimal256::percent(1300));
+        dec_coins.add(dec_coin("1.5", "ujuno")).unwrap();
+        assert_eq!(dec_coins.len(), 4);
+
+        // zero amounts are no-ops
+        dec_coins.add(dec_coin("0", "uosmo")).unwrap();
+        dec_coins.sub(dec_coin("0", "uosmo")).unwrap();
+        assert_eq!(dec_coins.len(), 4);
+
+        dec_coins.sub(dec_coin("1.5", "ujuno")).unwrap();
+        assert_eq!(dec_coins.len(), 3);
+        assert_eq!(dec_coins.amount_of("ujuno"), Decimal256::zero());
+
+        let err = dec_coins.sub(dec_coin("0.7", "ibc/1234ABCD")).unwrap_err();
+        assert!(matches!(err, StdError::Overflow { .. }));
+        let err = dec_coins.sub(dec_coin("1", "ujuno")).unwrap_err();
+        assert!(matches!(err, StdError::Overflow { .. }));
+        let err = dec_coins
+            .add(DecCoin::new(Decimal256::MAX, "uatom"))
+            .unwrap_err();
+        assert!(matches!(err, StdError::Overflow { .. }));
+        assert_eq!(dec_coins.amount_of("uatom"), Decimal256::percent(1300));
+    }
+
+    #[test]
+    fn denoms_works() {
+        assert_eq!(
+            mock_dec_coins().denoms(),
+            vec!["factory/osmo1234abcd/subdenom", "ibc/1234ABCD", "uatom"]
+        );
+    }
+
+    #[test]
+    fn truncate_works() {
+        let (truncated, change) = mock_dec_coins().truncate().unwrap();
+        assert_eq!(
+            truncated,
+            Coins::try_from(vec![
+                coin(88888, "factory/osmo1234abcd/subdenom"),
+                coin(12, "uatom")
+            ])
+            .unwrap()
+        );
+        assert_eq!(
+            change,
+            DecCoins::try_from(vec![
+                dec_coin("0.6942", "ibc/1234ABCD"),
+                dec_coin("0.345", "uatom")
+            ])
+            .unwrap()
+        );
+
+        let (truncated, change) = DecCoins::default().truncate().unwrap();
+        assert!(truncated.is_empty());
+        assert!(change.is_empty());
+
+        let dec_coins = DecCoins::try_from(vec![DecCoin::new(Decimal256::MAX, "uatom")]).unwrap();
+        dec_coins.truncate().unwrap_err();
+    }
+}
@@ -0,0 +1,301 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::errors::{DivideByZeroError, OverflowError, OverflowOperation};
+use crate::math::Uint64;
+
+/// A span of time in nanosecond precision, e.g. the difference between two [`Timestamp`]s.
+///
+/// This type can represent durations up to about 584 years. It is serialized
+/// as a string of nanoseconds, like [`Timestamp`].
+///
+/// ## Examples
+///
+/// ```
+/// # use cosmwasm_std::{Duration, Timestamp};
+/// let start = Timestamp::from_seconds(1_000);
+/// let end = start + Duration::from_seconds(3 * 60);
+/// assert_eq!(end - start, Duration::from_seconds(180));
+/// assert_eq!((end - start) / 2, Duration::from_seconds(90));
+/// ```
+///
+/// [`Timestamp`]: crate::Timestamp
+#[derive(
+    Serialize, Deserialize, Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema,
+)]
+pub struct Duration(Uint64);
+
+impl Duration {
+    /// Creates a duration from nanoseconds
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Duration(Uint64::new(nanos))
+    }
+
+    /// Creates a duration from seconds
+    pub const fn from_seconds(seconds: u64) -> Self {
+        Duration(Uint64::new(seconds * 1_000_000_000))
+    }
+
+    /// Creates a duration of length zero
+    #[inline]
+    pub const fn zero() -> Self {
+        Duration(Uint64::zero())
+    }
+
+    /// Returns true if the duration is zero
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns the total number of nanoseconds
+    #[inline]
+    pub fn nanos(&self) -> u64 {
+        self.0.u64()
+    }
+
+    /// Returns the number of whole seconds (truncate nanoseconds)
+    #[inline]
+    pub fn seconds(&self) -> u64 {
+        self.0.u64() / 1_000_000_000
+    }
+
+    /// Returns nanoseconds since the last whole second (the remainder truncated
+    /// by `seconds()`)
+    #[inline]
+    pub fn subsec_nanos(&self) -> u64 {
+        self.0.u64() % 1_000_000_000
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_add(other.0)
+            .map(Self)
+            .map_err(|_| OverflowError::new(OverflowOperation::Add, self, other))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_sub(other.0)
+            .map(Self)
+            .map_err(|_| OverflowError::new(OverflowOperation::Sub, self, other))
+    }
+
+    /// Multiplies the duration by an integer factor
+    pub fn checked_mul(self, factor: u64) -> Result<Self, OverflowError> {
+        self.0
+            .checked_mul(factor.into())
+            .map(Self)
+            .map_err(|_| OverflowError::new(OverflowOperation::Mul, self, factor))
+    }
+
+    /// Divides the duration by an integer divisor, truncating the result
+    pub fn checked_div(self, divisor: u64) -> Result<Self, DivideByZeroError> {
+        self.0
+            .checked_div(divisor.into())
+            .map(Self)
+            .map_err(|_| DivideByZeroError::new(self))
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+}
+
+impl fmt::Display for Duration {
+    /// Formats the duration in seconds with nanosecond precision, e.g. `"1.500000000s"`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{:09}s", self.seconds(), self.subsec_nanos())
+    }
+}
+
+impl Add for Duration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Duration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u64> for Duration {
+    type Output = Self;
+
+    fn mul(self, rhs: u64) -> Self {
+        Self(self.0 * Uint64::new(rhs))
+    }
+}
+
+impl MulAssign<u64> for Duration {
+    fn mul_assign(&mut self, rhs: u64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<u64> for Duration {
+    type Output = Self;
+
+    fn div(self, rhs: u64) -> Self {
+        Self(self.0 / Uint64::new(rhs))
+    }
+}
+
+impl DivAssign<u64> for Duration {
+    fn div_assign(&mut self, rhs: u64) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_slice, to_vec};
+
+    #[test]
+    fn duration_from_nanos_and_seconds() {
+        let d = Duration::from_nanos(123);
+        assert_eq!(d.nanos(), 123);
+        let d = Duration::from_seconds(123);
+        assert_eq!(d.nanos(), 123_000_000_000);
+        assert_eq!(Duration::from_seconds(0), Duration::zero());
+        assert!(Duration::zero().is_zero());
+        assert!(!Duration::from_nanos(1).is_zero());
+    }
+
+    #[test]
+    fn duration_seconds_and_subsec_nanos() {
+        let d = Duration::from_nanos(987654321000);
+        assert_eq!(d.seconds(), 987);
+        assert_eq!(d.subsec_nanos(), 654321000);
+    }
+
+    #[test]
+    fn duration_implements_display() {
+        assert_eq!(Duration::zero().to_string(), "0.000000000s");
+        assert_eq!(Duration::from_nanos(1).to_string(), "0.000000001s");
+        assert_eq!(
+            Duration::from_nanos(1_500_000_000).to_string(),
+            "1.500000000s"
+        );
+        assert_eq!(Duration::from_seconds(60).to_string(), "60.000000000s");
+    }
+
+    #[test]
+    fn duration_serialization_works() {
+        let d = Duration::from_nanos(1_500_000_000);
+        let json = to_vec(&d).unwrap();
+        assert_eq!(json, br#""1500000000""#);
+        let deserialized: Duration = from_slice(&json).unwrap();
+        assert_eq!(deserialized, d);
+    }
+
+    #[test]
+    fn duration_checked_add_and_sub() {
+        let a = Duration::from_seconds(3);
+        let b = Duration::from_nanos(500);
+        assert_eq!(a.checked_add(b), Ok(Duration::from_nanos(3_000_000_500)));
+        assert_eq!(a.checked_sub(b), Ok(Duration::from_nanos(2_999_999_500)));
+        assert_eq!(
+            b.checked_sub(a),
+            Err(OverflowError::new(OverflowOperation::Sub, b, a))
+        );
+        let max = Duration::from_nanos(u64::MAX);
+        assert_eq!(
+            max.checked_add(b),
+            Err(OverflowError::new(OverflowOperation::Add, max, b))
+        );
+
+        assert_eq!(max.saturating_add(b), max);
+        assert_eq!(b.saturating_sub(a), Duration::zero());
+    }
+
+    #[test]
+    fn duration_checked_mul_and_div() {
+        let d = Duration::from_seconds(10);
+        assert_eq!(d.checked_mul(3), Ok(Duration::from_seconds(30)));
+        assert_eq!(d.checked_mul(0), Ok(Duration::zero()));
+        assert_eq!(d.checked_div(4), Ok(Duration::from_nanos(2_500_000_000)));
+        // truncates
+        assert_eq!(
+            Duration::from_nanos(10).checked_div(3),
+            Ok(Duration::from_nanos(3))
+        );
+
+        let max = Duration::from_nanos(u64::MAX);
+        assert_eq!(
+            max.checked_mul(2),
+            Err(OverflowError::new(OverflowOperation::Mul, max, 2))
+        );
+        assert_eq!(d.checked_div(0), Err(DivideByZeroError::new(d)));
+    }
+
+    #[test]
+    fn duration_ops_work() {
+        let mut d = Duration::from_seconds(10);
+        assert_eq!(d + Duration::from_seconds(5), Duration::from_seconds(15));
+        assert_eq!(d - Duration::from_seconds(5), Duration::from_seconds(5));
+        assert_eq!(d * 3, Duration::from_seconds(30));
+        assert_eq!(d / 4, Duration::from_nanos(2_500_000_000));
+
+        d += Duration::from_seconds(2);
+        assert_eq!(d, Duration::from_seconds(12));
+        d -= Duration::from_seconds(4);
+        assert_eq!(d, Duration::from_seconds(8));
+        d *= 2;
+        assert_eq!(d, Duration::from_seconds(16));
+        d /= 8;
+        assert_eq!(d, Duration::from_seconds(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn duration_sub_panics_on_overflow() {
+        let _ = Duration::from_seconds(1) - Duration::from_seconds(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn duration_mul_panics_on_overflow() {
+        let _ = Duration::from_nanos(u64::MAX) * 2;
+    }
+
+    #[test]
+    #[should_panic]
+    fn duration_div_panics_for_zero() {
+        let _ = Duration::from_seconds(1) / 0;
+    }
+
+    #[test]
+    fn duration_implements_ord() {
+        let short = Duration::from_nanos(999_999_999);
+        let long = Duration::from_seconds(1);
+        assert!(short < long);
+        assert_eq!(short.max(long), long);
+        assert_eq!(short.min(long), short);
+    }
+}
@@ -5,8 +5,9 @@ mod verification_error;
 
 pub use recover_pubkey_error::RecoverPubkeyError;
 pub use std_error::{
-    CheckedFromRatioError, CheckedMultiplyRatioError, ConversionOverflowError, DivideByZeroError,
-    OverflowError, OverflowOperation, RoundUpOverflowError, StdError, StdResult,
+    CheckedFromRatioError, CheckedMultiplyRatioError, CoinFromStrError, CoinsError,
+    ConversionOverflowError, DivideByZeroError, DivisionError, LogarithmOfZeroError, OverflowError,
+    OverflowOperation, RoundDownOverflowError, RoundUpOverflowError, StdError, StdResult,
 };
 pub use system_error::SystemError;
 pub use verification_error::VerificationError;
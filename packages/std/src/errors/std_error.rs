@@ -556,6 +556,18 @@ impl DivideByZeroError {
     }
 }
 
+/// The error returned by the checked division operations of the signed integer types,
+/// which can fail because of a zero divisor or because the result does not fit into
+/// the type (e.g. `Int64::MIN / -1`).
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum DivisionError {
+    #[error("Divide by zero")]
+    DivideByZero,
+
+    #[error("Overflow in division")]
+    Overflow,
+}
+
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum CheckedMultiplyRatioError {
     #[error("Denominator must not be zero")]
@@ -578,6 +590,45 @@ pub enum CheckedFromRatioError {
 #[error("Round up operation failed because of overflow")]
 pub struct RoundUpOverflowError;
 
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Round down operation failed because of overflow")]
+pub struct RoundDownOverflowError;
+
+/// The error returned by the checked logarithms of the decimal types,
+/// since the logarithm of zero is not defined.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Cannot calculate the logarithm of zero")]
+pub struct LogarithmOfZeroError;
+
+/// The error returned when parsing a [`Coin`](crate::Coin) from a string like `"123ucosm"`.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CoinFromStrError {
+    #[error("Missing denominator")]
+    MissingDenom,
+
+    #[error("Missing amount or non-digit characters in amount")]
+    MissingAmount,
+
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(#[from] std::num::ParseIntError),
+
+    #[error("Invalid denom: {0}")]
+    InvalidDenom(String),
+}
+
+/// The error returned when creating a [`Coins`](crate::Coins) collection.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CoinsError {
+    #[error("Duplicate denom: {denom}")]
+    DuplicateDenom { denom: String },
+
+    #[error("Zero amount for denom: {denom}")]
+    ZeroAmount { denom: String },
+
+    #[error("Error parsing coin: {0}")]
+    InvalidCoin(#[from] CoinFromStrError),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,233 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::errors::{OverflowError, OverflowOperation, StdError, StdResult};
+use crate::{BlockInfo, Duration, Timestamp};
+
+/// A point in the future at which something expires, either in terms of block height
+/// or block time.
+///
+/// ## Examples
+///
+/// ```
+/// # use cosmwasm_std::{Duration, Expiration};
+/// # use cosmwasm_std::testing::mock_env;
+/// let env = mock_env();
+/// let expiration = Expiration::AtTime(env.block.time)
+///     .plus_duration(Duration::from_seconds(60))
+///     .unwrap();
+/// assert!(!expiration.is_expired(&env.block));
+///
+/// let mut block = env.block;
+/// block.time = block.time.plus_seconds(60);
+/// assert!(expiration.is_expired(&block));
+/// assert!(!Expiration::Never {}.is_expired(&block));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Expiration {
+    /// Expires when the current block height is greater than or equal to this value
+    AtHeight(u64),
+    /// Expires when the current block time is greater than or equal to this value
+    AtTime(Timestamp),
+    /// Never expires
+    Never {},
+}
+
+impl Expiration {
+    /// Returns true if the expiration is reached at the given block.
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        match self {
+            Expiration::AtHeight(height) => block.height >= *height,
+            Expiration::AtTime(time) => block.time >= *time,
+            Expiration::Never {} => false,
+        }
+    }
+
+    /// Moves a time based expiration by the given duration into the future.
+    ///
+    /// `Never` stays `Never`. Returns an error for height based expirations
+    /// and if the resulting time overflows.
+    pub fn plus_duration(self, duration: Duration) -> StdResult<Expiration> {
+        match self {
+            Expiration::AtTime(time) => Ok(Expiration::AtTime(time.checked_add(duration)?)),
+            Expiration::AtHeight(_) => Err(StdError::generic_err(
+                "Cannot add a duration to a height based expiration",
+            )),
+            Expiration::Never {} => Ok(self),
+        }
+    }
+
+    /// Moves a height based expiration by the given number of blocks into the future.
+    ///
+    /// `Never` stays `Never`. Returns an error for time based expirations
+    /// and if the resulting height overflows.
+    pub fn plus_blocks(self, blocks: u64) -> StdResult<Expiration> {
+        match self {
+            Expiration::AtHeight(height) => height
+                .checked_add(blocks)
+                .map(Expiration::AtHeight)
+                .ok_or_else(|| OverflowError::new(OverflowOperation::Add, height, blocks).into()),
+            Expiration::AtTime(_) => Err(StdError::generic_err(
+                "Cannot add blocks to a time based expiration",
+            )),
+            Expiration::Never {} => Ok(self),
+        }
+    }
+}
+
+/// The default expiration is to never expire.
+impl Default for Expiration {
+    fn default() -> Self {
+        Expiration::Never {}
+    }
+}
+
+impl fmt::Display for Expiration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expiration::AtHeight(height) => write!(f, "expiration height: {}", height),
+            Expiration::AtTime(time) => write!(f, "expiration time: {}", time),
+            Expiration::Never {} => write!(f, "expiration: never"),
+        }
+    }
+}
+
+/// Expirations can only be compared if they use the same unit.
+/// `Never` is later than every other expiration.
+impl PartialOrd for Expiration {
+    fn partial_cmp(&self, other: &Expiration) -> Option<Ordering> {
+        match (self, other) {
+            (Expiration::AtHeight(h1), Expiration::AtHeight(h2)) => Some(h1.cmp(h2)),
+            (Expiration::AtTime(t1), Expiration::AtTime(t2)) => Some(t1.cmp(t2)),
+            (Expiration::Never {}, Expiration::Never {}) => Some(Ordering::Equal),
+            (_, Expiration::Never {}) => Some(Ordering::Less),
+            (Expiration::Never {}, _) => Some(Ordering::Greater),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_env;
+    use crate::{from_slice, to_vec};
+
+    #[test]
+    fn is_expired_works() {
+        let block = mock_env().block;
+
+        assert!(Expiration::AtHeight(block.height - 1).is_expired(&block));
+        assert!(Expiration::AtHeight(block.height).is_expired(&block));
+        assert!(!Expiration::AtHeight(block.height + 1).is_expired(&block));
+
+        assert!(Expiration::AtTime(block.time.minus_nanos(1)).is_expired(&block));
+        assert!(Expiration::AtTime(block.time).is_expired(&block));
+        assert!(!Expiration::AtTime(block.time.plus_nanos(1)).is_expired(&block));
+
+        assert!(!Expiration::Never {}.is_expired(&block));
+    }
+
+    #[test]
+    fn plus_duration_works() {
+        let expiration = Expiration::AtTime(Timestamp::from_seconds(100));
+        assert_eq!(
+            expiration
+                .plus_duration(Duration::from_seconds(20))
+                .unwrap(),
+            Expiration::AtTime(Timestamp::from_seconds(120))
+        );
+        assert_eq!(
+            Expiration::Never {}
+                .plus_duration(Duration::from_seconds(20))
+                .unwrap(),
+            Expiration::Never {}
+        );
+
+        let err = Expiration::AtHeight(5)
+            .plus_duration(Duration::from_seconds(20))
+            .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        let err = Expiration::AtTime(Timestamp::from_nanos(u64::MAX))
+            .plus_duration(Duration::from_nanos(1))
+            .unwrap_err();
+        assert!(matches!(err, StdError::Overflow { .. }));
+    }
+
+    #[test]
+    fn plus_blocks_works() {
+        assert_eq!(
+            Expiration::AtHeight(5).plus_blocks(10).unwrap(),
+            Expiration::AtHeight(15)
+        );
+        assert_eq!(
+            Expiration::Never {}.plus_blocks(10).unwrap(),
+            Expiration::Never {}
+        );
+
+        let err = Expiration::AtTime(Timestamp::from_seconds(100))
+            .plus_blocks(10)
+            .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        let err = Expiration::AtHeight(u64::MAX).plus_blocks(1).unwrap_err();
+        assert!(matches!(err, StdError::Overflow { .. }));
+    }
+
+    #[test]
+    fn partial_ord_works() {
+        assert!(Expiration::AtHeight(5) < Expiration::AtHeight(10));
+        assert!(Expiration::AtHeight(10) >= Expiration::AtHeight(10));
+        assert!(
+            Expiration::AtTime(Timestamp::from_seconds(5))
+                < Expiration::AtTime(Timestamp::from_seconds(10))
+        );
+        assert!(Expiration::AtHeight(u64::MAX) < Expiration::Never {});
+        assert!(Expiration::Never {} > Expiration::AtTime(Timestamp::from_nanos(u64::MAX)));
+        assert_eq!(
+            Expiration::Never {}.partial_cmp(&Expiration::Never {}),
+            Some(Ordering::Equal)
+        );
+
+        // different units cannot be compared
+        let height = Expiration::AtHeight(5);
+        let time = Expiration::AtTime(Timestamp::from_seconds(5));
+        assert_eq!(height.partial_cmp(&time), None);
+        assert_eq!(time.partial_cmp(&height), None);
+    }
+
+    #[test]
+    fn default_is_never() {
+        assert_eq!(Expiration::default(), Expiration::Never {});
+    }
+
+    #[test]
+    fn display_works() {
+        assert_eq!(Expiration::AtHeight(5).to_string(), "expiration height: 5");
+        assert_eq!(
+            Expiration::AtTime(Timestamp::from_nanos(1_500_000_000)).to_string(),
+            "expiration time: 1.500000000"
+        );
+        assert_eq!(Expiration::Never {}.to_string(), "expiration: never");
+    }
+
+    #[test]
+    fn serialization_works() {
+        let cases: [(Expiration, &[u8]); 3] = [
+            (Expiration::AtHeight(5), br#"{"at_height":5}"#),
+            (
+                Expiration::AtTime(Timestamp::from_nanos(1_500_000_000)),
+                br#"{"at_time":"1500000000"}"#,
+            ),
+            (Expiration::Never {}, br#"{"never":{}}"#),
+        ];
+        for (expiration, json) in cases {
+            assert_eq!(to_vec(&expiration).unwrap(), json);
+            assert_eq!(from_slice::<Expiration>(json).unwrap(), expiration);
+        }
+    }
+}
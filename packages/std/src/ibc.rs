@@ -15,6 +15,14 @@ use crate::results::{Attribute, CosmosMsg, Empty, Event, SubMsg};
 use crate::serde::to_binary;
 use crate::timestamp::Timestamp;
 
+mod callbacks;
+#[cfg(feature = "cosmwasm_1_4")]
+mod ica;
+
+pub use callbacks::*;
+#[cfg(feature = "cosmwasm_1_4")]
+pub use ica::*;
+
 /// These are messages in the IBC lifecycle. Only usable by IBC-enabled contracts
 /// (contracts that directly speak the IBC protocol via 6 entry points)
 #[non_exhaustive]
@@ -37,6 +45,27 @@ pub enum IbcMsg {
         /// when packet times out, measured on remote chain
         timeout: IbcTimeout,
     },
+    /// Like [`IbcMsg::Transfer`], but with a memo attached to the ICS-20 packet, as used by
+    /// packet forwarding or IBC hooks. See the blog post
+    /// ["Moving Beyond Simple Token Transfers"](https://medium.com/the-interchain-foundation/moving-beyond-simple-token-transfers-d42b2b1dc29b)
+    /// for more information.
+    ///
+    /// This is serialized as a `transfer` message with a `memo` field. Parsing such a message
+    /// results in an [`IbcMsg::Transfer`] without the memo.
+    #[cfg(feature = "cosmwasm_1_4")]
+    #[serde(rename = "transfer", skip_deserializing)]
+    TransferWithMemo {
+        /// exisiting channel to send the tokens over
+        channel_id: String,
+        /// address on the remote chain to receive these tokens
+        to_address: String,
+        /// packet data only supports one coin
+        /// https://github.com/cosmos/cosmos-sdk/blob/v0.40.0/proto/ibc/applications/transfer/v1/transfer.proto#L11-L20
+        amount: Coin,
+        /// when packet times out, measured on remote chain
+        timeout: IbcTimeout,
+        memo: String,
+    },
     /// Sends an IBC packet with given data over the existing channel.
     /// Data should be encoded in a format defined by the channel version,
     /// and the module on the other side should know how to parse this.
@@ -49,6 +78,117 @@ pub enum IbcMsg {
     /// This will close an existing channel that is owned by this contract.
     /// Port is auto-assigned to the contract's IBC port
     CloseChannel { channel_id: String },
+    /// Incentivizes the next IBC packet sent on the given channel with a fee (ICS-29).
+    /// Note that this does not necessarily have to be a packet sent by this contract.
+    /// The fees are taken from the contract's balance immediately and locked until the packet is handled.
+    ///
+    /// This is translated to a [MsgPayPacketFee](https://github.com/cosmos/ibc-go/blob/v4.0.0/proto/ibc/applications/fee/v1/tx.proto#L76-L93).
+    /// `signer` is automatically filled with the current contract's address.
+    ///
+    /// # Example
+    ///
+    /// Most commonly, this message is added to a response right before the message sending the
+    /// packet, e.g. [`IbcMsg::SendPacket`] or [`IbcMsg::Transfer`].
+    ///
+    /// ```
+    /// # use cosmwasm_std::{coins, IbcFee, IbcMsg, IbcTimeout, Response, Timestamp};
+    /// let incentivize = IbcMsg::PayPacketFee {
+    ///     port_id: "transfer".to_string(),
+    ///     channel_id: "channel-0".to_string(),
+    ///     fee: IbcFee {
+    ///         receive_fee: coins(100, "ucosm"),
+    ///         ack_fee: coins(200, "ucosm"),
+    ///         timeout_fee: coins(200, "ucosm"),
+    ///     },
+    ///     relayers: vec![],
+    /// };
+    /// let transfer = IbcMsg::Transfer {
+    ///     channel_id: "channel-0".to_string(),
+    ///     to_address: "osmo1recipient".to_string(),
+    ///     amount: cosmwasm_std::coin(1000, "ucosm"),
+    ///     timeout: IbcTimeout::with_timestamp(Timestamp::from_seconds(1_700_000_000)),
+    /// };
+    /// let response: Response = Response::new()
+    ///     .add_message(incentivize)
+    ///     .add_message(transfer);
+    /// ```
+    #[cfg(feature = "cosmwasm_1_4")]
+    PayPacketFee {
+        /// The port id on the chain where the packet is sent from (this chain)
+        port_id: String,
+        /// The channel id on the chain where the packet is sent from (this chain)
+        channel_id: String,
+        fee: IbcFee,
+        /// Allowlist of relayer addresses that can receive the fee.
+        /// An empty list means that any relayer can receive the fee.
+        relayers: Vec<String>,
+    },
+    /// Incentivizes an already sent IBC packet, identified by port, channel and sequence,
+    /// with a fee (ICS-29). The fee is added to the existing fees of the packet.
+    /// Note that this does not necessarily have to be a packet sent by this contract.
+    /// The fees are taken from the contract's balance immediately and locked until the packet is handled.
+    ///
+    /// This is translated to a [MsgPayPacketFeeAsync](https://github.com/cosmos/ibc-go/blob/v4.0.0/proto/ibc/applications/fee/v1/tx.proto#L98-L107).
+    #[cfg(feature = "cosmwasm_1_4")]
+    PayPacketFeeAsync {
+        /// The port id on the chain where the packet is sent from (this chain)
+        port_id: String,
+        /// The channel id on the chain where the packet is sent from (this chain)
+        channel_id: String,
+        /// The sequence number of the packet that should be incentivized
+        sequence: u64,
+        fee: IbcFee,
+    },
+    /// Registers an interchain account (ICS-27) owned by this contract on the host chain
+    /// of the given connection. This opens a new channel on the contract's controller port
+    /// `icacontroller-{contract address}`, so the account is only usable once the channel
+    /// handshake is complete.
+    ///
+    /// This is translated to a [MsgRegisterInterchainAccount](https://github.com/cosmos/ibc-go/blob/v7.0.0/proto/ibc/applications/interchain_accounts/controller/v1/tx.proto#L25-L32).
+    /// `owner` is automatically filled with the current contract's address.
+    /// Use [`RegisterInterchainAccountResponse::from_reply_data`] to parse the data of a submessage reply.
+    #[cfg(feature = "cosmwasm_1_4")]
+    RegisterInterchainAccount {
+        /// The connection to the host chain
+        connection_id: String,
+        /// The channel version. If unset, the default ICS-27 version is negotiated.
+        version: Option<String>,
+    },
+    /// Sends a batch of messages to the interchain account of this contract on the host chain
+    /// of the given connection. The messages are executed atomically by the interchain account.
+    /// The result is reported back to the contract via `ibc_packet_ack` or `ibc_packet_timeout`,
+    /// see [`IcaAcknowledgement`].
+    ///
+    /// This is translated to a [MsgSendTx](https://github.com/cosmos/ibc-go/blob/v7.0.0/proto/ibc/applications/interchain_accounts/controller/v1/tx.proto#L40-L49)
+    /// with [`IcaPacketData::execute_tx`] as the packet data.
+    /// Use [`SendInterchainTxResponse::from_reply_data`] to parse the data of a submessage reply.
+    ///
+    /// Note that the timeout is interpreted on the host chain and for ordered channels
+    /// (as used by ICS-27 up to ibc-go v8) a timeout closes the channel.
+    #[cfg(feature = "cosmwasm_1_4")]
+    SendInterchainTx {
+        /// The connection to the host chain
+        connection_id: String,
+        /// The messages to execute, in order
+        msgs: Vec<AnyMsg>,
+        memo: Option<String>,
+        /// when packet times out, measured on remote chain
+        timeout: IbcTimeout,
+    },
+}
+
+/// The fees paid to relayers for relaying an IBC packet (ICS-29).
+///
+/// See https://github.com/cosmos/ibc-go/blob/v4.0.0/proto/ibc/applications/fee/v1/fee.proto#L13-L30
+#[cfg(feature = "cosmwasm_1_4")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcFee {
+    /// The packet receive fee, paid to the relayer that relays the packet to the counterparty chain
+    pub receive_fee: Vec<Coin>,
+    /// The packet acknowledgement fee, paid to the relayer that relays the acknowledgement back
+    pub ack_fee: Vec<Coin>,
+    /// The packet timeout fee, paid to the relayer that relays a timeout
+    pub timeout_fee: Vec<Coin>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -246,6 +386,121 @@ impl IbcAcknowledgement {
     }
 }
 
+/// This is a standard IBC acknowledgement type. IBC applications are free
+/// to use any acknowledgement format they want. However, for compatibility
+/// purposes it is recommended to use this.
+///
+/// The original type definition can be found in
+/// [ibc-go](https://github.com/cosmos/ibc-go/blob/v7.2.0/proto/ibc/core/channel/v1/channel.proto#L152-L163).
+/// The JSON encoding is the same as the acknowledgement of ICS-20 transfers.
+///
+/// In ibc-go this is either exactly `{"result":"<base64 data>"}` or exactly
+/// `{"error":"<error message>"}`, so it is important to not add any other fields here.
+///
+/// ## Examples
+///
+/// For your convenience, there are success and error constructors.
+///
+/// ```
+/// use cosmwasm_std::StdAck;
+///
+/// let ack1 = StdAck::success(b"\x01"); // 0x01 is a FungibleTokenPacketSuccess from ICS-20.
+/// assert!(ack1.is_success());
+///
+/// let ack2 = StdAck::error("kaputt"); // Some free text error message
+/// assert!(ack2.is_error());
+/// ```
+///
+/// Both of them can be used to set the acknowledgement of an [`IbcReceiveResponse`].
+///
+/// ```
+/// use cosmwasm_std::{IbcReceiveResponse, StdAck};
+///
+/// let response: IbcReceiveResponse = IbcReceiveResponse::new().set_ack(StdAck::success(b"\x01"));
+/// assert_eq!(response.acknowledgement, br#"{"result":"AQ=="}"#);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StdAck {
+    #[serde(rename = "result")]
+    Success(Binary),
+    Error(String),
+}
+
+impl StdAck {
+    /// Creates a success ack with the given data
+    pub fn success(data: impl Into<Binary>) -> Self {
+        StdAck::Success(data.into())
+    }
+
+    /// Creates an error ack
+    pub fn error(err: impl Into<String>) -> Self {
+        StdAck::Error(err.into())
+    }
+
+    #[must_use = "if you intended to assert that this is a success, consider `.unwrap()` instead"]
+    #[inline]
+    pub const fn is_success(&self) -> bool {
+        matches!(*self, StdAck::Success(_))
+    }
+
+    #[must_use = "if you intended to assert that this is an error, consider `.unwrap_err()` instead"]
+    #[inline]
+    pub const fn is_error(&self) -> bool {
+        !self.is_success()
+    }
+
+    /// Serializes the ack to binary using JSON. This can be used
+    /// for setting the acknowledgement field in IbcReceiveResponse.
+    ///
+    /// ## Examples
+    ///
+    /// Show how the acknowledgement looks on the wire:
+    ///
+    /// ```
+    /// # use cosmwasm_std::StdAck;
+    /// let ack1 = StdAck::success(b"\x01"); // 0x01 is a FungibleTokenPacketSuccess from ICS-20.
+    /// assert_eq!(ack1.to_binary(), br#"{"result":"AQ=="}"#);
+    ///
+    /// let ack2 = StdAck::error("kaputt"); // Some free text error message
+    /// assert_eq!(ack2.to_binary(), br#"{"error":"kaputt"}"#);
+    /// ```
+    pub fn to_binary(&self) -> Binary {
+        // We need a non-failing StdAck -> Binary conversion to allow using StdAck in
+        // `impl Into<Binary>` arguments.
+        // Pretty sure this cannot fail. If that changes we can create a non-failing implementation here.
+        to_binary(&self).unwrap()
+    }
+
+    /// Returns the success data. Panics if this is an error ack.
+    pub fn unwrap(self) -> Binary {
+        match self {
+            StdAck::Success(data) => data,
+            StdAck::Error(err) => panic!("{}", err),
+        }
+    }
+
+    /// Returns the error message. Panics if this is a success ack.
+    pub fn unwrap_err(self) -> String {
+        match self {
+            StdAck::Success(data) => panic!("{:?}", data),
+            StdAck::Error(err) => err,
+        }
+    }
+}
+
+impl From<StdAck> for Binary {
+    fn from(original: StdAck) -> Binary {
+        original.to_binary()
+    }
+}
+
+impl From<StdAck> for IbcAcknowledgement {
+    fn from(original: StdAck) -> IbcAcknowledgement {
+        IbcAcknowledgement::new(original)
+    }
+}
+
 /// The message that is passed into `ibc_channel_open`
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -789,6 +1044,86 @@ mod tests {
         assert_eq!(encoded.as_str(), expected);
     }
 
+    #[test]
+    #[cfg(feature = "cosmwasm_1_4")]
+    fn serialize_msg_with_memo() {
+        let msg = IbcMsg::TransferWithMemo {
+            channel_id: "channel-123".to_string(),
+            to_address: "my-special-addr".into(),
+            amount: Coin::new(12345678, "uatom"),
+            timeout: IbcTimeout::with_timestamp(Timestamp::from_nanos(1234567890)),
+            memo: "for you".to_string(),
+        };
+        let encoded = to_string(&msg).unwrap();
+        let expected = r#"{"transfer":{"channel_id":"channel-123","to_address":"my-special-addr","amount":{"denom":"uatom","amount":"12345678"},"timeout":{"block":null,"timestamp":"1234567890"},"memo":"for you"}}"#;
+        assert_eq!(encoded.as_str(), expected);
+
+        // parsing ignores the memo
+        let parsed: IbcMsg = serde_json_wasm::from_str(expected).unwrap();
+        assert_eq!(
+            parsed,
+            IbcMsg::Transfer {
+                channel_id: "channel-123".to_string(),
+                to_address: "my-special-addr".into(),
+                amount: Coin::new(12345678, "uatom"),
+                timeout: IbcTimeout::with_timestamp(Timestamp::from_nanos(1234567890)),
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "cosmwasm_1_4")]
+    fn serialize_interchain_account_msgs() {
+        let msg = IbcMsg::RegisterInterchainAccount {
+            connection_id: "connection-0".to_string(),
+            version: None,
+        };
+        let encoded = to_string(&msg).unwrap();
+        let expected =
+            r#"{"register_interchain_account":{"connection_id":"connection-0","version":null}}"#;
+        assert_eq!(encoded.as_str(), expected);
+
+        let msg = IbcMsg::SendInterchainTx {
+            connection_id: "connection-0".to_string(),
+            msgs: vec![AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", b"abc".to_vec())],
+            memo: Some("hi".to_string()),
+            timeout: IbcTimeout::with_timestamp(Timestamp::from_nanos(1234567890)),
+        };
+        let encoded = to_string(&msg).unwrap();
+        let expected = r#"{"send_interchain_tx":{"connection_id":"connection-0","msgs":[{"type_url":"/cosmos.bank.v1beta1.MsgSend","value":"YWJj"}],"memo":"hi","timeout":{"block":null,"timestamp":"1234567890"}}}"#;
+        assert_eq!(encoded.as_str(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "cosmwasm_1_4")]
+    fn serialize_pay_packet_fee_msgs() {
+        let fee = IbcFee {
+            receive_fee: vec![Coin::new(100, "ucosm")],
+            ack_fee: vec![Coin::new(201, "ucosm")],
+            timeout_fee: vec![],
+        };
+
+        let msg = IbcMsg::PayPacketFee {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-0".to_string(),
+            fee: fee.clone(),
+            relayers: vec!["relayer1".to_string()],
+        };
+        let encoded = to_string(&msg).unwrap();
+        let expected = r#"{"pay_packet_fee":{"port_id":"transfer","channel_id":"channel-0","fee":{"receive_fee":[{"denom":"ucosm","amount":"100"}],"ack_fee":[{"denom":"ucosm","amount":"201"}],"timeout_fee":[]},"relayers":["relayer1"]}}"#;
+        assert_eq!(encoded.as_str(), expected);
+
+        let msg = IbcMsg::PayPacketFeeAsync {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-0".to_string(),
+            sequence: 42,
+            fee,
+        };
+        let encoded = to_string(&msg).unwrap();
+        let expected = r#"{"pay_packet_fee_async":{"port_id":"transfer","channel_id":"channel-0","sequence":42,"fee":{"receive_fee":[{"denom":"ucosm","amount":"100"}],"ack_fee":[{"denom":"ucosm","amount":"201"}],"timeout_fee":[]}}}"#;
+        assert_eq!(encoded.as_str(), expected);
+    }
+
     #[test]
     fn ibc_timeout_serialize() {
         let timestamp = IbcTimeout::with_timestamp(Timestamp::from_nanos(684816844));
@@ -895,4 +1230,57 @@ mod tests {
         let expected = r#"{"data":"Zm9v","src":{"port_id":"their-port","channel_id":"channel-1234"},"dest":{"port_id":"our-port","channel_id":"chan33"},"sequence":27,"timeout":{"block":{"revision":1,"height":12345678},"timestamp":null}}"#;
         assert_eq!(to_string(&no_timestamp).unwrap(), expected);
     }
+
+    #[test]
+    fn std_ack_works() {
+        // success
+        let ack = StdAck::success(b"\x01");
+        assert!(ack.is_success());
+        assert!(!ack.is_error());
+        assert_eq!(ack.clone().unwrap(), Binary::from(b"\x01"));
+        assert_eq!(ack.to_binary(), br#"{"result":"AQ=="}"#);
+
+        // error
+        let ack = StdAck::error("kaputt");
+        assert!(!ack.is_success());
+        assert!(ack.is_error());
+        assert_eq!(ack.clone().unwrap_err(), "kaputt");
+        assert_eq!(ack.to_binary(), br#"{"error":"kaputt"}"#);
+    }
+
+    #[test]
+    fn std_ack_matches_ics20_ack() {
+        // From ibc-go's `channeltypes.NewResultAcknowledgement([]byte{byte(1)})`
+        // and `channeltypes.NewErrorAcknowledgement(err)` as sent by the transfer module
+        let success: StdAck = crate::from_slice(br#"{"result":"AQ=="}"#).unwrap();
+        assert_eq!(success, StdAck::success(b"\x01"));
+        let error: StdAck = crate::from_slice(
+            br#"{"error":"ABCI code: 1: error handling packet: see events for details"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            error,
+            StdAck::error("ABCI code: 1: error handling packet: see events for details")
+        );
+    }
+
+    #[test]
+    fn std_ack_converts_into_ibc_acknowledgement() {
+        let ack: IbcAcknowledgement = StdAck::success(b"\x01").into();
+        assert_eq!(ack, IbcAcknowledgement::new(br#"{"result":"AQ=="}"#));
+
+        let ack = IbcAcknowledgement::from(StdAck::error("kaputt"));
+        assert_eq!(ack.data, br#"{"error":"kaputt"}"#);
+    }
+
+    #[test]
+    fn ibc_receive_response_set_ack_works_with_std_ack() {
+        let response: IbcReceiveResponse =
+            IbcReceiveResponse::new().set_ack(StdAck::error("oh no"));
+        assert_eq!(response.acknowledgement, br#"{"error":"oh no"}"#);
+
+        let response: IbcReceiveResponse =
+            IbcReceiveResponse::new().set_ack(StdAck::success(b"\x01"));
+        assert_eq!(response.acknowledgement, br#"{"result":"AQ=="}"#);
+    }
 }
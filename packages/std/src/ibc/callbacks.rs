@@ -0,0 +1,263 @@
+//! This module contains types for the IBC callbacks defined in
+//! [ADR-8](https://github.com/cosmos/ibc-go/blob/main/docs/architecture/adr-008-app-caller-cbs.md).
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::addresses::Addr;
+use crate::errors::StdResult;
+use crate::math::Uint64;
+use crate::serde::to_vec;
+
+use super::{IbcAcknowledgement, IbcPacket};
+
+/// This is just a type representing the data that has to be sent with the IBC message to receive
+/// callbacks. It should be serialized and sent with the IBC message.
+/// The specific field and format to send it in can vary depending on the IBC message,
+/// but is usually the `memo` field by default.
+///
+/// See [`IbcSourceCallbackMsg`] and [`IbcDestinationCallbackMsg`] for more details.
+///
+/// # Example
+///
+/// ```
+/// # use cosmwasm_std::{Addr, IbcCallbackRequest, IbcSrcCallback};
+/// let memo = IbcCallbackRequest::source(IbcSrcCallback {
+///     address: Addr::unchecked("cosmos2contract"),
+///     gas_limit: None,
+/// })
+/// .to_memo()
+/// .unwrap();
+/// assert_eq!(memo, r#"{"src_callback":{"address":"cosmos2contract"}}"#);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcCallbackRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    src_callback: Option<IbcSrcCallback>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dest_callback: Option<IbcDstCallback>,
+}
+
+impl IbcCallbackRequest {
+    /// Use this if you want to execute IBC callbacks on both the source and destination chain.
+    pub fn both(src_callback: IbcSrcCallback, dest_callback: IbcDstCallback) -> Self {
+        IbcCallbackRequest {
+            src_callback: Some(src_callback),
+            dest_callback: Some(dest_callback),
+        }
+    }
+
+    /// Use this if you want to execute IBC callbacks on the source chain only.
+    pub fn source(src_callback: IbcSrcCallback) -> Self {
+        IbcCallbackRequest {
+            src_callback: Some(src_callback),
+            dest_callback: None,
+        }
+    }
+
+    /// Use this if you want to execute IBC callbacks on the destination chain only.
+    pub fn destination(dest_callback: IbcDstCallback) -> Self {
+        IbcCallbackRequest {
+            src_callback: None,
+            dest_callback: Some(dest_callback),
+        }
+    }
+
+    /// Serializes the request to the JSON string that is expected in the packet's memo field.
+    pub fn to_memo(&self) -> StdResult<String> {
+        let json = to_vec(self)?;
+        // serde-json-wasm always produces valid UTF-8
+        Ok(String::from_utf8(json).expect("JSON is valid UTF-8"))
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcSrcCallback {
+    /// The source chain address that should receive the callback.
+    /// You probably want to put `env.contract.address` here.
+    pub address: Addr,
+    /// Optional gas limit for the callback (in Cosmos SDK gas units)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_limit: Option<Uint64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcDstCallback {
+    /// The destination chain address that should receive the callback.
+    pub address: String,
+    /// Optional gas limit for the callback (in Cosmos SDK gas units)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_limit: Option<Uint64>,
+}
+
+/// The type of IBC source callback that is being called.
+///
+/// IBC source callbacks are needed for cases where your contract triggers the sending of an
+/// IBC packet through some other message (i.e. not through [`IbcMsg::SendPacket`]) and needs to
+/// know whether or not the packet was successfully received on the other chain.
+/// A prominent example is the [`IbcMsg::Transfer`] message. Without callbacks, you cannot know
+/// whether the transfer was successful or not.
+///
+/// Note that there are some prerequisites that need to be fulfilled to receive source callbacks:
+/// - The contract must implement the `ibc_source_callback` entrypoint.
+/// - The IBC application in the source chain must have support for the callbacks middleware.
+/// - You have to add serialized [`IbcCallbackRequest`] to a specific field of the message.
+///   For transfers, this is the `memo` field of `IbcMsg::TransferWithMemo`.
+///
+/// [`IbcMsg::SendPacket`]: crate::IbcMsg::SendPacket
+/// [`IbcMsg::Transfer`]: crate::IbcMsg::Transfer
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IbcSourceCallbackMsg {
+    Acknowledgement(IbcAckCallbackMsg),
+    Timeout(IbcTimeoutCallbackMsg),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[non_exhaustive]
+pub struct IbcAckCallbackMsg {
+    pub acknowledgement: IbcAcknowledgement,
+    pub original_packet: IbcPacket,
+    pub relayer: Addr,
+}
+
+impl IbcAckCallbackMsg {
+    pub fn new(
+        acknowledgement: IbcAcknowledgement,
+        original_packet: IbcPacket,
+        relayer: Addr,
+    ) -> Self {
+        Self {
+            acknowledgement,
+            original_packet,
+            relayer,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[non_exhaustive]
+pub struct IbcTimeoutCallbackMsg {
+    pub packet: IbcPacket,
+    pub relayer: Addr,
+}
+
+impl IbcTimeoutCallbackMsg {
+    pub fn new(packet: IbcPacket, relayer: Addr) -> Self {
+        Self { packet, relayer }
+    }
+}
+
+/// The message type of the IBC destination callback.
+///
+/// The IBC destination callback is needed for cases where someone triggers the sending of an
+/// IBC packet through some other message (i.e. not through [`IbcMsg::SendPacket`]) and
+/// your contract needs to know that it received this.
+/// A prominent example is the [`IbcMsg::Transfer`] message. Without callbacks, you cannot know
+/// that someone sent you IBC coins.
+///
+/// Note that there are some prerequisites that need to be fulfilled to receive destination callbacks:
+/// - The contract must implement the `ibc_destination_callback` entrypoint.
+/// - The IBC application in the destination chain must have support for the callbacks middleware.
+/// - You have to add serialized [`IbcCallbackRequest`] to a specific field of the message.
+///   For transfers, this is the `memo` field of `IbcMsg::TransferWithMemo`.
+///
+/// [`IbcMsg::SendPacket`]: crate::IbcMsg::SendPacket
+/// [`IbcMsg::Transfer`]: crate::IbcMsg::Transfer
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[non_exhaustive]
+pub struct IbcDestinationCallbackMsg {
+    pub packet: IbcPacket,
+    pub ack: IbcAcknowledgement,
+}
+
+impl IbcDestinationCallbackMsg {
+    pub fn new(packet: IbcPacket, ack: IbcAcknowledgement) -> Self {
+        Self { packet, ack }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_slice, IbcEndpoint, IbcTimeout, Timestamp};
+
+    fn packet() -> IbcPacket {
+        IbcPacket::new(
+            b"data",
+            IbcEndpoint {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-0".to_string(),
+            },
+            IbcEndpoint {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-1".to_string(),
+            },
+            7,
+            IbcTimeout::with_timestamp(Timestamp::from_nanos(1000)),
+        )
+    }
+
+    #[test]
+    fn ibc_callback_request_serialization_works() {
+        let request = IbcCallbackRequest::both(
+            IbcSrcCallback {
+                address: Addr::unchecked("src_address"),
+                gas_limit: Some(123456u64.into()),
+            },
+            IbcDstCallback {
+                address: "dst_address".to_string(),
+                gas_limit: None,
+            },
+        );
+        assert_eq!(
+            request.to_memo().unwrap(),
+            r#"{"src_callback":{"address":"src_address","gas_limit":"123456"},"dest_callback":{"address":"dst_address"}}"#
+        );
+
+        let request = IbcCallbackRequest::destination(IbcDstCallback {
+            address: "dst_address".to_string(),
+            gas_limit: Some(777u64.into()),
+        });
+        assert_eq!(
+            request.to_memo().unwrap(),
+            r#"{"dest_callback":{"address":"dst_address","gas_limit":"777"}}"#
+        );
+    }
+
+    #[test]
+    fn ibc_source_callback_msg_serialization_works() {
+        let msg = IbcSourceCallbackMsg::Timeout(IbcTimeoutCallbackMsg::new(
+            packet(),
+            Addr::unchecked("relayer"),
+        ));
+        let json = to_vec(&msg).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"timeout":{"packet":{"data":"ZGF0YQ==","src":{"port_id":"transfer","channel_id":"channel-0"},"dest":{"port_id":"transfer","channel_id":"channel-1"},"sequence":7,"timeout":{"block":null,"timestamp":"1000"}},"relayer":"relayer"}}"#
+        );
+        let parsed: IbcSourceCallbackMsg = from_slice(&json).unwrap();
+        assert_eq!(parsed, msg);
+
+        let msg = IbcSourceCallbackMsg::Acknowledgement(IbcAckCallbackMsg::new(
+            IbcAcknowledgement::new(b"{}"),
+            packet(),
+            Addr::unchecked("relayer"),
+        ));
+        let json = to_vec(&msg).unwrap();
+        let parsed: IbcSourceCallbackMsg = from_slice(&json).unwrap();
+        assert_eq!(parsed, msg);
+    }
+
+    #[test]
+    fn ibc_destination_callback_msg_serialization_works() {
+        let msg = IbcDestinationCallbackMsg::new(packet(), IbcAcknowledgement::new(b"{}"));
+        let json = to_vec(&msg).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"packet":{"data":"ZGF0YQ==","src":{"port_id":"transfer","channel_id":"channel-0"},"dest":{"port_id":"transfer","channel_id":"channel-1"},"sequence":7,"timeout":{"block":null,"timestamp":"1000"}},"ack":{"data":"e30="}}"#
+        );
+        let parsed: IbcDestinationCallbackMsg = from_slice(&json).unwrap();
+        assert_eq!(parsed, msg);
+    }
+}
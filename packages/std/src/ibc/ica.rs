@@ -0,0 +1,458 @@
+//! Types for the controller side of Interchain Accounts as defined in
+//! [ICS-27](https://github.com/cosmos/ibc/tree/main/spec/app/ics-027-interchain-accounts).
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::binary::Binary;
+use crate::errors::{StdError, StdResult};
+
+/// A protobuf `Any` encoded Cosmos SDK message, i.e. a type URL and the protobuf encoded
+/// message bytes.
+///
+/// This is used to describe the messages an interchain account executes on the host chain.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AnyMsg {
+    /// The type URL of the message, e.g. "/cosmos.bank.v1beta1.MsgSend"
+    pub type_url: String,
+    /// The protobuf encoded message
+    pub value: Binary,
+}
+
+impl AnyMsg {
+    pub fn new(type_url: impl Into<String>, value: impl Into<Binary>) -> Self {
+        AnyMsg {
+            type_url: type_url.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// The type of an interchain accounts packet
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+pub enum IcaPacketType {
+    #[serde(rename = "TYPE_UNSPECIFIED")]
+    Unspecified,
+    /// Execute a transaction on an interchain accounts host chain
+    #[serde(rename = "TYPE_EXECUTE_TX")]
+    ExecuteTx,
+}
+
+/// The packet data sent from the controller to the host chain
+/// (see [InterchainAccountPacketData](https://github.com/cosmos/ibc-go/blob/v7.0.0/proto/ibc/applications/interchain_accounts/v1/packet.proto#L20-L25)).
+///
+/// For an [`IcaPacketType::ExecuteTx`] packet, `data` contains a protobuf encoded
+/// `CosmosTx` holding the messages the interchain account executes atomically.
+///
+/// # Example
+///
+/// ```
+/// # use cosmwasm_std::{AnyMsg, IcaPacketData};
+/// let msgs = vec![AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", b"\x0a\x03abc".to_vec())];
+/// let packet = IcaPacketData::execute_tx(&msgs, "");
+/// assert_eq!(packet.messages().unwrap(), msgs);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IcaPacketData {
+    #[serde(rename = "type")]
+    pub packet_type: IcaPacketType,
+    pub data: Binary,
+    pub memo: String,
+}
+
+impl IcaPacketData {
+    /// Creates packet data that executes the given messages on the host chain
+    pub fn execute_tx(msgs: &[AnyMsg], memo: impl Into<String>) -> Self {
+        // CosmosTx { repeated google.protobuf.Any messages = 1; }
+        let mut data = Vec::new();
+        for msg in msgs {
+            proto::encode_bytes(1, &proto::encode_any(msg), &mut data);
+        }
+        IcaPacketData {
+            packet_type: IcaPacketType::ExecuteTx,
+            data: data.into(),
+            memo: memo.into(),
+        }
+    }
+
+    /// Decodes the messages contained in `data`
+    pub fn messages(&self) -> StdResult<Vec<AnyMsg>> {
+        proto::decode_fields(&self.data)?
+            .into_iter()
+            .filter(|(number, _)| *number == 1)
+            .map(|(_, field)| proto::decode_any(field.bytes()?))
+            .collect()
+    }
+}
+
+/// The acknowledgement written by the host chain for an interchain accounts packet.
+///
+/// This uses the same JSON format as the
+/// [standard acknowledgement envelope](https://github.com/cosmos/ibc/tree/main/spec/core/ics-004-channel-and-packet-semantics#acknowledgement-envelope),
+/// so it can be parsed from the `data` of an [`IbcAcknowledgement`](crate::IbcAcknowledgement)
+/// with [`from_binary`](crate::from_binary).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IcaAcknowledgement {
+    /// The transaction was executed successfully.
+    /// Contains the protobuf encoded `TxMsgData` of the transaction.
+    Result(Binary),
+    /// The transaction failed. Note that the host chain deliberately redacts the error details.
+    Error(String),
+}
+
+impl IcaAcknowledgement {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, IcaAcknowledgement::Result(_))
+    }
+
+    /// Decodes the responses of the executed messages in the order they were sent.
+    ///
+    /// Returns an error if the transaction failed.
+    pub fn msg_responses(&self) -> StdResult<Vec<AnyMsg>> {
+        let data = match self {
+            IcaAcknowledgement::Result(data) => data,
+            IcaAcknowledgement::Error(err) => {
+                return Err(StdError::generic_err(format!(
+                    "Interchain account transaction failed: {}",
+                    err
+                )))
+            }
+        };
+        // TxMsgData { repeated MsgData data = 1 [deprecated]; repeated google.protobuf.Any msg_responses = 2; }
+        // Chains before Cosmos SDK 0.46 only set the deprecated `data` field with `MsgData { string msg_type = 1; bytes data = 2; }`.
+        let mut legacy = Vec::new();
+        let mut responses = Vec::new();
+        for (number, field) in proto::decode_fields(data)? {
+            match number {
+                1 => {
+                    let mut msg_type = String::new();
+                    let mut value = Vec::new();
+                    for (number, field) in proto::decode_fields(field.bytes()?)? {
+                        match number {
+                            1 => msg_type = proto::to_string(field.bytes()?)?,
+                            2 => value = field.bytes()?.to_vec(),
+                            _ => {}
+                        }
+                    }
+                    legacy.push(AnyMsg::new(msg_type, value));
+                }
+                2 => responses.push(proto::decode_any(field.bytes()?)?),
+                _ => {}
+            }
+        }
+        if responses.is_empty() {
+            Ok(legacy)
+        } else {
+            Ok(responses)
+        }
+    }
+}
+
+/// The response of [`IbcMsg::RegisterInterchainAccount`](crate::IbcMsg::RegisterInterchainAccount).
+///
+/// Use [`RegisterInterchainAccountResponse::from_reply_data`] to parse it from the `data`
+/// of a [`SubMsgResponse`](crate::SubMsgResponse).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct RegisterInterchainAccountResponse {
+    /// The channel that is being opened for the interchain account
+    pub channel_id: String,
+    /// The controller port of the interchain account
+    pub port_id: String,
+}
+
+impl RegisterInterchainAccountResponse {
+    /// Parses the protobuf encoded `MsgRegisterInterchainAccountResponse` from the data
+    /// of a submessage reply
+    pub fn from_reply_data(data: &[u8]) -> StdResult<Self> {
+        // MsgRegisterInterchainAccountResponse { string channel_id = 1; string port_id = 2; }
+        let mut res = RegisterInterchainAccountResponse {
+            channel_id: String::new(),
+            port_id: String::new(),
+        };
+        for (number, field) in proto::decode_fields(data)? {
+            match number {
+                1 => res.channel_id = proto::to_string(field.bytes()?)?,
+                2 => res.port_id = proto::to_string(field.bytes()?)?,
+                _ => {}
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// The response of [`IbcMsg::SendInterchainTx`](crate::IbcMsg::SendInterchainTx).
+///
+/// Use [`SendInterchainTxResponse::from_reply_data`] to parse it from the `data`
+/// of a [`SubMsgResponse`](crate::SubMsgResponse).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SendInterchainTxResponse {
+    /// The sequence of the sent packet. Use this to correlate the
+    /// acknowledgement or timeout with the transaction.
+    pub sequence: u64,
+}
+
+impl SendInterchainTxResponse {
+    /// Parses the protobuf encoded `MsgSendTxResponse` from the data of a submessage reply
+    pub fn from_reply_data(data: &[u8]) -> StdResult<Self> {
+        // MsgSendTxResponse { uint64 sequence = 1; }
+        let mut sequence = 0;
+        for (number, field) in proto::decode_fields(data)? {
+            if number == 1 {
+                sequence = field.varint()?;
+            }
+        }
+        Ok(SendInterchainTxResponse { sequence })
+    }
+}
+
+/// A minimal protobuf encoder/decoder for the few simple message types needed here.
+/// This avoids pulling a full protobuf implementation into contracts.
+mod proto {
+    use super::AnyMsg;
+    use crate::errors::{StdError, StdResult};
+
+    const WIRE_TYPE_VARINT: u64 = 0;
+    const WIRE_TYPE_FIXED64: u64 = 1;
+    const WIRE_TYPE_LENGTH_DELIMITED: u64 = 2;
+    const WIRE_TYPE_FIXED32: u64 = 5;
+
+    pub enum Field<'a> {
+        Varint(u64),
+        Bytes(&'a [u8]),
+        Fixed,
+    }
+
+    impl<'a> Field<'a> {
+        pub fn bytes(&self) -> StdResult<&'a [u8]> {
+            match self {
+                Field::Bytes(bytes) => Ok(bytes),
+                _ => Err(parse_err("expected length-delimited field")),
+            }
+        }
+
+        pub fn varint(&self) -> StdResult<u64> {
+            match self {
+                Field::Varint(value) => Ok(*value),
+                _ => Err(parse_err("expected varint field")),
+            }
+        }
+    }
+
+    fn parse_err(msg: &str) -> StdError {
+        StdError::parse_err("protobuf", msg)
+    }
+
+    fn encode_varint(mut value: u64, out: &mut Vec<u8>) {
+        while value >= 0x80 {
+            out.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    pub fn encode_bytes(number: u32, bytes: &[u8], out: &mut Vec<u8>) {
+        encode_varint(((number as u64) << 3) | WIRE_TYPE_LENGTH_DELIMITED, out);
+        encode_varint(bytes.len() as u64, out);
+        out.extend_from_slice(bytes);
+    }
+
+    pub fn encode_any(msg: &AnyMsg) -> Vec<u8> {
+        // google.protobuf.Any { string type_url = 1; bytes value = 2; }
+        let mut out = Vec::new();
+        encode_bytes(1, msg.type_url.as_bytes(), &mut out);
+        encode_bytes(2, &msg.value, &mut out);
+        out
+    }
+
+    pub fn decode_any(data: &[u8]) -> StdResult<AnyMsg> {
+        let mut type_url = String::new();
+        let mut value = Vec::new();
+        for (number, field) in decode_fields(data)? {
+            match number {
+                1 => type_url = to_string(field.bytes()?)?,
+                2 => value = field.bytes()?.to_vec(),
+                _ => {}
+            }
+        }
+        Ok(AnyMsg::new(type_url, value))
+    }
+
+    pub fn to_string(bytes: &[u8]) -> StdResult<String> {
+        String::from_utf8(bytes.to_vec()).map_err(|_| parse_err("invalid UTF-8 in string field"))
+    }
+
+    fn decode_varint(data: &mut &[u8]) -> StdResult<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let (&byte, rest) = data
+                .split_first()
+                .ok_or_else(|| parse_err("unexpected end of varint"))?;
+            *data = rest;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte < 0x80 {
+                return Ok(value);
+            }
+        }
+        Err(parse_err("varint too long"))
+    }
+
+    fn take<'a>(data: &mut &'a [u8], len: usize) -> StdResult<&'a [u8]> {
+        if data.len() < len {
+            return Err(parse_err("unexpected end of data"));
+        }
+        let (taken, rest) = data.split_at(len);
+        *data = rest;
+        Ok(taken)
+    }
+
+    /// Decodes all fields of a message as (field number, value) pairs in order of appearance
+    pub fn decode_fields(mut data: &[u8]) -> StdResult<Vec<(u64, Field<'_>)>> {
+        let mut fields = Vec::new();
+        while !data.is_empty() {
+            let key = decode_varint(&mut data)?;
+            let field = match key & 0x7 {
+                WIRE_TYPE_VARINT => Field::Varint(decode_varint(&mut data)?),
+                WIRE_TYPE_FIXED64 => {
+                    take(&mut data, 8)?;
+                    Field::Fixed
+                }
+                WIRE_TYPE_LENGTH_DELIMITED => {
+                    let len = decode_varint(&mut data)?;
+                    let len = usize::try_from(len).map_err(|_| parse_err("length too large"))?;
+                    Field::Bytes(take(&mut data, len)?)
+                }
+                WIRE_TYPE_FIXED32 => {
+                    take(&mut data, 4)?;
+                    Field::Fixed
+                }
+                _ => return Err(parse_err("unsupported wire type")),
+            };
+            fields.push((key >> 3, field));
+        }
+        Ok(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_slice, to_vec};
+
+    #[test]
+    fn ica_packet_data_serializes_to_correct_json() {
+        let packet = IcaPacketData::execute_tx(
+            &[AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", b"abc".to_vec())],
+            "hello",
+        );
+        // CosmosTx with a single Any
+        assert_eq!(
+            packet.data.as_slice(),
+            b"\x0a\x23\x0a\x1c/cosmos.bank.v1beta1.MsgSend\x12\x03abc"
+        );
+        let json = to_vec(&packet).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"type":"TYPE_EXECUTE_TX","data":"CiMKHC9jb3Ntb3MuYmFuay52MWJldGExLk1zZ1NlbmQSA2FiYw==","memo":"hello"}"#
+        );
+        let parsed: IcaPacketData = from_slice(&json).unwrap();
+        assert_eq!(parsed, packet);
+    }
+
+    #[test]
+    fn ica_packet_data_messages_works() {
+        let msgs = vec![
+            AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", vec![7u8; 200]),
+            AnyMsg::new("/cosmos.staking.v1beta1.MsgDelegate", vec![]),
+        ];
+        let packet = IcaPacketData::execute_tx(&msgs, "");
+        assert_eq!(packet.messages().unwrap(), msgs);
+
+        let packet = IcaPacketData::execute_tx(&[], "");
+        assert_eq!(packet.messages().unwrap(), vec![]);
+
+        // truncated data
+        let packet = IcaPacketData {
+            packet_type: IcaPacketType::ExecuteTx,
+            data: Binary::from(b"\x0a\x25\x0a".to_vec()),
+            memo: String::new(),
+        };
+        let err = packet.messages().unwrap_err();
+        assert!(matches!(err, StdError::ParseErr { .. }));
+    }
+
+    #[test]
+    fn ica_acknowledgement_msg_responses_works() {
+        // TxMsgData with msg_responses
+        let mut data = Vec::new();
+        proto::encode_bytes(
+            2,
+            &proto::encode_any(&AnyMsg::new("/cosmos.bank.v1beta1.MsgSendResponse", vec![])),
+            &mut data,
+        );
+        let ack: IcaAcknowledgement =
+            from_slice(format!(r#"{{"result":"{}"}}"#, Binary::from(data)).as_bytes()).unwrap();
+        assert!(ack.is_ok());
+        assert_eq!(
+            ack.msg_responses().unwrap(),
+            vec![AnyMsg::new("/cosmos.bank.v1beta1.MsgSendResponse", vec![])]
+        );
+
+        // legacy TxMsgData with MsgData
+        let mut msg_data = Vec::new();
+        proto::encode_bytes(1, b"/cosmos.bank.v1beta1.MsgSend", &mut msg_data);
+        proto::encode_bytes(2, b"\x01", &mut msg_data);
+        let mut data = Vec::new();
+        proto::encode_bytes(1, &msg_data, &mut data);
+        let ack = IcaAcknowledgement::Result(data.into());
+        assert_eq!(
+            ack.msg_responses().unwrap(),
+            vec![AnyMsg::new("/cosmos.bank.v1beta1.MsgSend", vec![1u8])]
+        );
+
+        let ack: IcaAcknowledgement = from_slice(
+            br#"{"error":"ABCI code: 5: error handling packet: see events for details"}"#,
+        )
+        .unwrap();
+        assert!(!ack.is_ok());
+        let err = ack.msg_responses().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Interchain account transaction failed: ABCI code: 5"));
+    }
+
+    #[test]
+    fn register_interchain_account_response_from_reply_data_works() {
+        let mut data = Vec::new();
+        proto::encode_bytes(1, b"channel-12", &mut data);
+        proto::encode_bytes(2, b"icacontroller-cosmos2contract", &mut data);
+        let res = RegisterInterchainAccountResponse::from_reply_data(&data).unwrap();
+        assert_eq!(
+            res,
+            RegisterInterchainAccountResponse {
+                channel_id: "channel-12".to_string(),
+                port_id: "icacontroller-cosmos2contract".to_string(),
+            }
+        );
+
+        // invalid UTF-8
+        let mut data = Vec::new();
+        proto::encode_bytes(1, b"\xff", &mut data);
+        let err = RegisterInterchainAccountResponse::from_reply_data(&data).unwrap_err();
+        assert!(matches!(err, StdError::ParseErr { .. }));
+    }
+
+    #[test]
+    fn send_interchain_tx_response_from_reply_data_works() {
+        let res = SendInterchainTxResponse::from_reply_data(b"\x08\x96\x01").unwrap();
+        assert_eq!(res, SendInterchainTxResponse { sequence: 150 });
+
+        // empty message has default values
+        let res = SendInterchainTxResponse::from_reply_data(b"").unwrap();
+        assert_eq!(res, SendInterchainTxResponse { sequence: 0 });
+
+        // wrong wire type
+        let err = SendInterchainTxResponse::from_reply_data(b"\x0a\x01\x01").unwrap_err();
+        assert!(matches!(err, StdError::ParseErr { .. }));
+    }
+}
@@ -1,4 +1,7 @@
+use std::convert::Infallible;
+
 use crate::errors::StdError;
+use crate::traits::Storage;
 
 /// A record of a key-value storage that is created through an iterator API.
 /// The first element (key) is always raw binary data. The second element
@@ -30,3 +33,283 @@ impl From<Order> for i32 {
         original as _
     }
 }
+
+/// A bound of a range over storage keys. In contrast to the `start`/`end` arguments
+/// of [`Storage::range`], both ends of a range can be inclusive or exclusive.
+///
+/// The key type defaults to raw bytes. Typed storage helpers can use it with their
+/// own key types and convert it to raw bytes with [`Bound::map`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Bound<K = Vec<u8>> {
+    Inclusive(K),
+    Exclusive(K),
+}
+
+impl<K> Bound<K> {
+    pub fn inclusive(key: impl Into<K>) -> Self {
+        Bound::Inclusive(key.into())
+    }
+
+    pub fn exclusive(key: impl Into<K>) -> Self {
+        Bound::Exclusive(key.into())
+    }
+
+    /// Converts the key of the bound, keeping inclusiveness
+    pub fn map<U>(self, f: impl FnOnce(K) -> U) -> Bound<U> {
+        match self {
+            Bound::Inclusive(key) => Bound::Inclusive(f(key)),
+            Bound::Exclusive(key) => Bound::Exclusive(f(key)),
+        }
+    }
+}
+
+impl Bound<Vec<u8>> {
+    /// Returns the raw key to be used as the inclusive `start` of [`Storage::range`]
+    pub fn to_start_key(&self) -> Vec<u8> {
+        match self {
+            Bound::Inclusive(key) => key.clone(),
+            // the smallest key that is greater than `key`
+            Bound::Exclusive(key) => extend_with_zero(key),
+        }
+    }
+
+    /// Returns the raw key to be used as the exclusive `end` of [`Storage::range`]
+    pub fn to_end_key(&self) -> Vec<u8> {
+        match self {
+            // the smallest key that is greater than `key`
+            Bound::Inclusive(key) => extend_with_zero(key),
+            Bound::Exclusive(key) => key.clone(),
+        }
+    }
+}
+
+fn extend_with_zero(key: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(key.len() + 1);
+    out.extend_from_slice(key);
+    out.push(0);
+    out
+}
+
+/// A page of a paginated list query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Page<T, K = Vec<u8>> {
+    pub items: Vec<T>,
+    /// The key of the last item if there are more items after this page. It can be used
+    /// as the `start_after` value for the next page. `None` if this is the last page.
+    pub next_key: Option<K>,
+}
+
+/// Collects at most `limit` items of the iterator into a page. `key_of` returns
+/// the key of an item, which is used for [`Page::next_key`].
+///
+/// A `limit` of 0 is treated as 1, since an empty page cannot point to the next one.
+/// The iterator must start after the last key of the previous page.
+pub fn collect_page<T, K, E>(
+    iter: impl Iterator<Item = Result<T, E>>,
+    limit: usize,
+    key_of: impl Fn(&T) -> K,
+) -> Result<Page<T, K>, E> {
+    let limit = limit.max(1);
+    // take one more item to find out whether there is a next page
+    let mut items = iter.take(limit + 1).collect::<Result<Vec<_>, E>>()?;
+    let next_key = if items.len() > limit {
+        items.truncate(limit);
+        items.last().map(key_of)
+    } else {
+        None
+    };
+    Ok(Page { items, next_key })
+}
+
+/// Returns the number of items of a page, which is `limit` if set but at most `max_limit`.
+///
+/// The result is at least 1, such that a client sending a limit of 0 still gets a
+/// [`Page::next_key`] to continue with.
+pub fn page_limit(limit: Option<u32>, max_limit: u32) -> usize {
+    limit.unwrap_or(max_limit).min(max_limit).max(1) as usize
+}
+
+/// Loads a page of records from the storage.
+///
+/// The page starts after the key `start_after` in the given order, i.e. for descending
+/// order it contains keys lower than `start_after`. At most `limit` records are returned,
+/// but never more than `max_limit` and at least one if there are records left (see
+/// [`page_limit`]). If `limit` is not set, `max_limit` is used.
+pub fn paginate(
+    storage: &dyn Storage,
+    start_after: Option<&[u8]>,
+    limit: Option<u32>,
+    max_limit: u32,
+    order: Order,
+) -> Page<Record> {
+    let after = start_after.map(Bound::exclusive);
+    let (start, end) = match order {
+        Order::Ascending => (after.map(|b| b.to_start_key()), None),
+        Order::Descending => (None, after.map(|b| b.to_end_key())),
+    };
+    let iter = storage
+        .range(start.as_deref(), end.as_deref(), order)
+        .map(Ok::<_, Infallible>);
+    collect_page(iter, page_limit(limit, max_limit), |(key, _)| key.clone())
+        .unwrap_or_else(|never| match never {})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockStorage;
+
+    fn storage_with(keys: &[&[u8]]) -> MockStorage {
+        let mut storage = MockStorage::new();
+        for key in keys {
+            storage.set(key, b"value");
+        }
+        storage
+    }
+
+    fn keys(page: &Page<Record>) -> Vec<&[u8]> {
+        page.items.iter().map(|(k, _)| k.as_slice()).collect()
+    }
+
+    #[test]
+    fn bound_constructors_work() {
+        assert_eq!(
+            Bound::inclusive(b"a".to_vec()),
+            Bound::Inclusive(b"a".to_vec())
+        );
+        assert_eq!(
+            Bound::<String>::exclusive("a"),
+            Bound::Exclusive("a".to_string())
+        );
+        assert_eq!(
+            Bound::Inclusive(5u32).map(u64::from),
+            Bound::Inclusive(5u64)
+        );
+        assert_eq!(Bound::Exclusive(5u32).map(|k| k + 1), Bound::Exclusive(6));
+    }
+
+    #[test]
+    fn bound_raw_keys_work() {
+        assert_eq!(Bound::inclusive(b"ab".to_vec()).to_start_key(), b"ab");
+        assert_eq!(Bound::exclusive(b"ab".to_vec()).to_start_key(), b"ab\0");
+        assert_eq!(Bound::inclusive(b"ab".to_vec()).to_end_key(), b"ab\0");
+        assert_eq!(Bound::exclusive(b"ab".to_vec()).to_end_key(), b"ab");
+
+        let storage = storage_with(&[b"a", b"ab", b"ab\0", b"ab\x01", b"b"]);
+        let range = |start: Option<Vec<u8>>, end: Option<Vec<u8>>| -> Vec<Vec<u8>> {
+            storage
+                .range(start.as_deref(), end.as_deref(), Order::Ascending)
+                .map(|(k, _)| k)
+                .collect()
+        };
+        let ab = b"ab".to_vec();
+        assert_eq!(
+            range(Some(Bound::exclusive(ab.clone()).to_start_key()), None),
+            vec![b"ab\0".to_vec(), b"ab\x01".to_vec(), b"b".to_vec()]
+        );
+        assert_eq!(
+            range(None, Some(Bound::inclusive(ab.clone()).to_end_key())),
+            vec![b"a".to_vec(), b"ab".to_vec()]
+        );
+        assert_eq!(
+            range(None, Some(Bound::exclusive(ab).to_end_key())),
+            vec![b"a".to_vec()]
+        );
+    }
+
+    #[test]
+    fn collect_page_works() {
+        let items = || (1..=5).map(Ok::<u32, StdError>);
+
+        let page = collect_page(items(), 2, |i| *i).unwrap();
+        assert_eq!(page.items, vec![1, 2]);
+        assert_eq!(page.next_key, Some(2));
+
+        // no next page if the items fit exactly
+        let page = collect_page(items(), 5, |i| *i).unwrap();
+        assert_eq!(page.items, vec![1, 2, 3, 4, 5]);
+        assert_eq!(page.next_key, None);
+
+        // a limit of 0 still makes progress
+        let page = collect_page(items(), 0, |i| *i).unwrap();
+        assert_eq!(page.items, vec![1]);
+        assert_eq!(page.next_key, Some(1));
+
+        // errors are returned
+        let failing = vec![Ok(1), Err(StdError::generic_err("broken"))];
+        let err = collect_page(failing.into_iter(), 5, |i: &u32| *i).unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    fn page_limit_works() {
+        assert_eq!(page_limit(None, 30), 30);
+        assert_eq!(page_limit(Some(10), 30), 10);
+        assert_eq!(page_limit(Some(100), 30), 30);
+        // the limit is at least 1
+        assert_eq!(page_limit(Some(0), 30), 1);
+        assert_eq!(page_limit(None, 0), 1);
+    }
+
+    #[test]
+    fn paginate_ascending() {
+        let storage = storage_with(&[b"a", b"b", b"c", b"d", b"e"]);
+
+        let page = paginate(&storage, None, Some(2), 30, Order::Ascending);
+        assert_eq!(keys(&page), vec![b"a", b"b"]);
+        assert_eq!(page.next_key, Some(b"b".to_vec()));
+
+        let page = paginate(
+            &storage,
+            page.next_key.as_deref(),
+            Some(2),
+            30,
+            Order::Ascending,
+        );
+        assert_eq!(keys(&page), vec![b"c", b"d"]);
+        assert_eq!(page.next_key, Some(b"d".to_vec()));
+
+        let page = paginate(
+            &storage,
+            page.next_key.as_deref(),
+            Some(2),
+            30,
+            Order::Ascending,
+        );
+        assert_eq!(keys(&page), vec![b"e"]);
+        assert_eq!(page.next_key, None);
+
+        // start_after does not need to exist
+        let page = paginate(&storage, Some(b"bb"), None, 30, Order::Ascending);
+        assert_eq!(keys(&page), vec![b"c", b"d", b"e"]);
+
+        // limit is capped by max_limit
+        let page = paginate(&storage, None, Some(100), 3, Order::Ascending);
+        assert_eq!(keys(&page), vec![b"a", b"b", b"c"]);
+        assert_eq!(page.next_key, Some(b"c".to_vec()));
+
+        // a limit of 0 returns one record, such that the client can continue
+        let page = paginate(&storage, Some(b"c"), Some(0), 30, Order::Ascending);
+        assert_eq!(keys(&page), vec![b"d"]);
+        assert_eq!(page.next_key, Some(b"d".to_vec()));
+    }
+
+    #[test]
+    fn paginate_descending() {
+        let storage = storage_with(&[b"a", b"b", b"c", b"d", b"e"]);
+
+        let page = paginate(&storage, None, Some(3), 30, Order::Descending);
+        assert_eq!(keys(&page), vec![b"e", b"d", b"c"]);
+        assert_eq!(page.next_key, Some(b"c".to_vec()));
+
+        let page = paginate(
+            &storage,
+            page.next_key.as_deref(),
+            Some(3),
+            30,
+            Order::Descending,
+        );
+        assert_eq!(keys(&page), vec![b"b", b"a"]);
+        assert_eq!(page.next_key, None);
+    }
+}
@@ -6,15 +6,22 @@ mod addresses;
 mod assertions;
 mod binary;
 mod coin;
+mod coins;
 mod conversion;
+mod deccoin;
+mod deccoins;
 mod deps;
+mod duration;
 mod errors;
+mod expiration;
 mod hex_binary;
 mod ibc;
 mod import_helpers;
 #[cfg(feature = "iterator")]
 mod iterator;
 mod math;
+mod metadata;
+mod pagination;
 mod panic;
 mod query;
 mod results;
@@ -28,26 +35,44 @@ mod types;
 pub use crate::addresses::{instantiate2_address, Addr, CanonicalAddr};
 pub use crate::binary::Binary;
 pub use crate::coin::{coin, coins, has_coins, Coin};
+pub use crate::coins::Coins;
+pub use crate::deccoin::DecCoin;
+pub use crate::deccoins::DecCoins;
 pub use crate::deps::{Deps, DepsMut, OwnedDeps};
+pub use crate::duration::Duration;
 pub use crate::errors::{
-    CheckedFromRatioError, CheckedMultiplyRatioError, ConversionOverflowError, DivideByZeroError,
-    OverflowError, OverflowOperation, RecoverPubkeyError, StdError, StdResult, SystemError,
-    VerificationError,
+    CheckedFromRatioError, CheckedMultiplyRatioError, CoinFromStrError, CoinsError,
+    ConversionOverflowError, DivideByZeroError, DivisionError, LogarithmOfZeroError, OverflowError,
+    OverflowOperation, RecoverPubkeyError, RoundDownOverflowError, RoundUpOverflowError, StdError,
+    StdResult, SystemError, VerificationError,
 };
+pub use crate::expiration::Expiration;
 pub use crate::hex_binary::HexBinary;
+#[cfg(all(feature = "stargate", feature = "cosmwasm_1_4"))]
+pub use crate::ibc::{
+    AnyMsg, IbcFee, IcaAcknowledgement, IcaPacketData, IcaPacketType,
+    RegisterInterchainAccountResponse, SendInterchainTxResponse,
+};
 #[cfg(feature = "stargate")]
 pub use crate::ibc::{
-    Ibc3ChannelOpenResponse, IbcAcknowledgement, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
-    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcEndpoint, IbcMsg, IbcOrder,
-    IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse,
-    IbcTimeout, IbcTimeoutBlock,
+    Ibc3ChannelOpenResponse, IbcAckCallbackMsg, IbcAcknowledgement, IbcBasicResponse,
+    IbcCallbackRequest, IbcChannel, IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg,
+    IbcChannelOpenResponse, IbcDestinationCallbackMsg, IbcDstCallback, IbcEndpoint, IbcMsg,
+    IbcOrder, IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg,
+    IbcReceiveResponse, IbcSourceCallbackMsg, IbcSrcCallback, IbcTimeout, IbcTimeoutBlock,
+    IbcTimeoutCallbackMsg, StdAck,
 };
 #[cfg(feature = "iterator")]
-pub use crate::iterator::{Order, Record};
+pub use crate::iterator::{collect_page, page_limit, paginate, Bound, Order, Page, Record};
 pub use crate::math::{
-    Decimal, Decimal256, Decimal256RangeExceeded, DecimalRangeExceeded, Fraction, Isqrt, Uint128,
-    Uint256, Uint512, Uint64,
+    Decimal, Decimal256, Decimal256RangeExceeded, DecimalRangeExceeded, Fraction, Int128, Int256,
+    Int512, Int64, Isqrt, SignedDecimal, SignedDecimal256, SignedDecimal256RangeExceeded,
+    SignedDecimalRangeExceeded, Uint128, Uint256, Uint512, Uint64,
 };
+pub use crate::metadata::{DenomMetadata, DenomUnit};
+pub use crate::pagination::{PageRequest, PageResponse};
+#[cfg(feature = "cosmwasm_1_2")]
+pub use crate::query::CodeInfoResponse;
 #[cfg(feature = "cosmwasm_1_1")]
 pub use crate::query::SupplyResponse;
 pub use crate::query::{
@@ -59,8 +84,21 @@ pub use crate::query::{
     AllDelegationsResponse, AllValidatorsResponse, BondedDenomResponse, Delegation,
     DelegationResponse, FullDelegation, StakingQuery, Validator, ValidatorResponse,
 };
+#[cfg(feature = "cosmwasm_1_3")]
+pub use crate::query::{
+    AllDenomMetadataResponse, DelegationRewardsResponse, DelegationTotalRewardsResponse,
+    DelegatorReward, DelegatorValidatorsResponse, DelegatorWithdrawAddressResponse,
+    DenomMetadataResponse, DistributionQuery,
+};
 #[cfg(feature = "stargate")]
 pub use crate::query::{ChannelResponse, IbcQuery, ListChannelsResponse, PortIdResponse};
+#[cfg(all(feature = "stargate", feature = "cosmwasm_1_4"))]
+pub use crate::query::{
+    ConnectionResponse, CounterpartyResponse, FeeEnabledChannelResponse, IbcConnection,
+    NextSequenceSendResponse,
+};
+#[cfg(feature = "cosmwasm_1_2")]
+pub use crate::results::wasm_instantiate2;
 #[allow(deprecated)]
 pub use crate::results::SubMsgExecutionResponse;
 #[cfg(all(feature = "stargate", feature = "cosmwasm_1_2"))]
@@ -93,8 +131,8 @@ mod memory; // Used by exports and imports only. This assumes pointers are 32 bi
 pub use crate::exports::{do_execute, do_instantiate, do_migrate, do_query, do_reply, do_sudo};
 #[cfg(all(feature = "stargate", target_arch = "wasm32"))]
 pub use crate::exports::{
-    do_ibc_channel_close, do_ibc_channel_connect, do_ibc_channel_open, do_ibc_packet_ack,
-    do_ibc_packet_receive, do_ibc_packet_timeout,
+    do_ibc_channel_close, do_ibc_channel_connect, do_ibc_channel_open, do_ibc_destination_callback,
+    do_ibc_packet_ack, do_ibc_packet_receive, do_ibc_packet_timeout, do_ibc_source_callback,
 };
 #[cfg(target_arch = "wasm32")]
 pub use crate::imports::{ExternalApi, ExternalQuerier, ExternalStorage};
@@ -0,0 +1,175 @@
+//! Conversions between the integer types of this module.
+//!
+//! All of them work on the big endian byte representation, which makes it possible
+//! to implement them once for every combination of sizes.
+
+/// Resizes a big endian byte representation by adding or removing leading bytes.
+///
+/// `fill` is the byte used for padding when growing (0x00 for unsigned and non-negative
+/// values, 0xff for negative values in two's complement). When shrinking, all removed
+/// bytes must be equal to `fill`, otherwise `None` is returned.
+pub(crate) fn resize_be_bytes<const INPUT: usize, const OUTPUT: usize>(
+    input: [u8; INPUT],
+    fill: u8,
+) -> Option<[u8; OUTPUT]> {
+    let mut output = [fill; OUTPUT];
+    if OUTPUT >= INPUT {
+        output[OUTPUT - INPUT..].copy_from_slice(&input);
+    } else {
+        if input[..INPUT - OUTPUT].iter().any(|byte| *byte != fill) {
+            return None;
+        }
+        output.copy_from_slice(&input[INPUT - OUTPUT..]);
+    }
+    Some(output)
+}
+
+/// Returns true if the most significant bit of a big endian representation is set
+pub(crate) fn is_sign_bit_set(be_bytes: &[u8]) -> bool {
+    be_bytes[0] & 0x80 != 0
+}
+
+/// Lossless conversion from an unsigned to a larger signed type.
+macro_rules! from_uint_to_int {
+    ($input: ident, $output: ident) => {
+        impl From<$input> for $output {
+            fn from(value: $input) -> Self {
+                let bytes = crate::math::conversion::resize_be_bytes(value.to_be_bytes(), 0)
+                    .expect("growing never fails");
+                Self::from_be_bytes(bytes)
+            }
+        }
+    };
+}
+
+/// Checked conversion from an unsigned to a signed type that is not larger.
+macro_rules! try_from_uint_to_int {
+    ($input: ident, $output: ident) => {
+        impl TryFrom<$input> for $output {
+            type Error = crate::ConversionOverflowError;
+
+            fn try_from(value: $input) -> Result<Self, Self::Error> {
+                match crate::math::conversion::resize_be_bytes(value.to_be_bytes(), 0) {
+                    Some(bytes) if !crate::math::conversion::is_sign_bit_set(&bytes) => {
+                        Ok(Self::from_be_bytes(bytes))
+                    }
+                    _ => Err(crate::ConversionOverflowError::new(
+                        stringify!($input),
+                        stringify!($output),
+                        value.to_string(),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+/// Checked conversion from a signed to an unsigned type of any size.
+/// This fails for negative values.
+macro_rules! try_from_int_to_uint {
+    ($input: ident, $output: ident) => {
+        impl TryFrom<$input> for $output {
+            type Error = crate::ConversionOverflowError;
+
+            fn try_from(value: $input) -> Result<Self, Self::Error> {
+                let bytes = value.to_be_bytes();
+                let resized = if crate::math::conversion::is_sign_bit_set(&bytes) {
+                    None
+                } else {
+                    crate::math::conversion::resize_be_bytes(bytes, 0)
+                };
+                match resized {
+                    Some(bytes) => Ok(Self::from_be_bytes(bytes)),
+                    None => Err(crate::ConversionOverflowError::new(
+                        stringify!($input),
+                        stringify!($output),
+                        value.to_string(),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+/// Lossless conversion from a signed to a larger signed type.
+macro_rules! from_int_to_int {
+    ($input: ident, $output: ident) => {
+        impl From<$input> for $output {
+            fn from(value: $input) -> Self {
+                let bytes = value.to_be_bytes();
+                let fill = if crate::math::conversion::is_sign_bit_set(&bytes) {
+                    0xff
+                } else {
+                    0
+                };
+                let bytes = crate::math::conversion::resize_be_bytes(bytes, fill)
+                    .expect("growing never fails");
+                Self::from_be_bytes(bytes)
+            }
+        }
+    };
+}
+
+/// Checked conversion from a signed to a smaller signed type.
+macro_rules! try_from_int_to_int {
+    ($input: ident, $output: ident) => {
+        impl TryFrom<$input> for $output {
+            type Error = crate::ConversionOverflowError;
+
+            fn try_from(value: $input) -> Result<Self, Self::Error> {
+                let bytes = value.to_be_bytes();
+                let negative = crate::math::conversion::is_sign_bit_set(&bytes);
+                let fill = if negative { 0xff } else { 0 };
+                match crate::math::conversion::resize_be_bytes(bytes, fill) {
+                    Some(bytes) if crate::math::conversion::is_sign_bit_set(&bytes) == negative => {
+                        Ok(Self::from_be_bytes(bytes))
+                    }
+                    _ => Err(crate::ConversionOverflowError::new(
+                        stringify!($input),
+                        stringify!($output),
+                        value.to_string(),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use from_int_to_int;
+pub(crate) use from_uint_to_int;
+pub(crate) use try_from_int_to_int;
+pub(crate) use try_from_int_to_uint;
+pub(crate) use try_from_uint_to_int;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resize_be_bytes_works() {
+        // grow
+        assert_eq!(resize_be_bytes([1, 2], 0), Some([0, 0, 1, 2]));
+        assert_eq!(
+            resize_be_bytes([0xff, 0xfe], 0xff),
+            Some([0xff, 0xff, 0xff, 0xfe])
+        );
+        assert_eq!(resize_be_bytes([1, 2], 0), Some([1, 2]));
+
+        // shrink
+        assert_eq!(resize_be_bytes([0, 0, 1, 2], 0), Some([1, 2]));
+        assert_eq!(
+            resize_be_bytes([0xff, 0xff, 0x80, 0], 0xff),
+            Some([0x80, 0])
+        );
+        assert_eq!(resize_be_bytes::<4, 2>([0, 1, 1, 2], 0), None);
+        assert_eq!(resize_be_bytes::<4, 2>([0, 0, 1, 2], 0xff), None);
+    }
+
+    #[test]
+    fn is_sign_bit_set_works() {
+        assert!(!is_sign_bit_set(&[0, 0xff]));
+        assert!(!is_sign_bit_set(&[0x7f, 0xff]));
+        assert!(is_sign_bit_set(&[0x80, 0]));
+        assert!(is_sign_bit_set(&[0xff, 0xff]));
+    }
+}
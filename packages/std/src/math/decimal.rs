@@ -8,13 +8,14 @@ use std::str::FromStr;
 use thiserror::Error;
 
 use crate::errors::{
-    CheckedFromRatioError, CheckedMultiplyRatioError, DivideByZeroError, OverflowError,
-    OverflowOperation, RoundUpOverflowError, StdError,
+    CheckedFromRatioError, CheckedMultiplyRatioError, DivideByZeroError, LogarithmOfZeroError,
+    OverflowError, OverflowOperation, RoundUpOverflowError, StdError,
 };
 
+use super::transcendental;
 use super::Fraction;
 use super::Isqrt;
-use super::{Uint128, Uint256};
+use super::{SignedDecimal, Uint128, Uint256};
 
 /// A fixed-point decimal value with 18 fractional digits, i.e. Decimal(1_000_000_000_000_000_000) == 1.0
 ///
@@ -326,6 +327,131 @@ impl Decimal {
         })
     }
 
+    /// Returns the natural logarithm as a [`SignedDecimal`], since it is negative
+    /// for values below 1.
+    ///
+    /// The calculation only uses integer arithmetic and is therefore deterministic.
+    /// The absolute error before rounding is below 10^-55. The result is then rounded
+    /// to the nearest value with 18 decimal places.
+    ///
+    /// Panics for zero. See [`Decimal::checked_ln`] for a non-panicking version.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::{Decimal, SignedDecimal};
+    /// # use std::str::FromStr;
+    /// let x = Decimal::from_str("2").unwrap();
+    /// assert_eq!(x.ln().to_string(), "0.693147180559945309");
+    ///
+    /// let x = Decimal::percent(50);
+    /// assert_eq!(x.ln().to_string(), "-0.693147180559945309");
+    /// ```
+    pub fn ln(self) -> SignedDecimal {
+        match self.checked_ln() {
+            Ok(value) => value,
+            Err(_) => panic!("attempt to calculate the logarithm of zero"),
+        }
+    }
+
+    /// Returns the natural logarithm, or a [`LogarithmOfZeroError`] if `self` is zero.
+    ///
+    /// See [`Decimal::ln`] for details.
+    pub fn checked_ln(self) -> Result<SignedDecimal, LogarithmOfZeroError> {
+        transcendental::ln(self.0.into())
+            // |ln(x)| < 48 for all decimals, so this always fits
+            .map(|atomics| SignedDecimal::new(atomics.try_into().unwrap()))
+            .ok_or(LogarithmOfZeroError)
+    }
+
+    /// Returns the base 2 logarithm as a [`SignedDecimal`], since it is negative
+    /// for values below 1.
+    ///
+    /// The calculation only uses integer arithmetic and is therefore deterministic.
+    /// The absolute error before rounding is below 10^-55. The result is then rounded
+    /// to the nearest value with 18 decimal places.
+    ///
+    /// Panics for zero. See [`Decimal::checked_log2`] for a non-panicking version.
+    pub fn log2(self) -> SignedDecimal {
+        match self.checked_log2() {
+            Ok(value) => value,
+            Err(_) => panic!("attempt to calculate the logarithm of zero"),
+        }
+    }
+
+    /// Returns the base 2 logarithm, or a [`LogarithmOfZeroError`] if `self` is zero.
+    ///
+    /// See [`Decimal::log2`] for details.
+    pub fn checked_log2(self) -> Result<SignedDecimal, LogarithmOfZeroError> {
+        transcendental::log2(self.0.into())
+            // |log2(x)| < 69 for all decimals, so this always fits
+            .map(|atomics| SignedDecimal::new(atomics.try_into().unwrap()))
+            .ok_or(LogarithmOfZeroError)
+    }
+
+    /// Returns e^self, panics if an overflow occurred.
+    ///
+    /// The calculation only uses integer arithmetic and is therefore deterministic.
+    /// The relative error before rounding is below 10^-55. The result is then rounded
+    /// to the nearest value with 18 decimal places.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::Decimal;
+    /// assert_eq!(Decimal::one().exp().to_string(), "2.718281828459045235");
+    /// ```
+    pub fn exp(self) -> Self {
+        match self.checked_exp() {
+            Ok(value) => value,
+            Err(_) => panic!("attempt to exponentiate with overflow"),
+        }
+    }
+
+    /// Returns e^self, or an `OverflowError` if the result exceeds [`Decimal::MAX`].
+    ///
+    /// See [`Decimal::exp`] for details.
+    pub fn checked_exp(self) -> Result<Self, OverflowError> {
+        transcendental::exp(self.0.into())
+            .and_then(|atomics| atomics.try_into().ok())
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Pow, "e", self))
+    }
+
+    /// Raises a value to the power of a decimal `exp`, panics if an overflow occurred.
+    /// Use [`Decimal::pow`] for integer exponents, which is faster and exact.
+    ///
+    /// This is calculated as e^(exp * ln(self)) using integer arithmetic only and is
+    /// therefore deterministic. The relative error before rounding is below
+    /// (1 + exp) * 10^-56. The result is then rounded to the nearest value with
+    /// 18 decimal places.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::Decimal;
+    /// let x = Decimal::percent(900);
+    /// assert_eq!(x.pow_decimal(Decimal::percent(50)).to_string(), "3");
+    /// assert_eq!(x.pow_decimal(Decimal::percent(150)).to_string(), "27");
+    /// ```
+    pub fn pow_decimal(self, exp: Self) -> Self {
+        match self.checked_pow_decimal(exp) {
+            Ok(value) => value,
+            Err(_) => panic!("Multiplication overflow"),
+        }
+    }
+
+    /// Raises a value to the power of a decimal `exp`, returning an `OverflowError`
+    /// if the result exceeds [`Decimal::MAX`].
+    ///
+    /// See [`Decimal::pow_decimal`] for details.
+    pub fn checked_pow_decimal(self, exp: Self) -> Result<Self, OverflowError> {
+        transcendental::pow(self.0.into(), exp.0.into())
+            .and_then(|atomics| atomics.try_into().ok())
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Pow, self, exp))
+    }
+
     pub const fn abs_diff(self, other: Self) -> Self {
         Self(self.0.abs_diff(other.0))
     }
@@ -668,6 +794,10 @@ mod tests {
         Decimal::from_str(input).unwrap()
     }
 
+    fn sdec(input: &str) -> SignedDecimal {
+        SignedDecimal::from_str(input).unwrap()
+    }
+
     #[test]
     fn decimal_new() {
         let expected = Uint128::from(300u128);
@@ -2015,4 +2145,205 @@ mod tests {
             assert_eq!(&lhs == &rhs, expected);
         }
     }
+
+    #[test]
+    fn decimal_ln_works() {
+        assert_eq!(Decimal::one().ln(), SignedDecimal::zero());
+        assert_eq!(dec("2").ln(), sdec("0.693147180559945309"));
+        assert_eq!(dec("0.5").ln(), sdec("-0.693147180559945309"));
+        assert_eq!(dec("3").ln(), sdec("1.098612288668109691"));
+        assert_eq!(dec("10").ln(), sdec("2.302585092994045684"));
+        assert_eq!(dec("0.3").ln(), sdec("-1.203972804325935993"));
+        assert_eq!(
+            dec("1.000000000000000001").ln(),
+            sdec("0.000000000000000001")
+        );
+        assert_eq!(
+            dec("0.000000000000000001").ln(),
+            sdec("-41.446531673892822312")
+        );
+        assert_eq!(Decimal::MAX.ln(), sdec("47.276307437780177293"));
+
+        assert_eq!(Decimal::zero().checked_ln(), Err(LogarithmOfZeroError));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the logarithm of zero")]
+    fn decimal_ln_panics_for_zero() {
+        let _ = Decimal::zero().ln();
+    }
+
+    #[test]
+    fn decimal_log2_works() {
+        assert_eq!(Decimal::one().log2(), SignedDecimal::zero());
+        assert_eq!(dec("2").log2(), sdec("1"));
+        assert_eq!(dec("1024").log2(), sdec("10"));
+        assert_eq!(dec("0.125").log2(), sdec("-3"));
+        assert_eq!(dec("3").log2(), sdec("1.584962500721156181"));
+        assert_eq!(dec("10").log2(), sdec("3.321928094887362348"));
+        assert_eq!(dec("0.3").log2(), sdec("-1.736965594166206166"));
+        assert_eq!(
+            dec("0.000000000000000001").log2(),
+            sdec("-59.794705707972522262")
+        );
+        assert_eq!(Decimal::MAX.log2(), sdec("68.205294292027477738"));
+
+        assert_eq!(Decimal::zero().checked_log2(), Err(LogarithmOfZeroError));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the logarithm of zero")]
+    fn decimal_log2_panics_for_zero() {
+        let _ = Decimal::zero().log2();
+    }
+
+    #[test]
+    fn decimal_exp_works() {
+        assert_eq!(Decimal::zero().exp(), Decimal::one());
+        assert_eq!(Decimal::one().exp(), dec("2.718281828459045235"));
+        assert_eq!(dec("0.5").exp(), dec("1.648721270700128147"));
+        assert_eq!(dec("10").exp(), dec("22026.465794806716516958"));
+        assert_eq!(
+            dec("0.000000000000000001").exp(),
+            dec("1.000000000000000001")
+        );
+        assert_eq!(
+            dec("47").exp(),
+            dec("258131288619006739623.285800215273380432")
+        );
+        assert_eq!(
+            dec("47.276307437780177293").exp(),
+            dec("340282366920938463435.517268169940837589")
+        );
+
+        // e^47.276307437780177294 is larger than Decimal::MAX
+        assert_eq!(
+            dec("47.276307437780177294").checked_exp(),
+            Err(OverflowError::new(
+                OverflowOperation::Pow,
+                "e",
+                "47.276307437780177294"
+            ))
+        );
+        assert!(Decimal::MAX.checked_exp().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to exponentiate with overflow")]
+    fn decimal_exp_overflow_panics() {
+        let _ = dec("48").exp();
+    }
+
+    #[test]
+    fn decimal_pow_decimal_works() {
+        assert_eq!(
+            dec("2").pow_decimal(dec("0.5")),
+            dec("1.414213562373095049")
+        );
+        assert_eq!(
+            dec("0.5").pow_decimal(dec("0.5")),
+            dec("0.707106781186547524")
+        );
+        assert_eq!(dec("9").pow_decimal(dec("1.5")), dec("27"));
+        assert_eq!(
+            dec("1.5").pow_decimal(dec("2.5")),
+            dec("2.75567596063107536")
+        );
+        assert_eq!(
+            dec("0.9").pow_decimal(dec("100.5")),
+            dec("0.000025198355497512")
+        );
+        assert_eq!(
+            dec("2").pow_decimal(dec("67.5")),
+            dec("208701085205324515397.593148900040133902")
+        );
+
+        // integer exponents match pow
+        assert_eq!(dec("1.1").pow_decimal(dec("3")), dec("1.1").pow(3));
+        assert_eq!(dec("7").pow_decimal(dec("20")), dec("7").pow(20));
+
+        // special cases
+        assert_eq!(Decimal::zero().pow_decimal(Decimal::zero()), Decimal::one());
+        assert_eq!(Decimal::zero().pow_decimal(dec("0.5")), Decimal::zero());
+        assert_eq!(Decimal::MAX.pow_decimal(Decimal::zero()), Decimal::one());
+        assert_eq!(Decimal::one().pow_decimal(Decimal::MAX), Decimal::one());
+        assert_eq!(dec("0.5").pow_decimal(dec("70.5")), Decimal::zero());
+        assert_eq!(dec("0.5").pow_decimal(Decimal::MAX), Decimal::zero());
+
+        assert_eq!(
+            dec("2").checked_pow_decimal(dec("68.3")),
+            Err(OverflowError::new(OverflowOperation::Pow, 2, "68.3"))
+        );
+        assert!(Decimal::MAX.checked_pow_decimal(Decimal::MAX).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiplication overflow")]
+    fn decimal_pow_decimal_overflow_panics() {
+        let _ = dec("10").pow_decimal(dec("21"));
+    }
+
+    /// Returns a deterministic sequence of pseudo random numbers (xorshift64)
+    fn pseudo_random_u128s(seed: u64) -> impl Iterator<Item = u128> {
+        let next = |state: &u64| {
+            let mut x = *state;
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            Some(x)
+        };
+        let mut states = std::iter::successors(Some(seed), next).skip(1);
+        std::iter::from_fn(move || {
+            let high = states.next().unwrap() as u128;
+            let low = states.next().unwrap() as u128;
+            Some(high << 64 | low)
+        })
+    }
+
+    /// Creates decimals which are spread over all orders of magnitude
+    fn pseudo_random_decimals(seed: u64) -> impl Iterator<Item = Decimal> {
+        pseudo_random_u128s(seed).map(|random| {
+            let digits = (random % 39) as u32;
+            Decimal::raw(random % 10u128.pow(digits) + 1)
+        })
+    }
+
+    fn to_f64(value: impl ToString) -> f64 {
+        value.to_string().parse().unwrap()
+    }
+
+    fn assert_close_to_f64(actual: impl ToString, expected: f64) {
+        let actual = to_f64(actual);
+        let tolerance = 1e-12 * expected.abs().max(1.0);
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{} is not close to {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn decimal_transcendental_functions_match_f64() {
+        for x in pseudo_random_decimals(42).take(500) {
+            assert_close_to_f64(x.ln(), to_f64(x).ln());
+            assert_close_to_f64(x.log2(), to_f64(x).log2());
+        }
+
+        for x in pseudo_random_u128s(1337).take(500) {
+            // values up to 47, which is close to the max exponent
+            let x = Decimal::raw(x % 47_000_000_000_000_000_000);
+            assert_close_to_f64(x.exp(), to_f64(x).exp());
+        }
+
+        let bases = pseudo_random_decimals(7);
+        let exponents = pseudo_random_u128s(8).map(|x| Decimal::raw(x % 5_000_000_000_000_000_000));
+        for (base, exp) in bases.zip(exponents).take(500) {
+            let expected = to_f64(base).powf(to_f64(exp));
+            match base.checked_pow_decimal(exp) {
+                Ok(result) => assert_close_to_f64(result, expected),
+                Err(_) => assert!(expected >= to_f64(Decimal::MAX) * 0.999999),
+            }
+        }
+    }
 }
@@ -8,11 +8,12 @@ use std::str::FromStr;
 use thiserror::Error;
 
 use crate::errors::{
-    CheckedFromRatioError, CheckedMultiplyRatioError, DivideByZeroError, OverflowError,
-    OverflowOperation, RoundUpOverflowError, StdError,
+    CheckedFromRatioError, CheckedMultiplyRatioError, DivideByZeroError, LogarithmOfZeroError,
+    OverflowError, OverflowOperation, RoundUpOverflowError, StdError,
 };
-use crate::{Decimal, Uint512};
+use crate::{Decimal, SignedDecimal256, Uint512};
 
+use super::transcendental;
 use super::Fraction;
 use super::Isqrt;
 use super::Uint256;
@@ -339,6 +340,127 @@ impl Decimal256 {
         })
     }
 
+    /// Returns the natural logarithm as a [`SignedDecimal256`], since it is negative
+    /// for values below 1.
+    ///
+    /// The calculation only uses integer arithmetic and is therefore deterministic.
+    /// The absolute error before rounding is below 10^-55. The result is then rounded
+    /// to the nearest value with 18 decimal places.
+    ///
+    /// Panics for zero. See [`Decimal256::checked_ln`] for a non-panicking version.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::{Decimal256, SignedDecimal256};
+    /// # use std::str::FromStr;
+    /// let x = Decimal256::from_str("2").unwrap();
+    /// assert_eq!(x.ln().to_string(), "0.693147180559945309");
+    ///
+    /// let x = Decimal256::percent(50);
+    /// assert_eq!(x.ln().to_string(), "-0.693147180559945309");
+    /// ```
+    pub fn ln(self) -> SignedDecimal256 {
+        match self.checked_ln() {
+            Ok(value) => value,
+            Err(_) => panic!("attempt to calculate the logarithm of zero"),
+        }
+    }
+
+    /// Returns the natural logarithm, or a [`LogarithmOfZeroError`] if `self` is zero.
+    ///
+    /// See [`Decimal256::ln`] for details.
+    pub fn checked_ln(self) -> Result<SignedDecimal256, LogarithmOfZeroError> {
+        transcendental::ln(self.0)
+            .map(SignedDecimal256::new)
+            .ok_or(LogarithmOfZeroError)
+    }
+
+    /// Returns the base 2 logarithm as a [`SignedDecimal256`], since it is negative
+    /// for values below 1.
+    ///
+    /// The calculation only uses integer arithmetic and is therefore deterministic.
+    /// The absolute error before rounding is below 10^-55. The result is then rounded
+    /// to the nearest value with 18 decimal places.
+    ///
+    /// Panics for zero. See [`Decimal256::checked_log2`] for a non-panicking version.
+    pub fn log2(self) -> SignedDecimal256 {
+        match self.checked_log2() {
+            Ok(value) => value,
+            Err(_) => panic!("attempt to calculate the logarithm of zero"),
+        }
+    }
+
+    /// Returns the base 2 logarithm, or a [`LogarithmOfZeroError`] if `self` is zero.
+    ///
+    /// See [`Decimal256::log2`] for details.
+    pub fn checked_log2(self) -> Result<SignedDecimal256, LogarithmOfZeroError> {
+        transcendental::log2(self.0)
+            .map(SignedDecimal256::new)
+            .ok_or(LogarithmOfZeroError)
+    }
+
+    /// Returns e^self, panics if an overflow occurred.
+    ///
+    /// The calculation only uses integer arithmetic and is therefore deterministic.
+    /// The relative error before rounding is below 10^-55. The result is then rounded
+    /// to the nearest value with 18 decimal places.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::Decimal256;
+    /// assert_eq!(Decimal256::one().exp().to_string(), "2.718281828459045235");
+    /// ```
+    pub fn exp(self) -> Self {
+        match self.checked_exp() {
+            Ok(value) => value,
+            Err(_) => panic!("attempt to exponentiate with overflow"),
+        }
+    }
+
+    /// Returns e^self, or an `OverflowError` if the result exceeds [`Decimal256::MAX`].
+    ///
+    /// See [`Decimal256::exp`] for details.
+    pub fn checked_exp(self) -> Result<Self, OverflowError> {
+        transcendental::exp(self.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Pow, "e", self))
+    }
+
+    /// Raises a value to the power of a decimal `exp`, panics if an overflow occurred.
+    /// Use [`Decimal256::pow`] for integer exponents, which is faster and exact.
+    ///
+    /// This is calculated as e^(exp * ln(self)) using integer arithmetic only and is
+    /// therefore deterministic. The relative error before rounding is below
+    /// (1 + exp) * 10^-56. The result is then rounded to the nearest value with
+    /// 18 decimal places.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::Decimal256;
+    /// let x = Decimal256::percent(900);
+    /// assert_eq!(x.pow_decimal(Decimal256::percent(50)).to_string(), "3");
+    /// assert_eq!(x.pow_decimal(Decimal256::percent(150)).to_string(), "27");
+    /// ```
+    pub fn pow_decimal(self, exp: Self) -> Self {
+        match self.checked_pow_decimal(exp) {
+            Ok(value) => value,
+            Err(_) => panic!("Multiplication overflow"),
+        }
+    }
+
+    /// Raises a value to the power of a decimal `exp`, returning an `OverflowError`
+    /// if the result exceeds [`Decimal256::MAX`].
+    ///
+    /// See [`Decimal256::pow_decimal`] for details.
+    pub fn checked_pow_decimal(self, exp: Self) -> Result<Self, OverflowError> {
+        transcendental::pow(self.0, exp.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Pow, self, exp))
+    }
+
     pub fn abs_diff(self, other: Self) -> Self {
         if self < other {
             other - self
@@ -694,6 +816,10 @@ mod tests {
         Decimal256::from_str(input).unwrap()
     }
 
+    fn sdec(input: &str) -> SignedDecimal256 {
+        SignedDecimal256::from_str(input).unwrap()
+    }
+
     #[test]
     fn decimal256_new() {
         let expected = Uint256::from(300u128);
@@ -2162,4 +2288,204 @@ mod tests {
             assert_eq!(&lhs == &rhs, expected);
         }
     }
+
+    #[test]
+    fn decimal256_ln_works() {
+        assert_eq!(Decimal256::one().ln(), SignedDecimal256::zero());
+        assert_eq!(dec("2").ln(), sdec("0.693147180559945309"));
+        assert_eq!(dec("0.5").ln(), sdec("-0.693147180559945309"));
+        assert_eq!(dec("3").ln(), sdec("1.098612288668109691"));
+        assert_eq!(dec("0.3").ln(), sdec("-1.203972804325935993"));
+        assert_eq!(
+            dec("0.000000000000000001").ln(),
+            sdec("-41.446531673892822312")
+        );
+        assert_eq!(
+            dec("340282366920938463463.374607431768211455").ln(),
+            sdec("47.276307437780177293")
+        );
+        assert_eq!(Decimal256::MAX.ln(), sdec("135.999146549453176898"));
+
+        assert_eq!(Decimal256::zero().checked_ln(), Err(LogarithmOfZeroError));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the logarithm of zero")]
+    fn decimal256_ln_panics_for_zero() {
+        let _ = Decimal256::zero().ln();
+    }
+
+    #[test]
+    fn decimal256_log2_works() {
+        assert_eq!(Decimal256::one().log2(), SignedDecimal256::zero());
+        assert_eq!(dec("1024").log2(), sdec("10"));
+        assert_eq!(dec("0.125").log2(), sdec("-3"));
+        assert_eq!(dec("10").log2(), sdec("3.321928094887362348"));
+        assert_eq!(
+            dec("0.000000000000000001").log2(),
+            sdec("-59.794705707972522262")
+        );
+        assert_eq!(Decimal256::MAX.log2(), sdec("196.205294292027477738"));
+
+        assert_eq!(Decimal256::zero().checked_log2(), Err(LogarithmOfZeroError));
+    }
+
+    #[test]
+    fn decimal256_exp_works() {
+        assert_eq!(Decimal256::zero().exp(), Decimal256::one());
+        assert_eq!(Decimal256::one().exp(), dec("2.718281828459045235"));
+        assert_eq!(dec("10").exp(), dec("22026.465794806716516958"));
+        assert_eq!(
+            dec("47").exp(),
+            dec("258131288619006739623.285800215273380432")
+        );
+
+        // For huge results only the relative error is below 10^-55
+        let expected =
+            dec("42633899483147210448936866880765989356468745853255281087440.011736227864297277");
+        assert!(dec("135").exp().abs_diff(expected) < dec("1000"));
+        let expected =
+            dec("115792089237316195367113436054640938313993155168102775229371.716893181941307032");
+        assert!(dec("135.999146549453176898").exp().abs_diff(expected) < dec("1000"));
+
+        // e^135.999146549453176899 is larger than Decimal256::MAX
+        assert_eq!(
+            dec("135.999146549453176899").checked_exp(),
+            Err(OverflowError::new(
+                OverflowOperation::Pow,
+                "e",
+                "135.999146549453176899"
+            ))
+        );
+        assert!(Decimal256::MAX.checked_exp().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to exponentiate with overflow")]
+    fn decimal256_exp_overflow_panics() {
+        let _ = dec("137").exp();
+    }
+
+    #[test]
+    fn decimal256_pow_decimal_works() {
+        assert_eq!(
+            dec("2").pow_decimal(dec("0.5")),
+            dec("1.414213562373095049")
+        );
+        assert_eq!(dec("9").pow_decimal(dec("1.5")), dec("27"));
+        assert_eq!(
+            dec("0.9").pow_decimal(dec("100.5")),
+            dec("0.000025198355497512")
+        );
+        assert_eq!(
+            dec("2").pow_decimal(dec("68.3")),
+            dec("363369694572015313340.61789097309766973")
+        );
+        let expected =
+            dec("71017299252636278629426809467176478624361936751351075341518.818279763730265471");
+        assert!(dec("2").pow_decimal(dec("195.5")).abs_diff(expected) < dec("10000"));
+
+        // integer exponents match pow
+        assert_eq!(dec("7").pow_decimal(dec("20")), dec("7").pow(20));
+
+        // special cases
+        assert_eq!(
+            Decimal256::zero().pow_decimal(Decimal256::zero()),
+            Decimal256::one()
+        );
+        assert_eq!(
+            Decimal256::zero().pow_decimal(dec("0.5")),
+            Decimal256::zero()
+        );
+        assert_eq!(
+            Decimal256::one().pow_decimal(Decimal256::MAX),
+            Decimal256::one()
+        );
+        assert_eq!(dec("0.5").pow_decimal(Decimal256::MAX), Decimal256::zero());
+
+        assert_eq!(
+            dec("2").checked_pow_decimal(dec("196.3")),
+            Err(OverflowError::new(OverflowOperation::Pow, 2, "196.3"))
+        );
+        assert!(Decimal256::MAX
+            .checked_pow_decimal(Decimal256::MAX)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiplication overflow")]
+    fn decimal256_pow_decimal_overflow_panics() {
+        let _ = dec("10").pow_decimal(dec("60"));
+    }
+
+    /// Returns a deterministic sequence of pseudo random numbers (xorshift64)
+    fn pseudo_random_u128s(seed: u64) -> impl Iterator<Item = u128> {
+        let next = |state: &u64| {
+            let mut x = *state;
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            Some(x)
+        };
+        let mut states = std::iter::successors(Some(seed), next).skip(1);
+        std::iter::from_fn(move || {
+            let high = states.next().unwrap() as u128;
+            let low = states.next().unwrap() as u128;
+            Some(high << 64 | low)
+        })
+    }
+
+    /// Creates decimals which are spread over all orders of magnitude
+    fn pseudo_random_decimals(seed: u64) -> impl Iterator<Item = Decimal256> {
+        let mut randoms = pseudo_random_u128s(seed);
+        std::iter::from_fn(move || {
+            let random = (Uint256::from(randoms.next().unwrap()) << 128)
+                + Uint256::from(randoms.next().unwrap());
+            let digits = (randoms.next().unwrap() % 78) as u32;
+            let modulus = Uint256::from(10u8)
+                .checked_pow(digits)
+                .unwrap_or(Uint256::MAX);
+            Some(Decimal256::new(random % modulus + Uint256::one()))
+        })
+    }
+
+    fn to_f64(value: impl ToString) -> f64 {
+        value.to_string().parse().unwrap()
+    }
+
+    fn assert_close_to_f64(actual: impl ToString, expected: f64) {
+        let actual = to_f64(actual);
+        let tolerance = 1e-12 * expected.abs().max(1.0);
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{} is not close to {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn decimal256_transcendental_functions_match_f64() {
+        for x in pseudo_random_decimals(42).take(500) {
+            assert_close_to_f64(x.ln(), to_f64(x).ln());
+            assert_close_to_f64(x.log2(), to_f64(x).log2());
+        }
+
+        for x in pseudo_random_u128s(1337).take(500) {
+            // values up to 135, which is close to the max exponent
+            let x = Decimal256::new(Uint256::from(x % 135_000_000_000_000_000_000));
+            assert_close_to_f64(x.exp(), to_f64(x).exp());
+        }
+
+        let bases = pseudo_random_decimals(7);
+        let exponents = pseudo_random_u128s(8)
+            .map(|x| Decimal256::new(Uint256::from(x % 5_000_000_000_000_000_000)));
+        for (base, exp) in bases.zip(exponents).take(500) {
+            let expected = to_f64(base).powf(to_f64(exp));
+            match base.checked_pow_decimal(exp) {
+                Ok(result) => assert_close_to_f64(result, expected),
+                Err(_) => assert!(expected >= to_f64(Decimal256::MAX) * 0.999999),
+            }
+        }
+    }
 }
@@ -1,3 +1,6 @@
+use crate::errors::CheckedMultiplyRatioError;
+use crate::{Uint256, Uint512};
+
 /// A fraction `p`/`q` with integers `p` and `q`.
 ///
 /// `p` is called the numerator and `q` is called the denominator.
@@ -12,3 +15,176 @@ pub trait Fraction<T>: Sized {
     /// If `p` is zero, None is returned.
     fn inv(&self) -> Option<Self>;
 }
+
+/// Returns `value * numerator / denominator`, rounded up if `round_up` is true and
+/// rounded down otherwise.
+///
+/// There is no integer type wide enough to hold the full product of a [`Uint512`] and a
+/// [`Uint256`]. So `value` is split into `q * denominator + r` with `r < denominator`,
+/// which gives `value * numerator / denominator = q * numerator + r * numerator / denominator`.
+/// Since `r * numerator` always fits into a [`Uint512`], only the final result can overflow.
+pub(crate) fn checked_multiply_ratio_rounded<U>(
+    value: U,
+    numerator: Uint256,
+    denominator: Uint256,
+    round_up: bool,
+) -> Result<U, CheckedMultiplyRatioError>
+where
+    U: Into<Uint512> + TryFrom<Uint512>,
+{
+    if denominator.is_zero() {
+        return Err(CheckedMultiplyRatioError::DivideByZero);
+    }
+    let value: Uint512 = value.into();
+    let numerator = Uint512::from(numerator);
+    let denominator = Uint512::from(denominator);
+
+    let (q, r) = (value / denominator, value % denominator);
+    let remainder_product = r * numerator;
+    let mut result = q
+        .checked_mul(numerator)
+        .and_then(|whole| whole.checked_add(remainder_product / denominator))
+        .map_err(|_| CheckedMultiplyRatioError::Overflow)?;
+    if round_up && !(remainder_product % denominator).is_zero() {
+        result = result
+            .checked_add(Uint512::one())
+            .map_err(|_| CheckedMultiplyRatioError::Overflow)?;
+    }
+    result
+        .try_into()
+        .map_err(|_| CheckedMultiplyRatioError::Overflow)
+}
+
+/// Implements multiplication and division by a [`Fraction`] with explicit rounding
+/// for an unsigned integer type.
+macro_rules! impl_mul_fraction {
+    ($Uint:ident) => {
+        impl $Uint {
+            /// Multiplies `self` with the fraction `rhs` (e.g. a [`Decimal`](crate::Decimal)
+            /// or [`Decimal256`](crate::Decimal256)) and rounds the result down.
+            ///
+            /// Panics if the result does not fit into this type.
+            pub fn mul_floor<F: crate::Fraction<T>, T: Into<crate::Uint256>>(self, rhs: F) -> Self {
+                match self.checked_mul_floor(rhs) {
+                    Ok(value) => value,
+                    Err(crate::CheckedMultiplyRatioError::DivideByZero) => {
+                        panic!("Denominator must not be zero")
+                    }
+                    Err(crate::CheckedMultiplyRatioError::Overflow) => {
+                        panic!("Multiplication overflow")
+                    }
+                }
+            }
+
+            /// Multiplies `self` with the fraction `rhs` and rounds the result down.
+            ///
+            /// Returns an error if the result does not fit into this type.
+            pub fn checked_mul_floor<F: crate::Fraction<T>, T: Into<crate::Uint256>>(
+                self,
+                rhs: F,
+            ) -> Result<Self, crate::CheckedMultiplyRatioError> {
+                crate::math::fraction::checked_multiply_ratio_rounded(
+                    self,
+                    rhs.numerator().into(),
+                    rhs.denominator().into(),
+                    false,
+                )
+            }
+
+            /// Multiplies `self` with the fraction `rhs` (e.g. a [`Decimal`](crate::Decimal)
+            /// or [`Decimal256`](crate::Decimal256)) and rounds the result up.
+            ///
+            /// Panics if the result does not fit into this type.
+            pub fn mul_ceil<F: crate::Fraction<T>, T: Into<crate::Uint256>>(self, rhs: F) -> Self {
+                match self.checked_mul_ceil(rhs) {
+                    Ok(value) => value,
+                    Err(crate::CheckedMultiplyRatioError::DivideByZero) => {
+                        panic!("Denominator must not be zero")
+                    }
+                    Err(crate::CheckedMultiplyRatioError::Overflow) => {
+                        panic!("Multiplication overflow")
+                    }
+                }
+            }
+
+            /// Multiplies `self` with the fraction `rhs` and rounds the result up.
+            ///
+            /// Returns an error if the result does not fit into this type.
+            pub fn checked_mul_ceil<F: crate::Fraction<T>, T: Into<crate::Uint256>>(
+                self,
+                rhs: F,
+            ) -> Result<Self, crate::CheckedMultiplyRatioError> {
+                crate::math::fraction::checked_multiply_ratio_rounded(
+                    self,
+                    rhs.numerator().into(),
+                    rhs.denominator().into(),
+                    true,
+                )
+            }
+
+            /// Divides `self` by the fraction `rhs` (e.g. a [`Decimal`](crate::Decimal)
+            /// or [`Decimal256`](crate::Decimal256)) and rounds the result down.
+            ///
+            /// Panics if `rhs` is zero or the result does not fit into this type.
+            pub fn div_floor<F: crate::Fraction<T>, T: Into<crate::Uint256>>(self, rhs: F) -> Self {
+                match self.checked_div_floor(rhs) {
+                    Ok(value) => value,
+                    Err(crate::CheckedMultiplyRatioError::DivideByZero) => {
+                        panic!("Division failed - denominator must not be zero")
+                    }
+                    Err(crate::CheckedMultiplyRatioError::Overflow) => {
+                        panic!("Division failed - multiplication overflow")
+                    }
+                }
+            }
+
+            /// Divides `self` by the fraction `rhs` and rounds the result down.
+            ///
+            /// Returns an error if `rhs` is zero or the result does not fit into this type.
+            pub fn checked_div_floor<F: crate::Fraction<T>, T: Into<crate::Uint256>>(
+                self,
+                rhs: F,
+            ) -> Result<Self, crate::CheckedMultiplyRatioError> {
+                crate::math::fraction::checked_multiply_ratio_rounded(
+                    self,
+                    rhs.denominator().into(),
+                    rhs.numerator().into(),
+                    false,
+                )
+            }
+
+            /// Divides `self` by the fraction `rhs` (e.g. a [`Decimal`](crate::Decimal)
+            /// or [`Decimal256`](crate::Decimal256)) and rounds the result up.
+            ///
+            /// Panics if `rhs` is zero or the result does not fit into this type.
+            pub fn div_ceil<F: crate::Fraction<T>, T: Into<crate::Uint256>>(self, rhs: F) -> Self {
+                match self.checked_div_ceil(rhs) {
+                    Ok(value) => value,
+                    Err(crate::CheckedMultiplyRatioError::DivideByZero) => {
+                        panic!("Division failed - denominator must not be zero")
+                    }
+                    Err(crate::CheckedMultiplyRatioError::Overflow) => {
+                        panic!("Division failed - multiplication overflow")
+                    }
+                }
+            }
+
+            /// Divides `self` by the fraction `rhs` and rounds the result up.
+            ///
+            /// Returns an error if `rhs` is zero or the result does not fit into this type.
+            pub fn checked_div_ceil<F: crate::Fraction<T>, T: Into<crate::Uint256>>(
+                self,
+                rhs: F,
+            ) -> Result<Self, crate::CheckedMultiplyRatioError> {
+                crate::math::fraction::checked_multiply_ratio_rounded(
+                    self,
+                    rhs.denominator().into(),
+                    rhs.numerator().into(),
+                    true,
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use impl_mul_fraction;
@@ -0,0 +1,1463 @@
+use forward_ref::{forward_ref_binop, forward_ref_op_assign};
+use schemars::JsonSchema;
+use serde::{de, ser, Deserialize, Deserializer, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr,
+    ShrAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+use crate::errors::{DivisionError, OverflowError, OverflowOperation, StdError};
+use crate::math::conversion::{
+    from_int_to_int, from_uint_to_int, try_from_int_to_int, try_from_int_to_uint,
+    try_from_uint_to_int,
+};
+use crate::{Int128, Int256, Int64, Uint128, Uint256, Uint512, Uint64};
+
+/// This module is purely a workaround that lets us ignore lints for all the code
+/// the `construct_uint!` macro generates.
+#[allow(clippy::all)]
+mod uints {
+    uint::construct_uint! {
+        pub struct U512(8);
+    }
+}
+
+/// Used internally - we don't want to leak this type since we might change
+/// the implementation in the future.
+use uints::U512;
+
+/// An implementation of i512 that is using strings for JSON encoding/decoding,
+/// such that the full i512 range can be used for clients that convert JSON numbers to floats,
+/// like JavaScript and jq.
+///
+/// The number is stored in two's complement.
+///
+/// # Examples
+///
+/// Use `from` to create instances out of primitive int types or `new` to provide big
+/// endian bytes in two's complement:
+///
+/// ```
+/// # use cosmwasm_std::Int512;
+/// let a = Int512::from(-258i128);
+/// let b = Int512::new([
+///     255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+///     255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+///     255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+///     255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+///     255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+///     255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+///     255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+///     255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 254u8, 254u8,
+/// ]);
+/// assert_eq!(a, b);
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, JsonSchema)]
+pub struct Int512(#[schemars(with = "String")] U512);
+
+impl Int512 {
+    pub const MAX: Int512 = Int512(U512([
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        u64::MAX,
+        i64::MAX as u64,
+    ]));
+    pub const MIN: Int512 = Int512(U512([0, 0, 0, 0, 0, 0, 0, 0x8000_0000_0000_0000]));
+
+    /// Creates a Int512(value) from a big endian representation in two's complement.
+    /// It's just an alias for [`Int512::from_be_bytes`].
+    ///
+    /// This method is less flexible than `from` but can be called in a const context.
+    pub const fn new(value: [u8; 64]) -> Self {
+        Self::from_be_bytes(value)
+    }
+
+    /// Creates a Int512(0)
+    #[inline]
+    pub const fn zero() -> Self {
+        Int512(U512([0, 0, 0, 0, 0, 0, 0, 0]))
+    }
+
+    /// Creates a Int512(1)
+    #[inline]
+    pub const fn one() -> Self {
+        Int512(U512([1, 0, 0, 0, 0, 0, 0, 0]))
+    }
+
+    pub const fn from_be_bytes(data: [u8; 64]) -> Self {
+        let words: [u64; 8] = [
+            u64::from_le_bytes([
+                data[63], data[62], data[61], data[60], data[59], data[58], data[57], data[56],
+            ]),
+            u64::from_le_bytes([
+                data[55], data[54], data[53], data[52], data[51], data[50], data[49], data[48],
+            ]),
+            u64::from_le_bytes([
+                data[47], data[46], data[45], data[44], data[43], data[42], data[41], data[40],
+            ]),
+            u64::from_le_bytes([
+                data[39], data[38], data[37], data[36], data[35], data[34], data[33], data[32],
+            ]),
+            u64::from_le_bytes([
+                data[31], data[30], data[29], data[28], data[27], data[26], data[25], data[24],
+            ]),
+            u64::from_le_bytes([
+                data[23], data[22], data[21], data[20], data[19], data[18], data[17], data[16],
+            ]),
+            u64::from_le_bytes([
+                data[15], data[14], data[13], data[12], data[11], data[10], data[9], data[8],
+            ]),
+            u64::from_le_bytes([
+                data[7], data[6], data[5], data[4], data[3], data[2], data[1], data[0],
+            ]),
+        ];
+        Self(U512(words))
+    }
+
+    pub const fn from_le_bytes(data: [u8; 64]) -> Self {
+        let words: [u64; 8] = [
+            u64::from_le_bytes([
+                data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7],
+            ]),
+            u64::from_le_bytes([
+                data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15],
+            ]),
+            u64::from_le_bytes([
+                data[16], data[17], data[18], data[19], data[20], data[21], data[22], data[23],
+            ]),
+            u64::from_le_bytes([
+                data[24], data[25], data[26], data[27], data[28], data[29], data[30], data[31],
+            ]),
+            u64::from_le_bytes([
+                data[32], data[33], data[34], data[35], data[36], data[37], data[38], data[39],
+            ]),
+            u64::from_le_bytes([
+                data[40], data[41], data[42], data[43], data[44], data[45], data[46], data[47],
+            ]),
+            u64::from_le_bytes([
+                data[48], data[49], data[50], data[51], data[52], data[53], data[54], data[55],
+            ]),
+            u64::from_le_bytes([
+                data[56], data[57], data[58], data[59], data[60], data[61], data[62], data[63],
+            ]),
+        ];
+        Self(U512(words))
+    }
+
+    /// A conversion from `i128` that, unlike the one provided by the `From` trait,
+    /// can be used in a `const` context.
+    pub const fn from_i128(num: i128) -> Self {
+        let bytes = num.to_le_bytes();
+        let fill = if num < 0 { 0xff } else { 0 };
+
+        Self::from_le_bytes([
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+            fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill,
+            fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill,
+            fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill, fill,
+            fill, fill, fill, fill, fill, fill,
+        ])
+    }
+
+    /// Returns a copy of the number as big endian bytes in two's complement.
+    pub const fn to_be_bytes(self) -> [u8; 64] {
+        let words = [
+            (self.0).0[7].to_be_bytes(),
+            (self.0).0[6].to_be_bytes(),
+            (self.0).0[5].to_be_bytes(),
+            (self.0).0[4].to_be_bytes(),
+            (self.0).0[3].to_be_bytes(),
+            (self.0).0[2].to_be_bytes(),
+            (self.0).0[1].to_be_bytes(),
+            (self.0).0[0].to_be_bytes(),
+        ];
+        unsafe { std::mem::transmute::<[[u8; 8]; 8], [u8; 64]>(words) }
+    }
+
+    /// Returns a copy of the number as little endian bytes in two's complement.
+    pub const fn to_le_bytes(self) -> [u8; 64] {
+        let words = [
+            (self.0).0[0].to_le_bytes(),
+            (self.0).0[1].to_le_bytes(),
+            (self.0).0[2].to_le_bytes(),
+            (self.0).0[3].to_le_bytes(),
+            (self.0).0[4].to_le_bytes(),
+            (self.0).0[5].to_le_bytes(),
+            (self.0).0[6].to_le_bytes(),
+            (self.0).0[7].to_le_bytes(),
+        ];
+        unsafe { std::mem::transmute::<[[u8; 8]; 8], [u8; 64]>(words) }
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        let words = (self.0).0;
+        words[0] == 0
+            && words[1] == 0
+            && words[2] == 0
+            && words[3] == 0
+            && words[4] == 0
+            && words[5] == 0
+            && words[6] == 0
+            && words[7] == 0
+    }
+
+    /// Returns true if the number is strictly negative
+    pub const fn is_negative(&self) -> bool {
+        (self.0).0[8 - 1] >> 63 == 1
+    }
+
+    pub fn pow(self, exp: u32) -> Self {
+        self.checked_pow(exp)
+            .expect("attempt to multiply with overflow")
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
+        let sum = self.wrapping_add(other);
+        if self.is_negative() == other.is_negative() && sum.is_negative() != self.is_negative() {
+            return Err(OverflowError::new(OverflowOperation::Add, self, other));
+        }
+        Ok(sum)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
+        let difference = self.wrapping_sub(other);
+        if self.is_negative() != other.is_negative()
+            && difference.is_negative() != self.is_negative()
+        {
+            return Err(OverflowError::new(OverflowOperation::Sub, self, other));
+        }
+        Ok(difference)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Result<Self, OverflowError> {
+        let negative = self.is_negative() != other.is_negative();
+        self.unsigned_abs_raw()
+            .checked_mul(other.unsigned_abs_raw())
+            .and_then(|abs| Self::from_sign_and_abs(negative, abs))
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Mul, self, other))
+    }
+
+    pub fn checked_pow(self, exp: u32) -> Result<Self, OverflowError> {
+        let negative = self.is_negative() && exp % 2 == 1;
+        self.unsigned_abs_raw()
+            .checked_pow(exp.into())
+            .and_then(|abs| Self::from_sign_and_abs(negative, abs))
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Pow, self, exp))
+    }
+
+    pub fn checked_div(self, other: Self) -> Result<Self, DivisionError> {
+        if other.is_zero() {
+            return Err(DivisionError::DivideByZero);
+        }
+        let negative = self.is_negative() != other.is_negative();
+        let abs = self.unsigned_abs_raw() / other.unsigned_abs_raw();
+        Self::from_sign_and_abs(negative, abs).ok_or(DivisionError::Overflow)
+    }
+
+    pub fn checked_div_euclid(self, other: Self) -> Result<Self, DivisionError> {
+        let quotient = self.checked_div(other)?;
+        if self.checked_rem(other)?.is_negative() {
+            // The remainder is non-zero here, so the truncated quotient is
+            // at least one step away from the limits of the type.
+            if other.is_negative() {
+                Ok(quotient.wrapping_add(Self::one()))
+            } else {
+                Ok(quotient.wrapping_sub(Self::one()))
+            }
+        } else {
+            Ok(quotient)
+        }
+    }
+
+    pub fn checked_rem(self, other: Self) -> Result<Self, DivisionError> {
+        if other.is_zero() {
+            return Err(DivisionError::DivideByZero);
+        }
+        // Same as for the primitive types, `MIN % -1` is considered an overflow
+        // because `MIN / -1` is
+        if self == Self::MIN && other == Self::from_i128(-1) {
+            return Err(DivisionError::Overflow);
+        }
+        let abs = self.unsigned_abs_raw() % other.unsigned_abs_raw();
+        Ok(Self::from_sign_and_abs(self.is_negative(), abs).unwrap())
+    }
+
+    pub fn checked_shr(self, other: u32) -> Result<Self, OverflowError> {
+        if other >= 512 {
+            return Err(OverflowError::new(OverflowOperation::Shr, self, other));
+        }
+
+        // arithmetic shift, i.e. the sign bit is shifted in
+        if self.is_negative() {
+            Ok(Self(!((!self.0) >> other)))
+        } else {
+            Ok(Self(self.0 >> other))
+        }
+    }
+
+    pub fn checked_shl(self, other: u32) -> Result<Self, OverflowError> {
+        if other >= 512 {
+            return Err(OverflowError::new(OverflowOperation::Shl, self, other));
+        }
+
+        Ok(Self(self.0 << other))
+    }
+
+    #[inline]
+    pub fn wrapping_add(self, other: Self) -> Self {
+        let (value, _did_overflow) = self.0.overflowing_add(other.0);
+        Self(value)
+    }
+
+    #[inline]
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        let (value, _did_overflow) = self.0.overflowing_sub(other.0);
+        Self(value)
+    }
+
+    #[inline]
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        let (value, _did_overflow) = self.0.overflowing_mul(other.0);
+        Self(value)
+    }
+
+    #[inline]
+    pub fn wrapping_pow(self, other: u32) -> Self {
+        let (value, _did_overflow) = self.0.overflowing_pow(other.into());
+        Self(value)
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        match self.checked_add(other) {
+            Ok(value) => value,
+            Err(_) if other.is_negative() => Self::MIN,
+            Err(_) => Self::MAX,
+        }
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        match self.checked_sub(other) {
+            Ok(value) => value,
+            Err(_) if other.is_negative() => Self::MAX,
+            Err(_) => Self::MIN,
+        }
+    }
+
+    pub fn saturating_mul(self, other: Self) -> Self {
+        match self.checked_mul(other) {
+            Ok(value) => value,
+            Err(_) if self.is_negative() != other.is_negative() => Self::MIN,
+            Err(_) => Self::MAX,
+        }
+    }
+
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Ok(value) => value,
+            Err(_) if self.is_negative() && exp % 2 == 1 => Self::MIN,
+            Err(_) => Self::MAX,
+        }
+    }
+
+    /// Strict integer addition. Computes `self + rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Int512::add`].
+    pub fn strict_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs)
+            .unwrap_or_else(|_| panic!("attempt to add with overflow"))
+    }
+
+    /// Strict integer subtraction. Computes `self - rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Int512::sub`].
+    pub fn strict_sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .unwrap_or_else(|_| panic!("attempt to subtract with overflow"))
+    }
+
+    /// Returns the absolute difference between `self` and `other`,
+    /// which always fits into the unsigned counterpart.
+    pub fn abs_diff(self, other: Self) -> Uint512 {
+        // The difference is at most 2^512 - 1, so wrapping is fine
+        // when interpreting the result as unsigned.
+        let difference = if self < other {
+            other.wrapping_sub(self)
+        } else {
+            self.wrapping_sub(other)
+        };
+        Uint512::from_be_bytes(difference.to_be_bytes())
+    }
+
+    /// Returns the absolute value.
+    ///
+    /// # Panics
+    ///
+    /// This panics for [`Int512::MIN`] because its absolute value does not fit
+    /// into the type. Use [`Int512::unsigned_abs`] for a lossless alternative.
+    pub fn abs(self) -> Self {
+        if self == Self::MIN {
+            panic!("attempt to calculate absolute value with overflow");
+        }
+        Self(self.unsigned_abs_raw())
+    }
+
+    /// Returns the absolute value as its unsigned counterpart. This never overflows.
+    pub fn unsigned_abs(self) -> Uint512 {
+        Uint512::from_be_bytes(Self(self.unsigned_abs_raw()).to_be_bytes())
+    }
+
+    /// The absolute value as an unsigned number of the same size
+    fn unsigned_abs_raw(self) -> U512 {
+        if self.is_negative() {
+            negate(self.0)
+        } else {
+            self.0
+        }
+    }
+
+    /// Creates a number from its sign and absolute value.
+    /// Returns `None` if the result does not fit into the type.
+    fn from_sign_and_abs(negative: bool, abs: U512) -> Option<Self> {
+        let min_abs = Self::MIN.0;
+        if negative {
+            if abs > min_abs {
+                return None;
+            }
+            Some(Self(negate(abs)))
+        } else {
+            if abs >= min_abs {
+                return None;
+            }
+            Some(Self(abs))
+        }
+    }
+}
+
+/// Two's complement negation of the raw bits
+fn negate(value: U512) -> U512 {
+    let (negated, _did_overflow) = (!value).overflowing_add(U512::one());
+    negated
+}
+
+impl From<i128> for Int512 {
+    fn from(val: i128) -> Self {
+        Self::from_i128(val)
+    }
+}
+
+impl From<i64> for Int512 {
+    fn from(val: i64) -> Self {
+        Self::from_i128(val.into())
+    }
+}
+
+impl From<i32> for Int512 {
+    fn from(val: i32) -> Self {
+        Self::from_i128(val.into())
+    }
+}
+
+impl From<i16> for Int512 {
+    fn from(val: i16) -> Self {
+        Self::from_i128(val.into())
+    }
+}
+
+impl From<i8> for Int512 {
+    fn from(val: i8) -> Self {
+        Self::from_i128(val.into())
+    }
+}
+
+impl From<u128> for Int512 {
+    fn from(val: u128) -> Self {
+        Int512(val.into())
+    }
+}
+
+impl From<u64> for Int512 {
+    fn from(val: u64) -> Self {
+        Int512(val.into())
+    }
+}
+
+impl From<u32> for Int512 {
+    fn from(val: u32) -> Self {
+        Int512(val.into())
+    }
+}
+
+impl From<u16> for Int512 {
+    fn from(val: u16) -> Self {
+        Int512(val.into())
+    }
+}
+
+impl From<u8> for Int512 {
+    fn from(val: u8) -> Self {
+        Int512(val.into())
+    }
+}
+
+// uint to int
+from_uint_to_int!(Uint64, Int512);
+from_uint_to_int!(Uint128, Int512);
+from_uint_to_int!(Uint256, Int512);
+try_from_uint_to_int!(Uint512, Int512);
+
+// int to uint
+try_from_int_to_uint!(Int512, Uint64);
+try_from_int_to_uint!(Int512, Uint128);
+try_from_int_to_uint!(Int512, Uint256);
+try_from_int_to_uint!(Int512, Uint512);
+
+// int to int
+from_int_to_int!(Int64, Int512);
+from_int_to_int!(Int128, Int512);
+from_int_to_int!(Int256, Int512);
+try_from_int_to_int!(Int512, Int64);
+try_from_int_to_int!(Int512, Int128);
+try_from_int_to_int!(Int512, Int256);
+
+impl TryFrom<&str> for Int512 {
+    type Error = StdError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        Self::from_str(val)
+    }
+}
+
+impl FromStr for Int512 {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() {
+            return Err(StdError::generic_err("Parsing i512: received empty string"));
+        }
+
+        match U512::from_dec_str(digits) {
+            Ok(abs) => Self::from_sign_and_abs(negative, abs)
+                .ok_or_else(|| StdError::generic_err("Parsing i512: value out of range")),
+            Err(e) => Err(StdError::generic_err(format!("Parsing i512: {}", e))),
+        }
+    }
+}
+
+impl From<Int512> for String {
+    fn from(original: Int512) -> Self {
+        original.to_string()
+    }
+}
+
+impl fmt::Display for Int512 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The inner type doesn't work as expected with padding, so we
+        // work around that.
+        let unpadded = self.unsigned_abs_raw().to_string();
+
+        f.pad_integral(!self.is_negative(), "", &unpadded)
+    }
+}
+
+impl fmt::Debug for Int512 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The derived implementation would print the raw bits in two's complement
+        write!(f, "Int512({})", self)
+    }
+}
+
+impl PartialOrd for Int512 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Int512 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // For equal signs, two's complement preserves the order of the raw bits
+            _ => self.0.cmp(&other.0),
+        }
+    }
+}
+
+impl Add<Int512> for Int512 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.strict_add(rhs)
+    }
+}
+forward_ref_binop!(impl Add, add for Int512, Int512);
+
+impl Sub<Int512> for Int512 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.strict_sub(rhs)
+    }
+}
+forward_ref_binop!(impl Sub, sub for Int512, Int512);
+
+impl SubAssign<Int512> for Int512 {
+    fn sub_assign(&mut self, rhs: Int512) {
+        *self = *self - rhs;
+    }
+}
+forward_ref_op_assign!(impl SubAssign, sub_assign for Int512, Int512);
+
+impl Mul<Int512> for Int512 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+forward_ref_binop!(impl Mul, mul for Int512, Int512);
+
+impl MulAssign<Int512> for Int512 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+forward_ref_op_assign!(impl MulAssign, mul_assign for Int512, Int512);
+
+impl Div<Int512> for Int512 {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This operation will panic if `rhs` is zero or if the result overflows,
+    /// which is the case for `Int512::MIN / -1`.
+    fn div(self, rhs: Self) -> Self::Output {
+        match self.checked_div(rhs) {
+            Ok(value) => value,
+            Err(DivisionError::DivideByZero) => panic!("attempt to divide by zero"),
+            Err(DivisionError::Overflow) => panic!("attempt to divide with overflow"),
+        }
+    }
+}
+forward_ref_binop!(impl Div, div for Int512, Int512);
+
+impl DivAssign<Int512> for Int512 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+forward_ref_op_assign!(impl DivAssign, div_assign for Int512, Int512);
+
+impl Rem for Int512 {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This operation will panic if `rhs` is zero or if the result overflows,
+    /// which is the case for `Int512::MIN % -1`.
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        match self.checked_rem(rhs) {
+            Ok(value) => value,
+            Err(DivisionError::DivideByZero) => {
+                panic!("attempt to calculate the remainder with a divisor of zero")
+            }
+            Err(DivisionError::Overflow) => {
+                panic!("attempt to calculate the remainder with overflow")
+            }
+        }
+    }
+}
+forward_ref_binop!(impl Rem, rem for Int512, Int512);
+
+impl RemAssign<Int512> for Int512 {
+    fn rem_assign(&mut self, rhs: Int512) {
+        *self = *self % rhs;
+    }
+}
+forward_ref_op_assign!(impl RemAssign, rem_assign for Int512, Int512);
+
+impl Neg for Int512 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        if self == Self::MIN {
+            panic!("attempt to negate with overflow");
+        }
+        Self(negate(self.0))
+    }
+}
+
+impl Shr<u32> for Int512 {
+    type Output = Self;
+
+    /// Arithmetic right shift, i.e. the sign is preserved.
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.checked_shr(rhs).unwrap_or_else(|_| {
+            panic!(
+                "right shift error: {} is larger or equal than the number of bits in Int512",
+                rhs,
+            )
+        })
+    }
+}
+forward_ref_binop!(impl Shr, shr for Int512, u32);
+
+impl Shl<u32> for Int512 {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.checked_shl(rhs).unwrap_or_else(|_| {
+            panic!(
+                "left shift error: {} is larger or equal than the number of bits in Int512",
+                rhs,
+            )
+        })
+    }
+}
+forward_ref_binop!(impl Shl, shl for Int512, u32);
+
+impl AddAssign<Int512> for Int512 {
+    fn add_assign(&mut self, rhs: Int512) {
+        *self = *self + rhs;
+    }
+}
+forward_ref_op_assign!(impl AddAssign, add_assign for Int512, Int512);
+
+impl ShrAssign<u32> for Int512 {
+    fn shr_assign(&mut self, rhs: u32) {
+        *self = Shr::<u32>::shr(*self, rhs);
+    }
+}
+forward_ref_op_assign!(impl ShrAssign, shr_assign for Int512, u32);
+
+impl ShlAssign<u32> for Int512 {
+    fn shl_assign(&mut self, rhs: u32) {
+        *self = Shl::<u32>::shl(*self, rhs);
+    }
+}
+forward_ref_op_assign!(impl ShlAssign, shl_assign for Int512, u32);
+
+impl Serialize for Int512 {
+    /// Serializes as an integer string using base 10
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Int512 {
+    /// Deserialized from an integer string using base 10
+    fn deserialize<D>(deserializer: D) -> Result<Int512, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(Int512Visitor)
+    }
+}
+
+struct Int512Visitor;
+
+impl<'de> de::Visitor<'de> for Int512Visitor {
+    type Value = Int512;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("string-encoded integer")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Int512::try_from(v).map_err(|e| E::custom(format!("invalid Int512 '{}' - {}", v, e)))
+    }
+}
+
+impl<A> std::iter::Sum<A> for Int512
+where
+    Self: Add<A, Output = Self>,
+{
+    fn sum<I: Iterator<Item = A>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl PartialEq<&Int512> for Int512 {
+    fn eq(&self, rhs: &&Int512) -> bool {
+        self == *rhs
+    }
+}
+
+impl PartialEq<Int512> for &Int512 {
+    fn eq(&self, rhs: &Int512) -> bool {
+        *self == rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_slice, to_vec, ConversionOverflowError};
+
+    #[test]
+    fn int512_zero_and_one_works() {
+        assert_eq!(Int512::zero().to_string(), "0");
+        assert_eq!(Int512::one().to_string(), "1");
+        assert!(Int512::zero().is_zero());
+        assert!(!Int512::one().is_zero());
+        assert_eq!(Int512::default(), Int512::zero());
+    }
+
+    #[test]
+    fn int512_from_primitives_works() {
+        assert_eq!(Int512::from(-5i8).to_string(), "-5");
+        assert_eq!(Int512::from(-300i16).to_string(), "-300");
+        assert_eq!(Int512::from(-70_000i32).to_string(), "-70000");
+        assert_eq!(Int512::from(5u8).to_string(), "5");
+        assert_eq!(Int512::from(300u16).to_string(), "300");
+        assert_eq!(Int512::from(4_000_000_000u32).to_string(), "4000000000");
+        assert_eq!(Int512::from(i64::MIN).to_string(), "-9223372036854775808");
+        assert_eq!(Int512::from(i64::MAX).to_string(), "9223372036854775807");
+    }
+
+    #[test]
+    fn int512_is_negative_works() {
+        assert!(Int512::MIN.is_negative());
+        assert!(Int512::from(-1i32).is_negative());
+        assert!(!Int512::zero().is_negative());
+        assert!(!Int512::one().is_negative());
+        assert!(!Int512::MAX.is_negative());
+    }
+
+    #[test]
+    fn int512_bytes_roundtrip() {
+        for value in [
+            Int512::MIN,
+            Int512::from(-258i32),
+            Int512::from(-1i32),
+            Int512::zero(),
+            Int512::one(),
+            Int512::from(258i32),
+            Int512::MAX,
+        ] {
+            assert_eq!(Int512::from_be_bytes(value.to_be_bytes()), value);
+            assert_eq!(Int512::from_le_bytes(value.to_le_bytes()), value);
+        }
+
+        let mut be = [0xffu8; 64];
+        be[64 - 1] = 0xfe;
+        assert_eq!(Int512::from_be_bytes(be), Int512::from(-2i32));
+        let mut le = [0u8; 64];
+        le[0] = 42;
+        assert_eq!(Int512::from_le_bytes(le), Int512::from(42i32));
+        assert_eq!(Int512::from(-2i32).to_be_bytes(), be);
+    }
+
+    #[test]
+    fn int512_ordering_works() {
+        assert!(Int512::MIN < Int512::from(-1i32));
+        assert!(Int512::from(-2i32) < Int512::from(-1i32));
+        assert!(Int512::from(-1i32) < Int512::zero());
+        assert!(Int512::zero() < Int512::one());
+        assert!(Int512::one() < Int512::MAX);
+        assert_eq!(
+            Int512::from(-7i32).cmp(&Int512::from(-7i32)),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn int512_from_str_works() {
+        assert_eq!(Int512::from_str("0").unwrap(), Int512::zero());
+        assert_eq!(Int512::from_str("123").unwrap(), Int512::from(123i32));
+        assert_eq!(Int512::from_str("-123").unwrap(), Int512::from(-123i32));
+        assert_eq!(
+            Int512::from_str(&Int512::MAX.to_string()).unwrap(),
+            Int512::MAX
+        );
+        assert_eq!(
+            Int512::from_str(&Int512::MIN.to_string()).unwrap(),
+            Int512::MIN
+        );
+        assert_eq!(Int512::try_from("-42").unwrap(), Int512::from(-42i32));
+
+        assert!(matches!(
+            Int512::from_str(""),
+            Err(StdError::GenericErr { msg, .. }) if msg.starts_with("Parsing i512: ")
+        ));
+        assert!(Int512::from_str("-").is_err());
+        assert!(Int512::from_str("1.5").is_err());
+        assert!(Int512::from_str("12a").is_err());
+        assert!(Int512::from_str("--1").is_err());
+        assert!(Int512::from_str(&format!("{}0", Int512::MAX)).is_err());
+        assert!(Int512::from_str(&format!("{}0", Int512::MIN)).is_err());
+    }
+
+    #[test]
+    fn int512_display_works() {
+        assert_eq!(format!("{}", Int512::from(-123i32)), "-123");
+        assert_eq!(
+            format!("Embedded: {:05}", Int512::from(123i32)),
+            "Embedded: 00123"
+        );
+        assert_eq!(
+            format!("Embedded: {:05}", Int512::from(-123i32)),
+            "Embedded: -0123"
+        );
+        assert_eq!(format!("{:>6}", Int512::from(-12i32)), "   -12");
+        assert_eq!(String::from(Int512::from(-9i32)), "-9");
+    }
+
+    #[test]
+    fn int512_debug_works() {
+        assert_eq!(format!("{:?}", Int512::from(123i32)), "Int512(123)");
+        assert_eq!(format!("{:?}", Int512::from(-5i32)), "Int512(-5)");
+        assert_eq!(
+            format!("{:?}", Int512::MIN),
+            "Int512(-6703903964971298549787012499102923063739682910296196688861780721860882015036773488400937149083451713845015929093243025426876941405973284973216824503042048)"
+        );
+    }
+
+    #[test]
+    fn int512_json_works() {
+        let orig = Int512::from(-1234567i32);
+        let serialized = to_vec(&orig).unwrap();
+        assert_eq!(serialized.as_slice(), b"\"-1234567\"");
+        let parsed: Int512 = from_slice(&serialized).unwrap();
+        assert_eq!(parsed, orig);
+
+        let parsed: Int512 = from_slice(format!("\"{}\"", Int512::MIN).as_bytes()).unwrap();
+        assert_eq!(parsed, Int512::MIN);
+
+        // numbers are not accepted
+        assert!(from_slice::<Int512>(b"-12").is_err());
+        let err = from_slice::<Int512>(b"\"1.5\"").unwrap_err();
+        assert!(err.to_string().contains("invalid Int512 '1.5'"), "{}", err);
+    }
+
+    #[test]
+    fn int512_math_works() {
+        let a = Int512::from(-12345i32);
+        let b = Int512::from(23i32);
+
+        assert_eq!(a + b, Int512::from(-12322i32));
+        assert_eq!(a - b, Int512::from(-12368i32));
+        assert_eq!(b - a, Int512::from(12368i32));
+        assert_eq!(a * b, Int512::from(-283935i32));
+        assert_eq!(a * -b, Int512::from(283935i32));
+        // division rounds towards zero
+        assert_eq!(a / b, Int512::from(-536i32));
+        assert_eq!(a % b, Int512::from(-17i32));
+        assert_eq!(-a / b, Int512::from(536i32));
+        assert_eq!(-a % b, Int512::from(17i32));
+        assert_eq!(a / -b, Int512::from(536i32));
+        assert_eq!(a % -b, Int512::from(-17i32));
+
+        // references
+        #[allow(clippy::op_ref)]
+        {
+            assert_eq!(a + &b, a + b);
+            assert_eq!(&a + b, a + b);
+            assert_eq!(&a - &b, a - b);
+            assert_eq!(&a * &b, a * b);
+            assert_eq!(&a / &b, a / b);
+            assert_eq!(&a % &b, a % b);
+        }
+
+        // assign
+        let mut c = a;
+        c += b;
+        assert_eq!(c, Int512::from(-12322i32));
+        c -= &b;
+        assert_eq!(c, a);
+        c *= b;
+        assert_eq!(c, Int512::from(-283935i32));
+        c /= &b;
+        assert_eq!(c, a);
+        c %= b;
+        assert_eq!(c, Int512::from(-17i32));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn int512_add_overflow_panics() {
+        let _ = Int512::MAX + Int512::one();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn int512_sub_overflow_panics() {
+        let _ = Int512::MIN - Int512::one();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn int512_mul_overflow_panics() {
+        let _ = Int512::MIN * Int512::from(-1i32);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn int512_div_by_zero_panics() {
+        let _ = Int512::one() / Int512::zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn int512_div_overflow_panics() {
+        let _ = Int512::MIN / Int512::from(-1i32);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn int512_neg_overflow_panics() {
+        let _ = -Int512::MIN;
+    }
+
+    #[test]
+    fn int512_neg_works() {
+        assert_eq!(-Int512::from(5i32), Int512::from(-5i32));
+        assert_eq!(-Int512::from(-5i32), Int512::from(5i32));
+        assert_eq!(-Int512::zero(), Int512::zero());
+        assert_eq!(-Int512::MAX, Int512::MIN + Int512::one());
+    }
+
+    #[test]
+    fn int512_pow_works() {
+        assert_eq!(Int512::from(2i32).pow(10), Int512::from(1024i32));
+        assert_eq!(Int512::from(-2i32).pow(3), Int512::from(-8i32));
+        assert_eq!(Int512::from(-2i32).pow(4), Int512::from(16i32));
+        assert_eq!(Int512::from(-7i32).pow(0), Int512::one());
+    }
+
+    #[test]
+    fn int512_checked_operations_work() {
+        let a = Int512::from(-10i32);
+        let b = Int512::from(3i32);
+
+        assert_eq!(a.checked_add(b), Ok(Int512::from(-7i32)));
+        assert!(matches!(
+            Int512::MAX.checked_add(Int512::one()),
+            Err(OverflowError {
+                operation: OverflowOperation::Add,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Int512::MIN.checked_add(Int512::from(-1i32)),
+            Err(OverflowError { .. })
+        ));
+
+        assert_eq!(a.checked_sub(b), Ok(Int512::from(-13i32)));
+        assert!(matches!(
+            Int512::MIN.checked_sub(Int512::one()),
+            Err(OverflowError {
+                operation: OverflowOperation::Sub,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Int512::MAX.checked_sub(Int512::from(-1i32)),
+            Err(OverflowError { .. })
+        ));
+
+        assert_eq!(a.checked_mul(b), Ok(Int512::from(-30i32)));
+        assert_eq!(a.checked_mul(-b), Ok(Int512::from(30i32)));
+        assert!(matches!(
+            Int512::MAX.checked_mul(Int512::from(2i32)),
+            Err(OverflowError {
+                operation: OverflowOperation::Mul,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Int512::MIN.checked_mul(Int512::from(-1i32)),
+            Err(OverflowError { .. })
+        ));
+        assert_eq!(Int512::MIN.checked_mul(Int512::one()), Ok(Int512::MIN));
+
+        assert_eq!(a.checked_pow(3), Ok(Int512::from(-1000i32)));
+        assert!(matches!(
+            Int512::MAX.checked_pow(2),
+            Err(OverflowError {
+                operation: OverflowOperation::Pow,
+                ..
+            })
+        ));
+
+        assert_eq!(a.checked_div(b), Ok(Int512::from(-3i32)));
+        assert_eq!(
+            a.checked_div(Int512::zero()),
+            Err(DivisionError::DivideByZero)
+        );
+        assert_eq!(
+            Int512::MIN.checked_div(Int512::from(-1i32)),
+            Err(DivisionError::Overflow)
+        );
+
+        assert_eq!(a.checked_rem(b), Ok(Int512::from(-1i32)));
+        assert_eq!(
+            a.checked_rem(Int512::zero()),
+            Err(DivisionError::DivideByZero)
+        );
+        assert_eq!(
+            Int512::MIN.checked_rem(Int512::from(-1i32)),
+            Err(DivisionError::Overflow)
+        );
+
+        // Euclidean division has a non-negative remainder
+        assert_eq!(a.checked_div_euclid(b), Ok(Int512::from(-4i32)));
+        assert_eq!(a.checked_div_euclid(-b), Ok(Int512::from(4i32)));
+        assert_eq!(
+            Int512::from(10i32).checked_div_euclid(-b),
+            Ok(Int512::from(-3i32))
+        );
+        assert_eq!(
+            Int512::from(9i32).checked_div_euclid(-b),
+            Ok(Int512::from(-3i32))
+        );
+        assert_eq!(
+            a.checked_div_euclid(Int512::zero()),
+            Err(DivisionError::DivideByZero)
+        );
+        assert_eq!(
+            Int512::MIN.checked_div_euclid(Int512::from(-1i32)),
+            Err(DivisionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn int512_shifts_work() {
+        // arithmetic shift keeps the sign
+        assert_eq!(Int512::from(-16i32) >> 2, Int512::from(-4i32));
+        assert_eq!(Int512::from(-1i32) >> (512 - 1), Int512::from(-1i32));
+        assert_eq!(Int512::from(-17i32) >> 2, Int512::from(-5i32));
+        assert_eq!(Int512::from(16i32) >> 2, Int512::from(4i32));
+        assert_eq!(Int512::MIN >> (512 - 1), Int512::from(-1i32));
+        assert_eq!(Int512::MAX >> (512 - 2), Int512::one());
+
+        assert_eq!(Int512::from(-3i32) << 2, Int512::from(-12i32));
+        assert_eq!(Int512::one() << (512 - 1), Int512::MIN);
+
+        let mut a = Int512::from(-64i32);
+        a >>= 3;
+        assert_eq!(a, Int512::from(-8i32));
+        a <<= &3;
+        assert_eq!(a, Int512::from(-64i32));
+
+        assert!(matches!(
+            Int512::one().checked_shr(512),
+            Err(OverflowError {
+                operation: OverflowOperation::Shr,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Int512::one().checked_shl(512),
+            Err(OverflowError {
+                operation: OverflowOperation::Shl,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "right shift error: 512 is larger or equal than the number of bits in Int512"
+    )]
+    fn int512_shr_overflow_panics() {
+        let _ = Int512::one() >> 512;
+    }
+
+    #[test]
+    fn int512_wrapping_operations_work() {
+        assert_eq!(Int512::MAX.wrapping_add(Int512::one()), Int512::MIN);
+        assert_eq!(Int512::MIN.wrapping_sub(Int512::one()), Int512::MAX);
+        assert_eq!(Int512::MIN.wrapping_mul(Int512::from(-1i32)), Int512::MIN);
+        assert_eq!(
+            Int512::MAX.wrapping_mul(Int512::from(2i32)),
+            Int512::from(-2i32)
+        );
+        assert_eq!(
+            Int512::from(-3i32).wrapping_mul(Int512::from(5i32)),
+            Int512::from(-15i32)
+        );
+        assert_eq!(Int512::from(-2i32).wrapping_pow(512 - 1), Int512::MIN);
+        assert_eq!(Int512::from(-2i32).wrapping_pow(512), Int512::zero());
+        assert_eq!(Int512::from(-3i32).wrapping_pow(3), Int512::from(-27i32));
+    }
+
+    #[test]
+    fn int512_saturating_operations_work() {
+        assert_eq!(Int512::MAX.saturating_add(Int512::one()), Int512::MAX);
+        assert_eq!(Int512::MIN.saturating_add(Int512::from(-1i32)), Int512::MIN);
+        assert_eq!(
+            Int512::from(-3i32).saturating_add(Int512::one()),
+            Int512::from(-2i32)
+        );
+        assert_eq!(Int512::MIN.saturating_sub(Int512::one()), Int512::MIN);
+        assert_eq!(Int512::MAX.saturating_sub(Int512::from(-1i32)), Int512::MAX);
+        assert_eq!(
+            Int512::from(-3i32).saturating_sub(Int512::one()),
+            Int512::from(-4i32)
+        );
+        assert_eq!(Int512::MAX.saturating_mul(Int512::from(2i32)), Int512::MAX);
+        assert_eq!(Int512::MAX.saturating_mul(Int512::from(-2i32)), Int512::MIN);
+        assert_eq!(Int512::MIN.saturating_mul(Int512::from(-1i32)), Int512::MAX);
+        assert_eq!(
+            Int512::from(-3i32).saturating_mul(Int512::from(3i32)),
+            Int512::from(-9i32)
+        );
+        assert_eq!(Int512::from(-2i32).saturating_pow(512), Int512::MAX);
+        assert_eq!(Int512::from(-2i32).saturating_pow(512 + 1), Int512::MIN);
+        assert_eq!(Int512::from(-2i32).saturating_pow(3), Int512::from(-8i32));
+    }
+
+    #[test]
+    fn int512_strict_operations_work() {
+        assert_eq!(
+            Int512::from(-3i32).strict_add(Int512::from(5i32)),
+            Int512::from(2i32)
+        );
+        assert_eq!(
+            Int512::from(-3i32).strict_sub(Int512::from(5i32)),
+            Int512::from(-8i32)
+        );
+        assert_eq!(Int512::MIN.strict_add(Int512::MAX), Int512::from(-1i32));
+        assert_eq!(Int512::MIN.strict_sub(Int512::MIN), Int512::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn int512_strict_add_panics_on_overflow() {
+        let _ = Int512::MIN.strict_add(Int512::from(-1i32));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn int512_strict_sub_panics_on_overflow() {
+        let _ = Int512::MAX.strict_sub(Int512::from(-1i32));
+    }
+
+    #[test]
+    fn int512_abs_works() {
+        assert_eq!(Int512::from(-5i32).abs(), Int512::from(5i32));
+        assert_eq!(Int512::from(5i32).abs(), Int512::from(5i32));
+        assert_eq!(Int512::zero().abs(), Int512::zero());
+        assert_eq!(Int512::MAX.abs(), Int512::MAX);
+
+        assert_eq!(Int512::from(-5i32).unsigned_abs(), Uint512::from(5u32));
+        assert_eq!(Int512::from(5i32).unsigned_abs(), Uint512::from(5u32));
+        assert_eq!(
+            Int512::MIN.unsigned_abs(),
+            Uint512::try_from(Int512::MAX).unwrap() + Uint512::one()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate absolute value with overflow")]
+    fn int512_abs_overflow_panics() {
+        let _ = Int512::MIN.abs();
+    }
+
+    #[test]
+    fn int512_abs_diff_works() {
+        let a = Int512::from(42i32);
+        let b = Int512::from(-5i32);
+        let expected = Uint512::from(47u32);
+        assert_eq!(a.abs_diff(b), expected);
+        assert_eq!(b.abs_diff(a), expected);
+        assert_eq!(a.abs_diff(a), Uint512::zero());
+        assert_eq!(Int512::MIN.abs_diff(Int512::MAX), Uint512::MAX);
+        assert_eq!(Int512::MAX.abs_diff(Int512::MIN), Uint512::MAX);
+    }
+
+    #[test]
+    fn int512_sum_works() {
+        let nums = vec![
+            Int512::from(17i32),
+            Int512::from(-123i32),
+            Int512::from(540i32),
+            Int512::from(-82i32),
+        ];
+        let expected = Int512::from(352i32);
+
+        let sum_as_ref: Int512 = nums.iter().sum();
+        assert_eq!(expected, sum_as_ref);
+
+        let sum_as_owned: Int512 = nums.into_iter().sum();
+        assert_eq!(expected, sum_as_owned);
+    }
+
+    #[test]
+    fn int512_partial_eq() {
+        let test_cases = [
+            (1, 1, true),
+            (-42, -42, true),
+            (42, -42, false),
+            (0, 0, true),
+        ]
+        .into_iter()
+        .map(|(lhs, rhs, expected): (i32, i32, bool)| {
+            (Int512::from(lhs), Int512::from(rhs), expected)
+        });
+
+        #[allow(clippy::op_ref)]
+        for (lhs, rhs, expected) in test_cases {
+            assert_eq!(lhs == rhs, expected);
+            assert_eq!(&lhs == rhs, expected);
+            assert_eq!(lhs == &rhs, expected);
+            assert_eq!(&lhs == &rhs, expected);
+        }
+    }
+
+    #[test]
+    fn int512_min_max_works() {
+        assert_eq!(
+            Int512::MIN.to_string(),
+            "-6703903964971298549787012499102923063739682910296196688861780721860882015036773488400937149083451713845015929093243025426876941405973284973216824503042048"
+        );
+        assert_eq!(
+            Int512::MAX.to_string(),
+            "6703903964971298549787012499102923063739682910296196688861780721860882015036773488400937149083451713845015929093243025426876941405973284973216824503042047"
+        );
+        assert_eq!(Int512::MIN.to_be_bytes()[0], 0x80);
+        assert_eq!(Int512::MAX.to_be_bytes()[0], 0x7f);
+        assert_eq!(Int512::MAX.wrapping_add(Int512::one()), Int512::MIN);
+    }
+
+    #[test]
+    fn int512_from_i128_works() {
+        const A: Int512 = Int512::from_i128(-258);
+        assert_eq!(A, Int512::from(-258i32));
+        assert_eq!(
+            Int512::from_i128(i128::MIN).to_string(),
+            i128::MIN.to_string()
+        );
+        assert_eq!(
+            Int512::from_i128(i128::MAX).to_string(),
+            i128::MAX.to_string()
+        );
+        assert_eq!(Int512::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert!(!Int512::from(u128::MAX).is_negative());
+    }
+
+    #[test]
+    fn int512_large_values_work() {
+        let a = Int512::from(u128::MAX) + Int512::one();
+        assert_eq!(a.to_string(), "340282366920938463463374607431768211456");
+        assert_eq!((-a).to_string(), "-340282366920938463463374607431768211456");
+        assert_eq!(
+            (a * -a).to_string(),
+            "-115792089237316195423570985008687907853269984665640564039457584007913129639936"
+        );
+        assert_eq!(-a / Int512::from(u128::MAX), Int512::from(-1i32));
+        assert_eq!(-a % Int512::from(u128::MAX), Int512::from(-1i32));
+        assert_eq!(
+            Int512::from_str("-340282366920938463463374607431768211456").unwrap(),
+            -a
+        );
+        assert!(-a < Int512::from(i128::MIN));
+        assert!(a > Int512::from(i128::MAX));
+    }
+
+    #[test]
+    fn int512_from_uints_works() {
+        assert_eq!(Int512::from(Uint64::MAX), Int512::from(u64::MAX));
+        assert_eq!(Int512::from(Uint128::MAX), Int512::from(u128::MAX));
+        assert_eq!(
+            Int512::from(Uint256::MAX).to_string(),
+            Uint256::MAX.to_string()
+        );
+
+        assert_eq!(
+            Int512::try_from(Uint512::from(42u32)).unwrap(),
+            Int512::from(42i32)
+        );
+        assert_eq!(Int512::try_from(Uint512::MAX >> 1).unwrap(), Int512::MAX);
+        let err = Int512::try_from(Uint512::MAX).unwrap_err();
+        assert_eq!(err.source_type, "Uint512");
+        assert_eq!(err.target_type, "Int512");
+        assert_eq!(err.value, Uint512::MAX.to_string());
+    }
+
+    #[test]
+    fn int512_try_into_uints_works() {
+        assert_eq!(
+            Uint64::try_from(Int512::from(u64::MAX)).unwrap(),
+            Uint64::MAX
+        );
+        assert!(Uint64::try_from(Int512::from(u64::MAX) + Int512::one()).is_err());
+        assert!(Uint64::try_from(Int512::from(-1i32)).is_err());
+
+        assert_eq!(
+            Uint128::try_from(Int512::from(u128::MAX)).unwrap(),
+            Uint128::MAX
+        );
+        assert!(Uint128::try_from(Int512::MAX).is_err());
+
+        assert_eq!(
+            Uint256::try_from(Int512::from(Uint256::MAX)).unwrap(),
+            Uint256::MAX
+        );
+        assert!(Uint256::try_from(Int512::from(Uint256::MAX) + Int512::one()).is_err());
+        assert!(Uint256::try_from(Int512::MIN).is_err());
+
+        assert_eq!(Uint512::try_from(Int512::MAX).unwrap(), Uint512::MAX >> 1);
+        let err = Uint512::try_from(Int512::from(-1i32)).unwrap_err();
+        assert_eq!(err, ConversionOverflowError::new("Int512", "Uint512", "-1"));
+    }
+
+    #[test]
+    fn int512_smaller_int_conversions_work() {
+        assert_eq!(Int512::from(Int64::MIN), Int512::from(i64::MIN));
+        assert_eq!(Int512::from(Int128::MIN), Int512::from(i128::MIN));
+        assert_eq!(
+            Int512::from(Int256::MIN).to_string(),
+            Int256::MIN.to_string()
+        );
+        assert_eq!(
+            Int512::from(Int256::MAX).to_string(),
+            Int256::MAX.to_string()
+        );
+
+        assert_eq!(
+            Int64::try_from(Int512::from(-42i32)).unwrap(),
+            Int64::from(-42i32)
+        );
+        assert!(Int64::try_from(Int512::from(i64::MIN) - Int512::one()).is_err());
+        assert_eq!(
+            Int128::try_from(Int512::from(i128::MAX)).unwrap(),
+            Int128::MAX
+        );
+        assert!(Int128::try_from(Int512::from(i128::MAX) + Int512::one()).is_err());
+
+        assert_eq!(
+            Int256::try_from(Int512::from(Int256::MIN)).unwrap(),
+            Int256::MIN
+        );
+        assert_eq!(
+            Int256::try_from(Int512::from(Int256::MAX)).unwrap(),
+            Int256::MAX
+        );
+        assert!(Int256::try_from(Int512::from(Int256::MIN) - Int512::one()).is_err());
+        assert!(Int256::try_from(Int512::from(Int256::MAX) + Int512::one()).is_err());
+        assert!(Int256::try_from(Int512::MIN).is_err());
+        assert!(Int256::try_from(Int512::MAX).is_err());
+    }
+}
@@ -0,0 +1,1271 @@
+use forward_ref::{forward_ref_binop, forward_ref_op_assign};
+use schemars::JsonSchema;
+use serde::{de, ser, Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr,
+    ShrAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+use crate::errors::{
+    CheckedMultiplyRatioError, DivisionError, OverflowError, OverflowOperation, StdError,
+};
+use crate::math::conversion::{try_from_int_to_uint, try_from_uint_to_int};
+use crate::{Int128, Uint128, Uint256, Uint512, Uint64};
+
+/// An implementation of i64 that is using strings for JSON encoding/decoding,
+/// such that the full i64 range can be used for clients that convert JSON numbers to floats,
+/// like JavaScript and jq.
+///
+/// # Examples
+///
+/// Use `from` to create instances of this and `i64` to get the value out:
+///
+/// ```
+/// # use cosmwasm_std::Int64;
+/// let a = Int64::from(258i64);
+/// assert_eq!(a.i64(), 258);
+///
+/// let b = Int64::from(-70i32);
+/// assert_eq!(b.i64(), -70);
+/// ```
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema)]
+pub struct Int64(#[schemars(with = "String")] i64);
+
+impl Int64 {
+    pub const MAX: Self = Self(i64::MAX);
+    pub const MIN: Self = Self(i64::MIN);
+
+    /// Creates a Int64(value).
+    ///
+    /// This method is less flexible than `from` but can be called in a const context.
+    #[inline]
+    pub const fn new(value: i64) -> Self {
+        Self(value)
+    }
+
+    /// Creates a Int64(0)
+    #[inline]
+    pub const fn zero() -> Self {
+        Int64(0)
+    }
+
+    /// Creates a Int64(1)
+    #[inline]
+    pub const fn one() -> Self {
+        Self(1)
+    }
+
+    /// Returns a copy of the internal data
+    pub const fn i64(&self) -> i64 {
+        self.0
+    }
+
+    /// Creates a Int64 from its big endian two's complement representation.
+    pub const fn from_be_bytes(data: [u8; 8]) -> Self {
+        Self(i64::from_be_bytes(data))
+    }
+
+    /// Creates a Int64 from its little endian two's complement representation.
+    pub const fn from_le_bytes(data: [u8; 8]) -> Self {
+        Self(i64::from_le_bytes(data))
+    }
+
+    /// Returns a copy of the number as big endian bytes in two's complement.
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns a copy of the number as little endian bytes in two's complement.
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if the number is strictly negative
+    pub const fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    pub fn pow(self, exp: u32) -> Self {
+        Self(self.0.pow(exp))
+    }
+
+    /// Returns `self * numerator / denominator`.
+    ///
+    /// Due to the nature of the integer division involved, the result is always rounded
+    /// towards zero. E.g. 5 * 99/100 = 4 and -5 * 99/100 = -4.
+    pub fn multiply_ratio<A: Into<Self>, B: Into<Self>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Int64 {
+        match self.checked_multiply_ratio(numerator, denominator) {
+            Ok(value) => value,
+            Err(CheckedMultiplyRatioError::DivideByZero) => {
+                panic!("Denominator must not be zero")
+            }
+            Err(CheckedMultiplyRatioError::Overflow) => panic!("Multiplication overflow"),
+        }
+    }
+
+    /// Returns `self * numerator / denominator`.
+    ///
+    /// Due to the nature of the integer division involved, the result is always rounded
+    /// towards zero. E.g. 5 * 99/100 = 4 and -5 * 99/100 = -4.
+    pub fn checked_multiply_ratio<A: Into<Self>, B: Into<Self>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Result<Int64, CheckedMultiplyRatioError> {
+        let numerator: Self = numerator.into();
+        let denominator: Self = denominator.into();
+        if denominator.is_zero() {
+            return Err(CheckedMultiplyRatioError::DivideByZero);
+        }
+        match (self.full_mul(numerator) / Int128::from(denominator)).try_into() {
+            Ok(ratio) => Ok(ratio),
+            Err(_) => Err(CheckedMultiplyRatioError::Overflow),
+        }
+    }
+
+    /// Multiplies two i64 values without overflow, producing an
+    /// [`Int128`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cosmwasm_std::Int64;
+    ///
+    /// let a = Int64::MAX;
+    /// let result = a.full_mul(2i32);
+    /// assert_eq!(result.to_string(), "18446744073709551614");
+    /// ```
+    pub fn full_mul(self, rhs: impl Into<Self>) -> Int128 {
+        Int128::from(self)
+            .checked_mul(Int128::from(rhs.into()))
+            .unwrap()
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_add(other.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Add, self, other))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_sub(other.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Sub, self, other))
+    }
+
+    pub fn checked_mul(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_mul(other.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Mul, self, other))
+    }
+
+    pub fn checked_pow(self, exp: u32) -> Result<Self, OverflowError> {
+        self.0
+            .checked_pow(exp)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Pow, self, exp))
+    }
+
+    pub fn checked_div(self, other: Self) -> Result<Self, DivisionError> {
+        if other.is_zero() {
+            return Err(DivisionError::DivideByZero);
+        }
+        self.0
+            .checked_div(other.0)
+            .map(Self)
+            .ok_or(DivisionError::Overflow)
+    }
+
+    pub fn checked_div_euclid(self, other: Self) -> Result<Self, DivisionError> {
+        if other.is_zero() {
+            return Err(DivisionError::DivideByZero);
+        }
+        self.0
+            .checked_div_euclid(other.0)
+            .map(Self)
+            .ok_or(DivisionError::Overflow)
+    }
+
+    pub fn checked_rem(self, other: Self) -> Result<Self, DivisionError> {
+        if other.is_zero() {
+            return Err(DivisionError::DivideByZero);
+        }
+        self.0
+            .checked_rem(other.0)
+            .map(Self)
+            .ok_or(DivisionError::Overflow)
+    }
+
+    pub fn checked_shr(self, other: u32) -> Result<Self, OverflowError> {
+        if other >= 64 {
+            return Err(OverflowError::new(OverflowOperation::Shr, self, other));
+        }
+
+        Ok(Self(self.0.shr(other)))
+    }
+
+    pub fn checked_shl(self, other: u32) -> Result<Self, OverflowError> {
+        if other >= 64 {
+            return Err(OverflowError::new(OverflowOperation::Shl, self, other));
+        }
+
+        Ok(Self(self.0.shl(other)))
+    }
+
+    #[inline]
+    pub fn wrapping_add(self, other: Self) -> Self {
+        Self(self.0.wrapping_add(other.0))
+    }
+
+    #[inline]
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        Self(self.0.wrapping_sub(other.0))
+    }
+
+    #[inline]
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        Self(self.0.wrapping_mul(other.0))
+    }
+
+    #[inline]
+    pub fn wrapping_pow(self, other: u32) -> Self {
+        Self(self.0.wrapping_pow(other))
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    pub fn saturating_mul(self, other: Self) -> Self {
+        Self(self.0.saturating_mul(other.0))
+    }
+
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        Self(self.0.saturating_pow(exp))
+    }
+
+    /// Strict integer addition. Computes `self + rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Int64::add`] but const.
+    pub const fn strict_add(self, rhs: Self) -> Self {
+        match self.0.checked_add(rhs.0) {
+            None => panic!("attempt to add with overflow"),
+            Some(sum) => Self(sum),
+        }
+    }
+
+    /// Strict integer subtraction. Computes `self - rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Int64::sub`] but const.
+    pub const fn strict_sub(self, other: Self) -> Self {
+        match self.0.checked_sub(other.0) {
+            None => panic!("attempt to subtract with overflow"),
+            Some(diff) => Self(diff),
+        }
+    }
+
+    /// Returns the absolute difference between `self` and `other`,
+    /// which always fits into the unsigned counterpart.
+    pub const fn abs_diff(self, other: Self) -> Uint64 {
+        Uint64::new(if self.0 < other.0 {
+            (other.0 as u64).wrapping_sub(self.0 as u64)
+        } else {
+            (self.0 as u64).wrapping_sub(other.0 as u64)
+        })
+    }
+
+    /// Returns the absolute value.
+    ///
+    /// # Panics
+    ///
+    /// This panics for [`Int64::MIN`] because its absolute value does not fit
+    /// into the type. Use [`Int64::unsigned_abs`] for a lossless alternative.
+    pub const fn abs(self) -> Self {
+        match self.0.checked_abs() {
+            Some(abs) => Self(abs),
+            None => panic!("attempt to calculate absolute value with overflow"),
+        }
+    }
+
+    /// Returns the absolute value as its unsigned counterpart. This never overflows.
+    pub const fn unsigned_abs(self) -> Uint64 {
+        Uint64::new(self.0.unsigned_abs())
+    }
+}
+
+// `From<{i,u}{N}>` is implemented manually instead of
+// using `impl<T: Into<i64>> From<T> for Int64` because
+// of the conflict with `TryFrom<&str>` as described here
+// https://stackoverflow.com/questions/63136970/how-do-i-work-around-the-upstream-crates-may-add-a-new-impl-of-trait-error
+
+impl From<i64> for Int64 {
+    fn from(val: i64) -> Self {
+        Int64(val)
+    }
+}
+
+impl From<i32> for Int64 {
+    fn from(val: i32) -> Self {
+        Int64(val.into())
+    }
+}
+
+impl From<i16> for Int64 {
+    fn from(val: i16) -> Self {
+        Int64(val.into())
+    }
+}
+
+impl From<i8> for Int64 {
+    fn from(val: i8) -> Self {
+        Int64(val.into())
+    }
+}
+
+impl From<u32> for Int64 {
+    fn from(val: u32) -> Self {
+        Int64(val.into())
+    }
+}
+
+impl From<u16> for Int64 {
+    fn from(val: u16) -> Self {
+        Int64(val.into())
+    }
+}
+
+impl From<u8> for Int64 {
+    fn from(val: u8) -> Self {
+        Int64(val.into())
+    }
+}
+
+// uint to int
+try_from_uint_to_int!(Uint64, Int64);
+try_from_uint_to_int!(Uint128, Int64);
+try_from_uint_to_int!(Uint256, Int64);
+try_from_uint_to_int!(Uint512, Int64);
+
+// int to uint
+try_from_int_to_uint!(Int64, Uint64);
+try_from_int_to_uint!(Int64, Uint128);
+try_from_int_to_uint!(Int64, Uint256);
+try_from_int_to_uint!(Int64, Uint512);
+
+impl TryFrom<&str> for Int64 {
+    type Error = StdError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        Self::from_str(val)
+    }
+}
+
+impl FromStr for Int64 {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<i64>() {
+            Ok(i) => Ok(Self(i)),
+            Err(e) => Err(StdError::generic_err(format!("Parsing i64: {}", e))),
+        }
+    }
+}
+
+impl From<Int64> for String {
+    fn from(original: Int64) -> Self {
+        original.to_string()
+    }
+}
+
+impl From<Int64> for i64 {
+    fn from(original: Int64) -> Self {
+        original.0
+    }
+}
+
+impl fmt::Display for Int64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Add<Int64> for Int64 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.strict_add(rhs)
+    }
+}
+forward_ref_binop!(impl Add, add for Int64, Int64);
+
+impl Sub<Int64> for Int64 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.strict_sub(rhs)
+    }
+}
+forward_ref_binop!(impl Sub, sub for Int64, Int64);
+
+impl SubAssign<Int64> for Int64 {
+    fn sub_assign(&mut self, rhs: Int64) {
+        *self = *self - rhs;
+    }
+}
+forward_ref_op_assign!(impl SubAssign, sub_assign for Int64, Int64);
+
+impl Mul<Int64> for Int64 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(
+            self.0
+                .checked_mul(rhs.0)
+                .expect("attempt to multiply with overflow"),
+        )
+    }
+}
+forward_ref_binop!(impl Mul, mul for Int64, Int64);
+
+impl MulAssign<Int64> for Int64 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+forward_ref_op_assign!(impl MulAssign, mul_assign for Int64, Int64);
+
+impl Div<Int64> for Int64 {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This operation will panic if `rhs` is zero or if the result overflows,
+    /// which is the case for `Int64::MIN / -1`.
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(self.0 / rhs.0)
+    }
+}
+forward_ref_binop!(impl Div, div for Int64, Int64);
+
+impl DivAssign<Int64> for Int64 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+forward_ref_op_assign!(impl DivAssign, div_assign for Int64, Int64);
+
+impl Rem for Int64 {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This operation will panic if `rhs` is zero or if the result overflows,
+    /// which is the case for `Int64::MIN % -1`.
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        Self(self.0.rem(rhs.0))
+    }
+}
+forward_ref_binop!(impl Rem, rem for Int64, Int64);
+
+impl RemAssign<Int64> for Int64 {
+    fn rem_assign(&mut self, rhs: Int64) {
+        *self = *self % rhs;
+    }
+}
+forward_ref_op_assign!(impl RemAssign, rem_assign for Int64, Int64);
+
+impl Neg for Int64 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(
+            self.0
+                .checked_neg()
+                .expect("attempt to negate with overflow"),
+        )
+    }
+}
+
+impl Shr<u32> for Int64 {
+    type Output = Self;
+
+    /// Arithmetic right shift, i.e. the sign is preserved.
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.checked_shr(rhs).unwrap_or_else(|_| {
+            panic!(
+                "right shift error: {} is larger or equal than the number of bits in Int64",
+                rhs,
+            )
+        })
+    }
+}
+forward_ref_binop!(impl Shr, shr for Int64, u32);
+
+impl Shl<u32> for Int64 {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.checked_shl(rhs).unwrap_or_else(|_| {
+            panic!(
+                "left shift error: {} is larger or equal than the number of bits in Int64",
+                rhs,
+            )
+        })
+    }
+}
+forward_ref_binop!(impl Shl, shl for Int64, u32);
+
+impl AddAssign<Int64> for Int64 {
+    fn add_assign(&mut self, rhs: Int64) {
+        *self = *self + rhs;
+    }
+}
+forward_ref_op_assign!(impl AddAssign, add_assign for Int64, Int64);
+
+impl ShrAssign<u32> for Int64 {
+    fn shr_assign(&mut self, rhs: u32) {
+        *self = Shr::<u32>::shr(*self, rhs);
+    }
+}
+forward_ref_op_assign!(impl ShrAssign, shr_assign for Int64, u32);
+
+impl ShlAssign<u32> for Int64 {
+    fn shl_assign(&mut self, rhs: u32) {
+        *self = Shl::<u32>::shl(*self, rhs);
+    }
+}
+forward_ref_op_assign!(impl ShlAssign, shl_assign for Int64, u32);
+
+impl Serialize for Int64 {
+    /// Serializes as an integer string using base 10
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Int64 {
+    /// Deserialized from an integer string using base 10
+    fn deserialize<D>(deserializer: D) -> Result<Int64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(Int64Visitor)
+    }
+}
+
+struct Int64Visitor;
+
+impl<'de> de::Visitor<'de> for Int64Visitor {
+    type Value = Int64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("string-encoded integer")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Int64::try_from(v).map_err(|e| E::custom(format!("invalid Int64 '{}' - {}", v, e)))
+    }
+}
+
+impl<A> std::iter::Sum<A> for Int64
+where
+    Self: Add<A, Output = Self>,
+{
+    fn sum<I: Iterator<Item = A>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl PartialEq<&Int64> for Int64 {
+    fn eq(&self, rhs: &&Int64) -> bool {
+        self == *rhs
+    }
+}
+
+impl PartialEq<Int64> for &Int64 {
+    fn eq(&self, rhs: &Int64) -> bool {
+        *self == rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_slice, to_vec, ConversionOverflowError};
+
+    #[test]
+    fn int64_zero_and_one_works() {
+        assert_eq!(Int64::zero().to_string(), "0");
+        assert_eq!(Int64::one().to_string(), "1");
+        assert!(Int64::zero().is_zero());
+        assert!(!Int64::one().is_zero());
+        assert_eq!(Int64::default(), Int64::zero());
+    }
+
+    #[test]
+    fn int64_from_primitives_works() {
+        assert_eq!(Int64::from(-5i8).to_string(), "-5");
+        assert_eq!(Int64::from(-300i16).to_string(), "-300");
+        assert_eq!(Int64::from(-70_000i32).to_string(), "-70000");
+        assert_eq!(Int64::from(5u8).to_string(), "5");
+        assert_eq!(Int64::from(300u16).to_string(), "300");
+        assert_eq!(Int64::from(4_000_000_000u32).to_string(), "4000000000");
+        assert_eq!(Int64::from(i64::MIN).to_string(), "-9223372036854775808");
+        assert_eq!(Int64::from(i64::MAX).to_string(), "9223372036854775807");
+    }
+
+    #[test]
+    fn int64_is_negative_works() {
+        assert!(Int64::MIN.is_negative());
+        assert!(Int64::from(-1i32).is_negative());
+        assert!(!Int64::zero().is_negative());
+        assert!(!Int64::one().is_negative());
+        assert!(!Int64::MAX.is_negative());
+    }
+
+    #[test]
+    fn int64_bytes_roundtrip() {
+        for value in [
+            Int64::MIN,
+            Int64::from(-258i32),
+            Int64::from(-1i32),
+            Int64::zero(),
+            Int64::one(),
+            Int64::from(258i32),
+            Int64::MAX,
+        ] {
+            assert_eq!(Int64::from_be_bytes(value.to_be_bytes()), value);
+            assert_eq!(Int64::from_le_bytes(value.to_le_bytes()), value);
+        }
+
+        let mut be = [0xffu8; 8];
+        be[8 - 1] = 0xfe;
+        assert_eq!(Int64::from_be_bytes(be), Int64::from(-2i32));
+        let mut le = [0u8; 8];
+        le[0] = 42;
+        assert_eq!(Int64::from_le_bytes(le), Int64::from(42i32));
+        assert_eq!(Int64::from(-2i32).to_be_bytes(), be);
+    }
+
+    #[test]
+    fn int64_ordering_works() {
+        assert!(Int64::MIN < Int64::from(-1i32));
+        assert!(Int64::from(-2i32) < Int64::from(-1i32));
+        assert!(Int64::from(-1i32) < Int64::zero());
+        assert!(Int64::zero() < Int64::one());
+        assert!(Int64::one() < Int64::MAX);
+        assert_eq!(
+            Int64::from(-7i32).cmp(&Int64::from(-7i32)),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn int64_from_str_works() {
+        assert_eq!(Int64::from_str("0").unwrap(), Int64::zero());
+        assert_eq!(Int64::from_str("123").unwrap(), Int64::from(123i32));
+        assert_eq!(Int64::from_str("-123").unwrap(), Int64::from(-123i32));
+        assert_eq!(
+            Int64::from_str(&Int64::MAX.to_string()).unwrap(),
+            Int64::MAX
+        );
+        assert_eq!(
+            Int64::from_str(&Int64::MIN.to_string()).unwrap(),
+            Int64::MIN
+        );
+        assert_eq!(Int64::try_from("-42").unwrap(), Int64::from(-42i32));
+
+        assert!(matches!(
+            Int64::from_str(""),
+            Err(StdError::GenericErr { msg, .. }) if msg.starts_with("Parsing i64: ")
+        ));
+        assert!(Int64::from_str("-").is_err());
+        assert!(Int64::from_str("1.5").is_err());
+        assert!(Int64::from_str("12a").is_err());
+        assert!(Int64::from_str("--1").is_err());
+        assert!(Int64::from_str(&format!("{}0", Int64::MAX)).is_err());
+        assert!(Int64::from_str(&format!("{}0", Int64::MIN)).is_err());
+    }
+
+    #[test]
+    fn int64_display_works() {
+        assert_eq!(format!("{}", Int64::from(-123i32)), "-123");
+        assert_eq!(
+            format!("Embedded: {:05}", Int64::from(123i32)),
+            "Embedded: 00123"
+        );
+        assert_eq!(
+            format!("Embedded: {:05}", Int64::from(-123i32)),
+            "Embedded: -0123"
+        );
+        assert_eq!(format!("{:>6}", Int64::from(-12i32)), "   -12");
+        assert_eq!(String::from(Int64::from(-9i32)), "-9");
+    }
+
+    #[test]
+    fn int64_json_works() {
+        let orig = Int64::from(-1234567i32);
+        let serialized = to_vec(&orig).unwrap();
+        assert_eq!(serialized.as_slice(), b"\"-1234567\"");
+        let parsed: Int64 = from_slice(&serialized).unwrap();
+        assert_eq!(parsed, orig);
+
+        let parsed: Int64 = from_slice(format!("\"{}\"", Int64::MIN).as_bytes()).unwrap();
+        assert_eq!(parsed, Int64::MIN);
+
+        // numbers are not accepted
+        assert!(from_slice::<Int64>(b"-12").is_err());
+        let err = from_slice::<Int64>(b"\"1.5\"").unwrap_err();
+        assert!(err.to_string().contains("invalid Int64 '1.5'"), "{}", err);
+    }
+
+    #[test]
+    fn int64_math_works() {
+        let a = Int64::from(-12345i32);
+        let b = Int64::from(23i32);
+
+        assert_eq!(a + b, Int64::from(-12322i32));
+        assert_eq!(a - b, Int64::from(-12368i32));
+        assert_eq!(b - a, Int64::from(12368i32));
+        assert_eq!(a * b, Int64::from(-283935i32));
+        assert_eq!(a * -b, Int64::from(283935i32));
+        // division rounds towards zero
+        assert_eq!(a / b, Int64::from(-536i32));
+        assert_eq!(a % b, Int64::from(-17i32));
+        assert_eq!(-a / b, Int64::from(536i32));
+        assert_eq!(-a % b, Int64::from(17i32));
+        assert_eq!(a / -b, Int64::from(536i32));
+        assert_eq!(a % -b, Int64::from(-17i32));
+
+        // references
+        #[allow(clippy::op_ref)]
+        {
+            assert_eq!(a + &b, a + b);
+            assert_eq!(&a + b, a + b);
+            assert_eq!(&a - &b, a - b);
+            assert_eq!(&a * &b, a * b);
+            assert_eq!(&a / &b, a / b);
+            assert_eq!(&a % &b, a % b);
+        }
+
+        // assign
+        let mut c = a;
+        c += b;
+        assert_eq!(c, Int64::from(-12322i32));
+        c -= &b;
+        assert_eq!(c, a);
+        c *= b;
+        assert_eq!(c, Int64::from(-283935i32));
+        c /= &b;
+        assert_eq!(c, a);
+        c %= b;
+        assert_eq!(c, Int64::from(-17i32));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn int64_add_overflow_panics() {
+        let _ = Int64::MAX + Int64::one();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn int64_sub_overflow_panics() {
+        let _ = Int64::MIN - Int64::one();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn int64_mul_overflow_panics() {
+        let _ = Int64::MIN * Int64::from(-1i32);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn int64_div_by_zero_panics() {
+        let _ = Int64::one() / Int64::zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn int64_div_overflow_panics() {
+        let _ = Int64::MIN / Int64::from(-1i32);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn int64_neg_overflow_panics() {
+        let _ = -Int64::MIN;
+    }
+
+    #[test]
+    fn int64_neg_works() {
+        assert_eq!(-Int64::from(5i32), Int64::from(-5i32));
+        assert_eq!(-Int64::from(-5i32), Int64::from(5i32));
+        assert_eq!(-Int64::zero(), Int64::zero());
+        assert_eq!(-Int64::MAX, Int64::MIN + Int64::one());
+    }
+
+    #[test]
+    fn int64_pow_works() {
+        assert_eq!(Int64::from(2i32).pow(10), Int64::from(1024i32));
+        assert_eq!(Int64::from(-2i32).pow(3), Int64::from(-8i32));
+        assert_eq!(Int64::from(-2i32).pow(4), Int64::from(16i32));
+        assert_eq!(Int64::from(-7i32).pow(0), Int64::one());
+    }
+
+    #[test]
+    fn int64_checked_operations_work() {
+        let a = Int64::from(-10i32);
+        let b = Int64::from(3i32);
+
+        assert_eq!(a.checked_add(b), Ok(Int64::from(-7i32)));
+        assert!(matches!(
+            Int64::MAX.checked_add(Int64::one()),
+            Err(OverflowError {
+                operation: OverflowOperation::Add,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Int64::MIN.checked_add(Int64::from(-1i32)),
+            Err(OverflowError { .. })
+        ));
+
+        assert_eq!(a.checked_sub(b), Ok(Int64::from(-13i32)));
+        assert!(matches!(
+            Int64::MIN.checked_sub(Int64::one()),
+            Err(OverflowError {
+                operation: OverflowOperation::Sub,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Int64::MAX.checked_sub(Int64::from(-1i32)),
+            Err(OverflowError { .. })
+        ));
+
+        assert_eq!(a.checked_mul(b), Ok(Int64::from(-30i32)));
+        assert_eq!(a.checked_mul(-b), Ok(Int64::from(30i32)));
+        assert!(matches!(
+            Int64::MAX.checked_mul(Int64::from(2i32)),
+            Err(OverflowError {
+                operation: OverflowOperation::Mul,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Int64::MIN.checked_mul(Int64::from(-1i32)),
+            Err(OverflowError { .. })
+        ));
+        assert_eq!(Int64::MIN.checked_mul(Int64::one()), Ok(Int64::MIN));
+
+        assert_eq!(a.checked_pow(3), Ok(Int64::from(-1000i32)));
+        assert!(matches!(
+            Int64::MAX.checked_pow(2),
+            Err(OverflowError {
+                operation: OverflowOperation::Pow,
+                ..
+            })
+        ));
+
+        assert_eq!(a.checked_div(b), Ok(Int64::from(-3i32)));
+        assert_eq!(
+            a.checked_div(Int64::zero()),
+            Err(DivisionError::DivideByZero)
+        );
+        assert_eq!(
+            Int64::MIN.checked_div(Int64::from(-1i32)),
+            Err(DivisionError::Overflow)
+        );
+
+        assert_eq!(a.checked_rem(b), Ok(Int64::from(-1i32)));
+        assert_eq!(
+            a.checked_rem(Int64::zero()),
+            Err(DivisionError::DivideByZero)
+        );
+        assert_eq!(
+            Int64::MIN.checked_rem(Int64::from(-1i32)),
+            Err(DivisionError::Overflow)
+        );
+
+        // Euclidean division has a non-negative remainder
+        assert_eq!(a.checked_div_euclid(b), Ok(Int64::from(-4i32)));
+        assert_eq!(a.checked_div_euclid(-b), Ok(Int64::from(4i32)));
+        assert_eq!(
+            Int64::from(10i32).checked_div_euclid(-b),
+            Ok(Int64::from(-3i32))
+        );
+        assert_eq!(
+            Int64::from(9i32).checked_div_euclid(-b),
+            Ok(Int64::from(-3i32))
+        );
+        assert_eq!(
+            a.checked_div_euclid(Int64::zero()),
+            Err(DivisionError::DivideByZero)
+        );
+        assert_eq!(
+            Int64::MIN.checked_div_euclid(Int64::from(-1i32)),
+            Err(DivisionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn int64_shifts_work() {
+        // arithmetic shift keeps the sign
+        assert_eq!(Int64::from(-16i32) >> 2, Int64::from(-4i32));
+        assert_eq!(Int64::from(-1i32) >> (64 - 1), Int64::from(-1i32));
+        assert_eq!(Int64::from(-17i32) >> 2, Int64::from(-5i32));
+        assert_eq!(Int64::from(16i32) >> 2, Int64::from(4i32));
+        assert_eq!(Int64::MIN >> (64 - 1), Int64::from(-1i32));
+        assert_eq!(Int64::MAX >> (64 - 2), Int64::one());
+
+        assert_eq!(Int64::from(-3i32) << 2, Int64::from(-12i32));
+        assert_eq!(Int64::one() << (64 - 1), Int64::MIN);
+
+        let mut a = Int64::from(-64i32);
+        a >>= 3;
+        assert_eq!(a, Int64::from(-8i32));
+        a <<= &3;
+        assert_eq!(a, Int64::from(-64i32));
+
+        assert!(matches!(
+            Int64::one().checked_shr(64),
+            Err(OverflowError {
+                operation: OverflowOperation::Shr,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Int64::one().checked_shl(64),
+            Err(OverflowError {
+                operation: OverflowOperation::Shl,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "right shift error: 64 is larger or equal than the number of bits in Int64"
+    )]
+    fn int64_shr_overflow_panics() {
+        let _ = Int64::one() >> 64;
+    }
+
+    #[test]
+    fn int64_wrapping_operations_work() {
+        assert_eq!(Int64::MAX.wrapping_add(Int64::one()), Int64::MIN);
+        assert_eq!(Int64::MIN.wrapping_sub(Int64::one()), Int64::MAX);
+        assert_eq!(Int64::MIN.wrapping_mul(Int64::from(-1i32)), Int64::MIN);
+        assert_eq!(
+            Int64::MAX.wrapping_mul(Int64::from(2i32)),
+            Int64::from(-2i32)
+        );
+        assert_eq!(
+            Int64::from(-3i32).wrapping_mul(Int64::from(5i32)),
+            Int64::from(-15i32)
+        );
+        assert_eq!(Int64::from(-2i32).wrapping_pow(64 - 1), Int64::MIN);
+        assert_eq!(Int64::from(-2i32).wrapping_pow(64), Int64::zero());
+        assert_eq!(Int64::from(-3i32).wrapping_pow(3), Int64::from(-27i32));
+    }
+
+    #[test]
+    fn int64_saturating_operations_work() {
+        assert_eq!(Int64::MAX.saturating_add(Int64::one()), Int64::MAX);
+        assert_eq!(Int64::MIN.saturating_add(Int64::from(-1i32)), Int64::MIN);
+        assert_eq!(
+            Int64::from(-3i32).saturating_add(Int64::one()),
+            Int64::from(-2i32)
+        );
+        assert_eq!(Int64::MIN.saturating_sub(Int64::one()), Int64::MIN);
+        assert_eq!(Int64::MAX.saturating_sub(Int64::from(-1i32)), Int64::MAX);
+        assert_eq!(
+            Int64::from(-3i32).saturating_sub(Int64::one()),
+            Int64::from(-4i32)
+        );
+        assert_eq!(Int64::MAX.saturating_mul(Int64::from(2i32)), Int64::MAX);
+        assert_eq!(Int64::MAX.saturating_mul(Int64::from(-2i32)), Int64::MIN);
+        assert_eq!(Int64::MIN.saturating_mul(Int64::from(-1i32)), Int64::MAX);
+        assert_eq!(
+            Int64::from(-3i32).saturating_mul(Int64::from(3i32)),
+            Int64::from(-9i32)
+        );
+        assert_eq!(Int64::from(-2i32).saturating_pow(64), Int64::MAX);
+        assert_eq!(Int64::from(-2i32).saturating_pow(64 + 1), Int64::MIN);
+        assert_eq!(Int64::from(-2i32).saturating_pow(3), Int64::from(-8i32));
+    }
+
+    #[test]
+    fn int64_strict_operations_work() {
+        assert_eq!(
+            Int64::from(-3i32).strict_add(Int64::from(5i32)),
+            Int64::from(2i32)
+        );
+        assert_eq!(
+            Int64::from(-3i32).strict_sub(Int64::from(5i32)),
+            Int64::from(-8i32)
+        );
+        assert_eq!(Int64::MIN.strict_add(Int64::MAX), Int64::from(-1i32));
+        assert_eq!(Int64::MIN.strict_sub(Int64::MIN), Int64::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn int64_strict_add_panics_on_overflow() {
+        let _ = Int64::MIN.strict_add(Int64::from(-1i32));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn int64_strict_sub_panics_on_overflow() {
+        let _ = Int64::MAX.strict_sub(Int64::from(-1i32));
+    }
+
+    #[test]
+    fn int64_abs_works() {
+        assert_eq!(Int64::from(-5i32).abs(), Int64::from(5i32));
+        assert_eq!(Int64::from(5i32).abs(), Int64::from(5i32));
+        assert_eq!(Int64::zero().abs(), Int64::zero());
+        assert_eq!(Int64::MAX.abs(), Int64::MAX);
+
+        assert_eq!(Int64::from(-5i32).unsigned_abs(), Uint64::from(5u32));
+        assert_eq!(Int64::from(5i32).unsigned_abs(), Uint64::from(5u32));
+        assert_eq!(
+            Int64::MIN.unsigned_abs(),
+            Uint64::try_from(Int64::MAX).unwrap() + Uint64::one()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate absolute value with overflow")]
+    fn int64_abs_overflow_panics() {
+        let _ = Int64::MIN.abs();
+    }
+
+    #[test]
+    fn int64_abs_diff_works() {
+        let a = Int64::from(42i32);
+        let b = Int64::from(-5i32);
+        let expected = Uint64::from(47u32);
+        assert_eq!(a.abs_diff(b), expected);
+        assert_eq!(b.abs_diff(a), expected);
+        assert_eq!(a.abs_diff(a), Uint64::zero());
+        assert_eq!(Int64::MIN.abs_diff(Int64::MAX), Uint64::MAX);
+        assert_eq!(Int64::MAX.abs_diff(Int64::MIN), Uint64::MAX);
+    }
+
+    #[test]
+    fn int64_multiply_ratio_works() {
+        let base = Int64::from(500i32);
+
+        // factor 1/1
+        assert_eq!(base.multiply_ratio(1i32, 1i32), base);
+        assert_eq!(base.multiply_ratio(3i32, 3i32), base);
+        assert_eq!(base.multiply_ratio(654321i32, 654321i32), base);
+        assert_eq!(base.multiply_ratio(Int64::MAX, Int64::MAX), base);
+
+        // factor 3/2
+        assert_eq!(base.multiply_ratio(3i32, 2i32), Int64::from(750i32));
+        assert_eq!(base.multiply_ratio(-3i32, 2i32), Int64::from(-750i32));
+        assert_eq!(base.multiply_ratio(3i32, -2i32), Int64::from(-750i32));
+        assert_eq!(base.multiply_ratio(-3i32, -2i32), Int64::from(750i32));
+
+        // rounds towards zero
+        assert_eq!(
+            Int64::from(5i32).multiply_ratio(99i32, 100i32),
+            Int64::from(4i32)
+        );
+        assert_eq!(
+            Int64::from(-5i32).multiply_ratio(99i32, 100i32),
+            Int64::from(-4i32)
+        );
+
+        // does not overflow in the intermediate step
+        assert_eq!(
+            Int64::MAX.multiply_ratio(Int64::MAX, Int64::MAX),
+            Int64::MAX
+        );
+        assert_eq!(
+            Int64::MIN.multiply_ratio(Int64::MIN, Int64::MIN),
+            Int64::MIN
+        );
+        assert_eq!(
+            Int64::MIN.multiply_ratio(2i32, 4i32),
+            Int64::MIN / Int64::from(2i32)
+        );
+
+        assert_eq!(
+            Int64::MAX.checked_multiply_ratio(2i32, 1i32),
+            Err(CheckedMultiplyRatioError::Overflow)
+        );
+        assert_eq!(
+            Int64::MIN.checked_multiply_ratio(-1i32, 1i32),
+            Err(CheckedMultiplyRatioError::Overflow)
+        );
+        assert_eq!(
+            base.checked_multiply_ratio(1i32, 0i32),
+            Err(CheckedMultiplyRatioError::DivideByZero)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Denominator must not be zero")]
+    fn int64_multiply_ratio_panics_for_zero_denominator() {
+        Int64::from(500i32).multiply_ratio(1i32, 0i32);
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiplication overflow")]
+    fn int64_multiply_ratio_panics_on_overflow() {
+        Int64::MAX.multiply_ratio(2i32, 1i32);
+    }
+
+    #[test]
+    fn int64_full_mul_works() {
+        assert_eq!(Int64::from(-3i32).full_mul(7i32), Int128::from(-21i32));
+        assert_eq!(
+            Int64::MIN.full_mul(Int64::MIN),
+            Int128::from(Int64::MIN) * Int128::from(Int64::MIN)
+        );
+        assert_eq!(
+            Int64::MAX.full_mul(Int64::MIN),
+            Int128::from(Int64::MAX) * Int128::from(Int64::MIN)
+        );
+    }
+
+    #[test]
+    fn int64_sum_works() {
+        let nums = vec![
+            Int64::from(17i32),
+            Int64::from(-123i32),
+            Int64::from(540i32),
+            Int64::from(-82i32),
+        ];
+        let expected = Int64::from(352i32);
+
+        let sum_as_ref: Int64 = nums.iter().sum();
+        assert_eq!(expected, sum_as_ref);
+
+        let sum_as_owned: Int64 = nums.into_iter().sum();
+        assert_eq!(expected, sum_as_owned);
+    }
+
+    #[test]
+    fn int64_partial_eq() {
+        let test_cases = [
+            (1, 1, true),
+            (-42, -42, true),
+            (42, -42, false),
+            (0, 0, true),
+        ]
+        .into_iter()
+        .map(|(lhs, rhs, expected): (i32, i32, bool)| {
+            (Int64::from(lhs), Int64::from(rhs), expected)
+        });
+
+        #[allow(clippy::op_ref)]
+        for (lhs, rhs, expected) in test_cases {
+            assert_eq!(lhs == rhs, expected);
+            assert_eq!(&lhs == rhs, expected);
+            assert_eq!(lhs == &rhs, expected);
+            assert_eq!(&lhs == &rhs, expected);
+        }
+    }
+
+    #[test]
+    fn int64_try_from_uints_works() {
+        assert_eq!(
+            Int64::try_from(Uint64::new(42)).unwrap(),
+            Int64::from(42i32)
+        );
+        assert_eq!(
+            Int64::try_from(Uint64::new(i64::MAX as u64)).unwrap(),
+            Int64::MAX
+        );
+        let err = Int64::try_from(Uint64::new(i64::MAX as u64 + 1)).unwrap_err();
+        assert_eq!(
+            err,
+            ConversionOverflowError::new("Uint64", "Int64", "9223372036854775808")
+        );
+        assert!(Int64::try_from(Uint64::MAX).is_err());
+
+        assert_eq!(
+            Int64::try_from(Uint128::new(42)).unwrap(),
+            Int64::from(42i32)
+        );
+        assert_eq!(
+            Int64::try_from(Uint128::new(i64::MAX as u128)).unwrap(),
+            Int64::MAX
+        );
+        assert!(Int64::try_from(Uint128::new(i64::MAX as u128 + 1)).is_err());
+        assert!(Int64::try_from(Uint128::new(1u128 << 64)).is_err());
+
+        assert_eq!(
+            Int64::try_from(Uint256::from(42u32)).unwrap(),
+            Int64::from(42i32)
+        );
+        assert!(Int64::try_from(Uint256::MAX).is_err());
+        assert_eq!(
+            Int64::try_from(Uint512::from(42u32)).unwrap(),
+            Int64::from(42i32)
+        );
+        assert!(Int64::try_from(Uint512::from(1u128 << 64)).is_err());
+    }
+
+    #[test]
+    fn int64_try_into_uints_works() {
+        assert_eq!(
+            Uint64::try_from(Int64::from(42i32)).unwrap(),
+            Uint64::new(42)
+        );
+        assert_eq!(
+            Uint64::try_from(Int64::MAX).unwrap(),
+            Uint64::new(i64::MAX as u64)
+        );
+        let err = Uint64::try_from(Int64::from(-1i32)).unwrap_err();
+        assert_eq!(err, ConversionOverflowError::new("Int64", "Uint64", "-1"));
+        assert!(Uint64::try_from(Int64::MIN).is_err());
+
+        assert_eq!(
+            Uint128::try_from(Int64::MAX).unwrap(),
+            Uint128::new(i64::MAX as u128)
+        );
+        assert!(Uint128::try_from(Int64::from(-1i32)).is_err());
+        assert_eq!(
+            Uint256::try_from(Int64::from(7i32)).unwrap(),
+            Uint256::from(7u32)
+        );
+        assert!(Uint256::try_from(Int64::MIN).is_err());
+        assert_eq!(
+            Uint512::try_from(Int64::from(7i32)).unwrap(),
+            Uint512::from(7u32)
+        );
+        assert!(Uint512::try_from(Int64::from(-7i32)).is_err());
+    }
+}
@@ -1,7 +1,15 @@
+mod conversion;
 mod decimal;
 mod decimal256;
 mod fraction;
+mod int128;
+mod int256;
+mod int512;
+mod int64;
 mod isqrt;
+mod signed_decimal;
+mod signed_decimal256;
+mod transcendental;
 mod uint128;
 mod uint256;
 mod uint512;
@@ -10,7 +18,13 @@ mod uint64;
 pub use decimal::{Decimal, DecimalRangeExceeded};
 pub use decimal256::{Decimal256, Decimal256RangeExceeded};
 pub use fraction::Fraction;
+pub use int128::Int128;
+pub use int256::Int256;
+pub use int512::Int512;
+pub use int64::Int64;
 pub use isqrt::Isqrt;
+pub use signed_decimal::{SignedDecimal, SignedDecimalRangeExceeded};
+pub use signed_decimal256::{SignedDecimal256, SignedDecimal256RangeExceeded};
 pub use uint128::Uint128;
 pub use uint256::Uint256;
 pub use uint512::Uint512;
@@ -54,6 +68,12 @@ mod tests {
     impl AllImpl<'_> for Uint128 {}
     impl AllImpl<'_> for Uint256 {}
     impl AllImpl<'_> for Uint512 {}
+    impl AllImpl<'_> for Int64 {}
+    impl AllImpl<'_> for Int128 {}
+    impl AllImpl<'_> for Int256 {}
+    impl AllImpl<'_> for Int512 {}
     impl AllImpl<'_> for Decimal {}
     impl AllImpl<'_> for Decimal256 {}
+    impl AllImpl<'_> for SignedDecimal {}
+    impl AllImpl<'_> for SignedDecimal256 {}
 }
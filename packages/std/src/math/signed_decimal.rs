@@ -0,0 +1,1504 @@
+use forward_ref::{forward_ref_binop, forward_ref_op_assign};
+use schemars::JsonSchema;
+use serde::{de, ser, Deserialize, Deserializer, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+use thiserror::Error;
+
+use crate::errors::{
+    CheckedFromRatioError, CheckedMultiplyRatioError, DivideByZeroError, DivisionError,
+    OverflowError, OverflowOperation, RoundDownOverflowError, RoundUpOverflowError, StdError,
+};
+use crate::{Decimal, DecimalRangeExceeded};
+
+use super::Fraction;
+use super::{Int128, Int256, Uint128};
+
+/// A signed fixed-point decimal value with 18 fractional digits, i.e. SignedDecimal(1_000_000_000_000_000_000) == 1.0
+///
+/// The greatest possible value that can be represented is 170141183460469231731.687303715884105727 (which is (2^127 - 1) / 10^18)
+/// and the smallest is -170141183460469231731.687303715884105728 (which is -2^127 / 10^18).
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema)]
+pub struct SignedDecimal(#[schemars(with = "String")] Int128);
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("SignedDecimal range exceeded")]
+pub struct SignedDecimalRangeExceeded;
+
+impl SignedDecimal {
+    const DECIMAL_FRACTIONAL: Int128 = Int128::new(1_000_000_000_000_000_000i128); // 1*10**18
+    const DECIMAL_FRACTIONAL_SQUARED: Int128 =
+        Int128::new(1_000_000_000_000_000_000_000_000_000_000_000_000i128); // (1*10**18)**2 = 1*10**36
+
+    /// The number of decimal places. Since decimal types are fixed-point rather than
+    /// floating-point, this is a constant.
+    pub const DECIMAL_PLACES: u32 = 18;
+    /// The largest value that can be represented by this signed decimal type.
+    pub const MAX: Self = Self(Int128::MAX);
+    /// The smallest value that can be represented by this signed decimal type.
+    pub const MIN: Self = Self(Int128::MIN);
+
+    /// Creates a SignedDecimal(value)
+    /// This is equivalent to `SignedDecimal::from_atomics(value, 18)` but usable in a const context.
+    pub const fn new(value: Int128) -> Self {
+        Self(value)
+    }
+
+    /// Creates a SignedDecimal(Int128(value))
+    /// This is equivalent to `SignedDecimal::from_atomics(value, 18)` but usable in a const context.
+    pub const fn raw(value: i128) -> Self {
+        Self(Int128::new(value))
+    }
+
+    /// Create a 1.0 SignedDecimal
+    #[inline]
+    pub const fn one() -> Self {
+        Self(Self::DECIMAL_FRACTIONAL)
+    }
+
+    /// Create a -1.0 SignedDecimal
+    #[inline]
+    pub const fn negative_one() -> Self {
+        Self::raw(-1_000_000_000_000_000_000i128)
+    }
+
+    /// Create a 0.0 SignedDecimal
+    #[inline]
+    pub const fn zero() -> Self {
+        Self(Int128::zero())
+    }
+
+    /// Convert x% into SignedDecimal
+    pub fn percent(x: i64) -> Self {
+        Self(((x as i128) * 10_000_000_000_000_000).into())
+    }
+
+    /// Convert permille (x/1000) into SignedDecimal
+    pub fn permille(x: i64) -> Self {
+        Self(((x as i128) * 1_000_000_000_000_000).into())
+    }
+
+    /// Creates a signed decimal from a number of atomic units and the number
+    /// of decimal places. The inputs will be converted internally to form
+    /// a decimal with 18 decimal places. So the input -123 and 2 will create
+    /// the decimal -1.23.
+    ///
+    /// Using 18 decimal places is slightly more efficient than other values
+    /// as no internal conversion is necessary.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::{SignedDecimal, Int128};
+    /// let a = SignedDecimal::from_atomics(Int128::new(1234), 3).unwrap();
+    /// assert_eq!(a.to_string(), "1.234");
+    ///
+    /// let a = SignedDecimal::from_atomics(-1234i128, 0).unwrap();
+    /// assert_eq!(a.to_string(), "-1234");
+    ///
+    /// let a = SignedDecimal::from_atomics(-1i64, 18).unwrap();
+    /// assert_eq!(a.to_string(), "-0.000000000000000001");
+    /// ```
+    pub fn from_atomics(
+        atomics: impl Into<Int128>,
+        decimal_places: u32,
+    ) -> Result<Self, SignedDecimalRangeExceeded> {
+        let atomics = atomics.into();
+        const TEN: Int128 = Int128::new(10);
+        Ok(match decimal_places.cmp(&(Self::DECIMAL_PLACES)) {
+            Ordering::Less => {
+                let digits = (Self::DECIMAL_PLACES) - decimal_places; // No overflow because decimal_places < DECIMAL_PLACES
+                let factor = TEN.checked_pow(digits).unwrap(); // Safe because digits <= 17
+                Self(
+                    atomics
+                        .checked_mul(factor)
+                        .map_err(|_| SignedDecimalRangeExceeded)?,
+                )
+            }
+            Ordering::Equal => Self(atomics),
+            Ordering::Greater => {
+                let digits = decimal_places - (Self::DECIMAL_PLACES); // No overflow because decimal_places > DECIMAL_PLACES
+                if let Ok(factor) = TEN.checked_pow(digits) {
+                    Self(atomics.checked_div(factor).unwrap()) // Safe because factor is greater than one
+                } else {
+                    // In this case `factor` exceeds the Int128 range.
+                    // Any Int128 `x` divided by `factor` with `factor > Int128::MAX` is 0.
+                    // Try e.g. Python3: `int((2**127-1) / 2**127)`
+                    Self(Int128::zero())
+                }
+            }
+        })
+    }
+
+    /// Returns the ratio (numerator / denominator) as a SignedDecimal
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::SignedDecimal;
+    /// assert_eq!(SignedDecimal::from_ratio(1, 3).to_string(), "0.333333333333333333");
+    /// assert_eq!(SignedDecimal::from_ratio(-1, 3).to_string(), "-0.333333333333333333");
+    /// ```
+    pub fn from_ratio(numerator: impl Into<Int128>, denominator: impl Into<Int128>) -> Self {
+        match SignedDecimal::checked_from_ratio(numerator, denominator) {
+            Ok(value) => value,
+            Err(CheckedFromRatioError::DivideByZero) => {
+                panic!("Denominator must not be zero")
+            }
+            Err(CheckedFromRatioError::Overflow) => panic!("Multiplication overflow"),
+        }
+    }
+
+    /// Returns the ratio (numerator / denominator) as a SignedDecimal
+    pub fn checked_from_ratio(
+        numerator: impl Into<Int128>,
+        denominator: impl Into<Int128>,
+    ) -> Result<Self, CheckedFromRatioError> {
+        let numerator: Int128 = numerator.into();
+        let denominator: Int128 = denominator.into();
+        match numerator.checked_multiply_ratio(Self::DECIMAL_FRACTIONAL, denominator) {
+            Ok(ratio) => {
+                // numerator * DECIMAL_FRACTIONAL / denominator
+                Ok(SignedDecimal(ratio))
+            }
+            Err(CheckedMultiplyRatioError::Overflow) => Err(CheckedFromRatioError::Overflow),
+            Err(CheckedMultiplyRatioError::DivideByZero) => {
+                Err(CheckedFromRatioError::DivideByZero)
+            }
+        }
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns `true` if the number is strictly negative and `false` if it is zero or positive.
+    pub const fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// A decimal is an integer of atomic units plus a number that specifies the
+    /// position of the decimal dot. So any decimal can be expressed as two numbers.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::{SignedDecimal, Int128};
+    /// # use std::str::FromStr;
+    /// // Value with whole and fractional part
+    /// let a = SignedDecimal::from_str("-1.234").unwrap();
+    /// assert_eq!(a.decimal_places(), 18);
+    /// assert_eq!(a.atomics(), Int128::new(-1234000000000000000));
+    ///
+    /// // Smallest possible positive value
+    /// let b = SignedDecimal::from_str("0.000000000000000001").unwrap();
+    /// assert_eq!(b.decimal_places(), 18);
+    /// assert_eq!(b.atomics(), Int128::new(1));
+    /// ```
+    #[inline]
+    pub const fn atomics(&self) -> Int128 {
+        self.0
+    }
+
+    /// The number of decimal places. This is a constant value for now
+    /// but this could potentially change as the type evolves.
+    ///
+    /// See also [`SignedDecimal::atomics()`].
+    #[inline]
+    pub const fn decimal_places(&self) -> u32 {
+        Self::DECIMAL_PLACES
+    }
+
+    /// Rounds value by truncating the decimal places, i.e. towards zero.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use cosmwasm_std::SignedDecimal;
+    /// # use std::str::FromStr;
+    /// assert_eq!(SignedDecimal::from_str("1.5").unwrap().trunc().to_string(), "1");
+    /// assert_eq!(SignedDecimal::from_str("-1.5").unwrap().trunc().to_string(), "-1");
+    /// ```
+    pub fn trunc(&self) -> Self {
+        Self((self.0 / Self::DECIMAL_FRACTIONAL) * Self::DECIMAL_FRACTIONAL)
+    }
+
+    /// Rounds value down after decimal places, i.e. towards negative infinity.
+    /// Panics on overflow.
+    pub fn floor(&self) -> Self {
+        match self.checked_floor() {
+            Ok(value) => value,
+            Err(_) => panic!("attempt to floor with overflow"),
+        }
+    }
+
+    /// Rounds value down after decimal places. Returns RoundDownOverflowError on overflow.
+    pub fn checked_floor(&self) -> Result<Self, RoundDownOverflowError> {
+        let truncated = self.trunc();
+        if self.is_negative() && truncated != self {
+            truncated
+                .checked_sub(SignedDecimal::one())
+                .map_err(|_| RoundDownOverflowError)
+        } else {
+            Ok(truncated)
+        }
+    }
+
+    /// Rounds value up after decimal places, i.e. towards positive infinity.
+    /// Panics on overflow.
+    pub fn ceil(&self) -> Self {
+        match self.checked_ceil() {
+            Ok(value) => value,
+            Err(_) => panic!("attempt to ceil with overflow"),
+        }
+    }
+
+    /// Rounds value up after decimal places. Returns RoundUpOverflowError on overflow.
+    pub fn checked_ceil(&self) -> Result<Self, RoundUpOverflowError> {
+        let truncated = self.trunc();
+        if !self.is_negative() && truncated != self {
+            truncated
+                .checked_add(SignedDecimal::one())
+                .map_err(|_| RoundUpOverflowError)
+        } else {
+            Ok(truncated)
+        }
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_add(other.0)
+            .map(Self)
+            .map_err(|_| OverflowError::new(OverflowOperation::Add, self, other))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_sub(other.0)
+            .map(Self)
+            .map_err(|_| OverflowError::new(OverflowOperation::Sub, self, other))
+    }
+
+    /// Multiplies one `SignedDecimal` by another, returning an `OverflowError` if an overflow occurred.
+    pub fn checked_mul(self, other: Self) -> Result<Self, OverflowError> {
+        let result_as_int256 =
+            self.numerator().full_mul(other.numerator()) / Int256::from(Self::DECIMAL_FRACTIONAL);
+        result_as_int256
+            .try_into()
+            .map(Self)
+            .map_err(|_| OverflowError::new(OverflowOperation::Mul, self, other))
+    }
+
+    /// Raises a value to the power of `exp`, panics if an overflow occurred.
+    pub fn pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Ok(value) => value,
+            Err(_) => panic!("Multiplication overflow"),
+        }
+    }
+
+    /// Raises a value to the power of `exp`, returning an `OverflowError` if an overflow occurred.
+    pub fn checked_pow(self, exp: u32) -> Result<Self, OverflowError> {
+        // This uses the exponentiation by squaring algorithm:
+        // https://en.wikipedia.org/wiki/Exponentiation_by_squaring#Basic_method
+
+        fn inner(mut x: SignedDecimal, mut n: u32) -> Result<SignedDecimal, OverflowError> {
+            if n == 0 {
+                return Ok(SignedDecimal::one());
+            }
+
+            let mut y = SignedDecimal::one();
+
+            while n > 1 {
+                if n % 2 == 0 {
+                    x = x.checked_mul(x)?;
+                    n /= 2;
+                } else {
+                    y = x.checked_mul(y)?;
+                    x = x.checked_mul(x)?;
+                    n = (n - 1) / 2;
+                }
+            }
+
+            x.checked_mul(y)
+        }
+
+        inner(self, exp).map_err(|_| OverflowError::new(OverflowOperation::Pow, self, exp))
+    }
+
+    pub fn checked_div(self, other: Self) -> Result<Self, CheckedFromRatioError> {
+        SignedDecimal::checked_from_ratio(self.numerator(), other.numerator())
+    }
+
+    pub fn checked_rem(self, other: Self) -> Result<Self, DivideByZeroError> {
+        match self.0.checked_rem(other.0) {
+            Ok(remainder) => Ok(Self(remainder)),
+            Err(DivisionError::DivideByZero) => Err(DivideByZeroError::new(self)),
+            // Only happens for `MIN % -0.000000000000000001`, which is zero
+            Err(DivisionError::Overflow) => Ok(Self::zero()),
+        }
+    }
+
+    /// Returns the absolute value. Panics for [`SignedDecimal::MIN`].
+    pub fn abs(&self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns the absolute difference between `self` and `other`
+    /// as an unsigned [`Decimal`], which can always represent it.
+    pub const fn abs_diff(self, other: Self) -> Decimal {
+        Decimal::new(self.0.abs_diff(other.0))
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        match self.checked_add(other) {
+            Ok(value) => value,
+            Err(_) if other.is_negative() => Self::MIN,
+            Err(_) => Self::MAX,
+        }
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        match self.checked_sub(other) {
+            Ok(value) => value,
+            Err(_) if other.is_negative() => Self::MAX,
+            Err(_) => Self::MIN,
+        }
+    }
+
+    pub fn saturating_mul(self, other: Self) -> Self {
+        match self.checked_mul(other) {
+            Ok(value) => value,
+            Err(_) if self.is_negative() != other.is_negative() => Self::MIN,
+            Err(_) => Self::MAX,
+        }
+    }
+
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Ok(value) => value,
+            Err(_) if self.is_negative() && exp % 2 == 1 => Self::MIN,
+            Err(_) => Self::MAX,
+        }
+    }
+}
+
+impl Fraction<Int128> for SignedDecimal {
+    #[inline]
+    fn numerator(&self) -> Int128 {
+        self.0
+    }
+
+    #[inline]
+    fn denominator(&self) -> Int128 {
+        Self::DECIMAL_FRACTIONAL
+    }
+
+    /// Returns the multiplicative inverse `1/d` for decimal `d`.
+    ///
+    /// If `d` is zero, none is returned.
+    fn inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Let self be p/q with p = self.0 and q = DECIMAL_FRACTIONAL.
+            // Now we calculate the inverse a/b = q/p such that b = DECIMAL_FRACTIONAL. Then
+            // `a = DECIMAL_FRACTIONAL*DECIMAL_FRACTIONAL / self.0`.
+            Some(SignedDecimal(Self::DECIMAL_FRACTIONAL_SQUARED / self.0))
+        }
+    }
+}
+
+impl TryFrom<Decimal> for SignedDecimal {
+    type Error = SignedDecimalRangeExceeded;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        // Both decimal types have the same decimal places, so only the atomics need converting.
+        Int128::try_from(value.atomics())
+            .map(SignedDecimal)
+            .map_err(|_| SignedDecimalRangeExceeded)
+    }
+}
+
+impl TryFrom<SignedDecimal> for Decimal {
+    type Error = DecimalRangeExceeded;
+
+    fn try_from(value: SignedDecimal) -> Result<Self, Self::Error> {
+        // Both decimal types have the same decimal places, so only the atomics need converting.
+        Uint128::try_from(value.atomics())
+            .map(Decimal::new)
+            .map_err(|_| DecimalRangeExceeded)
+    }
+}
+
+impl FromStr for SignedDecimal {
+    type Err = StdError;
+
+    /// Converts the decimal string to a SignedDecimal
+    /// Possible inputs: "1.23", "-1.23", "1", "000012", "1.123000000"
+    /// Disallowed: "", ".23", "-.23"
+    ///
+    /// This never performs any kind of rounding.
+    /// More than DECIMAL_PLACES fractional digits, even zeros, result in an error.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (negative, unsigned) = match input.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, input),
+        };
+        let mut parts_iter = unsigned.split('.');
+
+        let whole_part = parts_iter.next().unwrap(); // split always returns at least one element
+        let whole = whole_part
+            .parse::<Uint128>()
+            .map_err(|_| StdError::generic_err("Error parsing whole"))?;
+        let whole = Int128::try_from(whole)
+            .ok()
+            .and_then(|whole| whole.checked_mul(Self::DECIMAL_FRACTIONAL).ok())
+            .ok_or_else(|| StdError::generic_err("Value too big"))?;
+        let mut atomics = if negative { -whole } else { whole };
+
+        if let Some(fractional_part) = parts_iter.next() {
+            let fractional = fractional_part
+                .parse::<Uint128>()
+                .map_err(|_| StdError::generic_err("Error parsing fractional"))?;
+            let exp = (Self::DECIMAL_PLACES.checked_sub(fractional_part.len() as u32)).ok_or_else(
+                || {
+                    StdError::generic_err(format!(
+                        "Cannot parse more than {} fractional digits",
+                        Self::DECIMAL_PLACES
+                    ))
+                },
+            )?;
+            debug_assert!(exp <= Self::DECIMAL_PLACES);
+            let fractional_factor = Int128::from(10i128.pow(exp));
+            // The conversion and multiplication can't overflow because
+            // fractional < 10^DECIMAL_PLACES && fractional_factor <= 10^DECIMAL_PLACES
+            let fractional = Int128::try_from(fractional)
+                .unwrap()
+                .checked_mul(fractional_factor)
+                .unwrap();
+            atomics = if negative {
+                atomics.checked_sub(fractional)
+            } else {
+                atomics.checked_add(fractional)
+            }
+            .map_err(|_| StdError::generic_err("Value too big"))?;
+        }
+
+        if parts_iter.next().is_some() {
+            return Err(StdError::generic_err("Unexpected number of dots"));
+        }
+
+        Ok(SignedDecimal(atomics))
+    }
+}
+
+impl fmt::Display for SignedDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The absolute value always fits into the unsigned counterpart
+        let absolute = Decimal::new(self.0.unsigned_abs());
+        if self.is_negative() {
+            write!(f, "-{}", absolute)
+        } else {
+            write!(f, "{}", absolute)
+        }
+    }
+}
+
+impl Add for SignedDecimal {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        SignedDecimal(self.0 + other.0)
+    }
+}
+forward_ref_binop!(impl Add, add for SignedDecimal, SignedDecimal);
+
+impl AddAssign for SignedDecimal {
+    fn add_assign(&mut self, rhs: SignedDecimal) {
+        *self = *self + rhs;
+    }
+}
+forward_ref_op_assign!(impl AddAssign, add_assign for SignedDecimal, SignedDecimal);
+
+impl Sub for SignedDecimal {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        SignedDecimal(self.0 - other.0)
+    }
+}
+forward_ref_binop!(impl Sub, sub for SignedDecimal, SignedDecimal);
+
+impl SubAssign for SignedDecimal {
+    fn sub_assign(&mut self, rhs: SignedDecimal) {
+        *self = *self - rhs;
+    }
+}
+forward_ref_op_assign!(impl SubAssign, sub_assign for SignedDecimal, SignedDecimal);
+
+impl Mul for SignedDecimal {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, other: Self) -> Self {
+        // Decimals are fractions. We can multiply two decimals a and b
+        // via
+        //       (a.numerator() * b.numerator()) / (a.denominator() * b.denominator())
+        //     = (a.numerator() * b.numerator()) / a.denominator() / b.denominator()
+
+        let result_as_int256 =
+            self.numerator().full_mul(other.numerator()) / Int256::from(Self::DECIMAL_FRACTIONAL);
+        match result_as_int256.try_into() {
+            Ok(result) => Self(result),
+            Err(_) => panic!("attempt to multiply with overflow"),
+        }
+    }
+}
+forward_ref_binop!(impl Mul, mul for SignedDecimal, SignedDecimal);
+
+impl MulAssign for SignedDecimal {
+    fn mul_assign(&mut self, rhs: SignedDecimal) {
+        *self = *self * rhs;
+    }
+}
+forward_ref_op_assign!(impl MulAssign, mul_assign for SignedDecimal, SignedDecimal);
+
+impl Div for SignedDecimal {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        match SignedDecimal::checked_from_ratio(self.numerator(), other.numerator()) {
+            Ok(ratio) => ratio,
+            Err(CheckedFromRatioError::DivideByZero) => {
+                panic!("Division failed - denominator must not be zero")
+            }
+            Err(CheckedFromRatioError::Overflow) => {
+                panic!("Division failed - multiplication overflow")
+            }
+        }
+    }
+}
+forward_ref_binop!(impl Div, div for SignedDecimal, SignedDecimal);
+
+impl DivAssign for SignedDecimal {
+    fn div_assign(&mut self, rhs: SignedDecimal) {
+        *self = *self / rhs;
+    }
+}
+forward_ref_op_assign!(impl DivAssign, div_assign for SignedDecimal, SignedDecimal);
+
+impl Div<Int128> for SignedDecimal {
+    type Output = Self;
+
+    fn div(self, rhs: Int128) -> Self::Output {
+        SignedDecimal(self.0 / rhs)
+    }
+}
+
+impl DivAssign<Int128> for SignedDecimal {
+    fn div_assign(&mut self, rhs: Int128) {
+        self.0 /= rhs;
+    }
+}
+
+impl Rem for SignedDecimal {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This operation will panic if `rhs` is zero
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        match self.checked_rem(rhs) {
+            Ok(remainder) => remainder,
+            Err(_) => panic!("attempt to calculate the remainder with a divisor of zero"),
+        }
+    }
+}
+forward_ref_binop!(impl Rem, rem for SignedDecimal, SignedDecimal);
+
+impl RemAssign<SignedDecimal> for SignedDecimal {
+    fn rem_assign(&mut self, rhs: SignedDecimal) {
+        *self = *self % rhs;
+    }
+}
+forward_ref_op_assign!(impl RemAssign, rem_assign for SignedDecimal, SignedDecimal);
+
+impl Neg for SignedDecimal {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl<A> std::iter::Sum<A> for SignedDecimal
+where
+    Self: Add<A, Output = Self>,
+{
+    fn sum<I: Iterator<Item = A>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+/// Serializes as a decimal string
+impl Serialize for SignedDecimal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Deserializes as a decimal string
+impl<'de> Deserialize<'de> for SignedDecimal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(SignedDecimalVisitor)
+    }
+}
+
+struct SignedDecimalVisitor;
+
+impl<'de> de::Visitor<'de> for SignedDecimalVisitor {
+    type Value = SignedDecimal;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("string-encoded signed decimal")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match SignedDecimal::from_str(v) {
+            Ok(d) => Ok(d),
+            Err(e) => Err(E::custom(format!("Error parsing decimal '{}': {}", v, e))),
+        }
+    }
+}
+
+impl PartialEq<&SignedDecimal> for SignedDecimal {
+    fn eq(&self, rhs: &&SignedDecimal) -> bool {
+        self == *rhs
+    }
+}
+
+impl PartialEq<SignedDecimal> for &SignedDecimal {
+    fn eq(&self, rhs: &SignedDecimal) -> bool {
+        *self == rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_slice, to_vec};
+
+    fn dec(input: &str) -> SignedDecimal {
+        SignedDecimal::from_str(input).unwrap()
+    }
+
+    #[test]
+    fn signed_decimal_new() {
+        let expected = Int128::from(-300i128);
+        assert_eq!(SignedDecimal::new(expected).0, expected);
+    }
+
+    #[test]
+    fn signed_decimal_raw() {
+        let value = -300i128;
+        assert_eq!(SignedDecimal::raw(value).0, Int128::from(value));
+    }
+
+    #[test]
+    fn signed_decimal_one_zero_negative_one() {
+        assert_eq!(SignedDecimal::one().0, SignedDecimal::DECIMAL_FRACTIONAL);
+        assert_eq!(
+            SignedDecimal::negative_one().0,
+            -SignedDecimal::DECIMAL_FRACTIONAL
+        );
+        assert!(SignedDecimal::zero().0.is_zero());
+        assert_eq!(-SignedDecimal::one(), SignedDecimal::negative_one());
+    }
+
+    #[test]
+    fn signed_decimal_percent_and_permille() {
+        assert_eq!(SignedDecimal::percent(50), dec("0.5"));
+        assert_eq!(SignedDecimal::percent(-50), dec("-0.5"));
+        assert_eq!(SignedDecimal::permille(125), dec("0.125"));
+        assert_eq!(SignedDecimal::permille(-125), dec("-0.125"));
+    }
+
+    #[test]
+    fn signed_decimal_from_atomics_works() {
+        let one = SignedDecimal::one();
+        let neg_two = SignedDecimal::negative_one() + SignedDecimal::negative_one();
+
+        assert_eq!(SignedDecimal::from_atomics(1i128, 0).unwrap(), one);
+        assert_eq!(SignedDecimal::from_atomics(10i128, 1).unwrap(), one);
+        assert_eq!(SignedDecimal::from_atomics(1000i128, 3).unwrap(), one);
+        assert_eq!(
+            SignedDecimal::from_atomics(1000000000000000000i128, 18).unwrap(),
+            one
+        );
+        assert_eq!(
+            SignedDecimal::from_atomics(100000000000000000000i128, 20).unwrap(),
+            one
+        );
+
+        assert_eq!(SignedDecimal::from_atomics(-2i128, 0).unwrap(), neg_two);
+        assert_eq!(SignedDecimal::from_atomics(-200i128, 2).unwrap(), neg_two);
+        assert_eq!(
+            SignedDecimal::from_atomics(-2000000000000000000i128, 18).unwrap(),
+            neg_two
+        );
+        assert_eq!(
+            SignedDecimal::from_atomics(-200000000000000000000i128, 20).unwrap(),
+            neg_two
+        );
+
+        // Cuts decimal digits (20 provided but only 18 can be stored), rounding towards zero
+        assert_eq!(
+            SignedDecimal::from_atomics(4321i128, 20).unwrap(),
+            dec("0.000000000000000043")
+        );
+        assert_eq!(
+            SignedDecimal::from_atomics(-6789i128, 20).unwrap(),
+            dec("-0.000000000000000067")
+        );
+        assert_eq!(
+            SignedDecimal::from_atomics(i128::MAX, 38).unwrap(),
+            dec("1.701411834604692317")
+        );
+        assert_eq!(
+            SignedDecimal::from_atomics(i128::MIN, 39).unwrap(),
+            dec("-0.170141183460469231")
+        );
+        assert_eq!(
+            SignedDecimal::from_atomics(i128::MIN, 45).unwrap(),
+            dec("-0.000000170141183460")
+        );
+        assert_eq!(
+            SignedDecimal::from_atomics(i128::MAX, 56).unwrap(),
+            dec("0.000000000000000001")
+        );
+        assert_eq!(
+            SignedDecimal::from_atomics(i128::MAX, 57).unwrap(),
+            SignedDecimal::zero()
+        );
+        assert_eq!(
+            SignedDecimal::from_atomics(i128::MIN, u32::MAX).unwrap(),
+            SignedDecimal::zero()
+        );
+
+        // Can be used with max value
+        let max = SignedDecimal::MAX;
+        assert_eq!(
+            SignedDecimal::from_atomics(max.atomics(), max.decimal_places()).unwrap(),
+            max
+        );
+        let min = SignedDecimal::MIN;
+        assert_eq!(
+            SignedDecimal::from_atomics(min.atomics(), min.decimal_places()).unwrap(),
+            min
+        );
+
+        // Overflow is only possible with digits < 18
+        let result = SignedDecimal::from_atomics(i128::MAX, 17);
+        assert_eq!(result.unwrap_err(), SignedDecimalRangeExceeded);
+        let result = SignedDecimal::from_atomics(i128::MIN, 17);
+        assert_eq!(result.unwrap_err(), SignedDecimalRangeExceeded);
+    }
+
+    #[test]
+    fn signed_decimal_from_ratio_works() {
+        assert_eq!(
+            SignedDecimal::from_ratio(1i128, 1i128),
+            SignedDecimal::one()
+        );
+        assert_eq!(SignedDecimal::from_ratio(-53i128, 53i128), dec("-1"));
+        assert_eq!(SignedDecimal::from_ratio(125i128, -125i128), dec("-1"));
+        assert_eq!(SignedDecimal::from_ratio(-3i128, -2i128), dec("1.5"));
+        assert_eq!(
+            SignedDecimal::from_ratio(0i128, -5i128),
+            SignedDecimal::zero()
+        );
+
+        // rounding is towards zero
+        assert_eq!(
+            SignedDecimal::from_ratio(1i128, 3i128),
+            dec("0.333333333333333333")
+        );
+        assert_eq!(
+            SignedDecimal::from_ratio(-2i128, 3i128),
+            dec("-0.666666666666666666")
+        );
+
+        // large inputs
+        assert_eq!(
+            SignedDecimal::from_ratio(i128::MIN, i128::MIN),
+            SignedDecimal::one()
+        );
+        assert_eq!(
+            SignedDecimal::from_ratio(i128::MAX, i128::MIN),
+            dec("-0.999999999999999999")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Denominator must not be zero")]
+    fn signed_decimal_from_ratio_panics_for_zero_denominator() {
+        SignedDecimal::from_ratio(1i128, 0i128);
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiplication overflow")]
+    fn signed_decimal_from_ratio_panics_for_mul_overflow() {
+        SignedDecimal::from_ratio(i128::MIN, 1i128);
+    }
+
+    #[test]
+    fn signed_decimal_checked_from_ratio_does_not_panic() {
+        assert_eq!(
+            SignedDecimal::checked_from_ratio(1i128, 0i128),
+            Err(CheckedFromRatioError::DivideByZero)
+        );
+        assert_eq!(
+            SignedDecimal::checked_from_ratio(i128::MAX, 1i128),
+            Err(CheckedFromRatioError::Overflow)
+        );
+    }
+
+    #[test]
+    fn signed_decimal_implements_fraction() {
+        let fraction = SignedDecimal::from_str("-1234.567").unwrap();
+        assert_eq!(
+            fraction.numerator(),
+            Int128::from(-1_234_567_000_000_000_000_000i128)
+        );
+        assert_eq!(
+            fraction.denominator(),
+            Int128::from(1_000_000_000_000_000_000i128)
+        );
+    }
+
+    #[test]
+    fn signed_decimal_inv_works() {
+        assert_eq!(SignedDecimal::zero().inv(), None);
+        assert_eq!(SignedDecimal::one().inv(), Some(SignedDecimal::one()));
+        assert_eq!(dec("-2").inv(), Some(dec("-0.5")));
+        assert_eq!(dec("-0.25").inv(), Some(dec("-4")));
+        assert_eq!(dec("3").inv(), Some(dec("0.333333333333333333")));
+        assert_eq!(
+            dec("-0.000000000000000001").inv(),
+            Some(dec("-1000000000000000000"))
+        );
+    }
+
+    #[test]
+    fn signed_decimal_from_str_works() {
+        assert_eq!(dec("0"), SignedDecimal::zero());
+        assert_eq!(dec("-0"), SignedDecimal::zero());
+        assert_eq!(dec("1"), SignedDecimal::one());
+        assert_eq!(dec("-1"), SignedDecimal::negative_one());
+        assert_eq!(
+            dec("-000012"),
+            SignedDecimal::raw(-12_000_000_000_000_000_000)
+        );
+        assert_eq!(dec("1.5"), SignedDecimal::permille(1500));
+        assert_eq!(dec("-0.75"), SignedDecimal::percent(-75));
+        assert_eq!(dec("-1.123000000"), SignedDecimal::permille(-1123));
+        assert_eq!(dec("-0.000000000000000001"), SignedDecimal::raw(-1));
+
+        // Can handle 18 fractional digits
+        assert_eq!(
+            dec("-7.123456789012345678"),
+            SignedDecimal::raw(-7123456789012345678)
+        );
+
+        // Works for the extreme values
+        assert_eq!(
+            dec("170141183460469231731.687303715884105727"),
+            SignedDecimal::MAX
+        );
+        assert_eq!(
+            dec("-170141183460469231731.687303715884105728"),
+            SignedDecimal::MIN
+        );
+    }
+
+    #[test]
+    fn signed_decimal_from_str_errors_for_broken_input() {
+        for input in ["", "-", "1-", "--1", " 1", "-.5", ".5"] {
+            match SignedDecimal::from_str(input).unwrap_err() {
+                StdError::GenericErr { msg, .. } => assert_eq!(msg, "Error parsing whole"),
+                e => panic!("Unexpected error: {:?}", e),
+            }
+        }
+
+        for input in ["1.", "-1.", "1.-5", "-1.a", "1. 5"] {
+            match SignedDecimal::from_str(input).unwrap_err() {
+                StdError::GenericErr { msg, .. } => assert_eq!(msg, "Error parsing fractional"),
+                e => panic!("Unexpected error: {:?}", e),
+            }
+        }
+
+        match SignedDecimal::from_str("-7.1234567890123456789").unwrap_err() {
+            StdError::GenericErr { msg, .. } => {
+                assert_eq!(msg, "Cannot parse more than 18 fractional digits")
+            }
+            e => panic!("Unexpected error: {:?}", e),
+        }
+
+        match SignedDecimal::from_str("-1.2.3").unwrap_err() {
+            StdError::GenericErr { msg, .. } => assert_eq!(msg, "Unexpected number of dots"),
+            e => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn signed_decimal_from_str_errors_for_out_of_range_values() {
+        for input in [
+            "170141183460469231732",
+            "-170141183460469231732",
+            "170141183460469231731.687303715884105728",
+            "-170141183460469231731.687303715884105729",
+        ] {
+            match SignedDecimal::from_str(input).unwrap_err() {
+                StdError::GenericErr { msg, .. } => assert_eq!(msg, "Value too big", "{}", input),
+                e => panic!("Unexpected error: {:?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn signed_decimal_atomics_and_decimal_places_work() {
+        let value = dec("-12.345");
+        assert_eq!(value.atomics(), Int128::new(-12345000000000000000));
+        assert_eq!(value.decimal_places(), 18);
+
+        assert_eq!(SignedDecimal::MIN.atomics(), Int128::MIN);
+        assert_eq!(SignedDecimal::MAX.decimal_places(), 18);
+    }
+
+    #[test]
+    fn signed_decimal_is_zero_and_is_negative_work() {
+        assert!(SignedDecimal::zero().is_zero());
+        assert!(dec("-0").is_zero());
+        assert!(!dec("-0.000000000000000001").is_zero());
+
+        assert!(dec("-0.000000000000000001").is_negative());
+        assert!(SignedDecimal::MIN.is_negative());
+        assert!(!SignedDecimal::zero().is_negative());
+        assert!(!dec("0.000000000000000001").is_negative());
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn signed_decimal_add_sub_work() {
+        assert_eq!(dec("1.5") + dec("-2.25"), dec("-0.75"));
+        assert_eq!(dec("-1.5") + dec("-2.25"), dec("-3.75"));
+        assert_eq!(dec("1.5") - dec("2.25"), dec("-0.75"));
+        assert_eq!(dec("-1.5") - dec("-2.25"), dec("0.75"));
+
+        let a = dec("-1.5");
+        let b = dec("0.5");
+        assert_eq!(a + &b, dec("-1"));
+        assert_eq!(&a - b, dec("-2"));
+        assert_eq!(&a - &b, dec("-2"));
+
+        let mut a = dec("-1.5");
+        a += dec("0.5");
+        assert_eq!(a, dec("-1"));
+        a -= &dec("1.25");
+        assert_eq!(a, dec("-2.25"));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn signed_decimal_add_overflow_panics() {
+        let _ = SignedDecimal::MAX + dec("0.000000000000000001");
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn signed_decimal_sub_overflow_panics() {
+        let _ = SignedDecimal::MIN - dec("0.000000000000000001");
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn signed_decimal_implements_mul() {
+        assert_eq!(dec("2") * dec("-3"), dec("-6"));
+        assert_eq!(dec("-0.5") * dec("-0.5"), dec("0.25"));
+        assert_eq!(dec("-1.5") * dec("0"), SignedDecimal::zero());
+        assert_eq!(
+            dec("-0.000000001") * dec("0.000000001"),
+            dec("-0.000000000000000001")
+        );
+
+        // rounding is towards zero
+        assert_eq!(
+            dec("0.000000000000000001") * dec("0.5"),
+            SignedDecimal::zero()
+        );
+        assert_eq!(
+            dec("-0.000000000000000001") * dec("0.5"),
+            SignedDecimal::zero()
+        );
+        assert_eq!(
+            dec("-0.000000000000000003") * dec("0.5"),
+            dec("-0.000000000000000001")
+        );
+
+        // large values
+        assert_eq!(
+            SignedDecimal::MAX * SignedDecimal::negative_one(),
+            -SignedDecimal::MAX
+        );
+        assert_eq!(
+            SignedDecimal::MIN * SignedDecimal::one(),
+            SignedDecimal::MIN
+        );
+
+        let a = dec("-1.5");
+        let b = dec("2");
+        assert_eq!(a * &b, dec("-3"));
+        assert_eq!(&a * b, dec("-3"));
+        assert_eq!(&a * &b, dec("-3"));
+
+        let mut a = dec("-1.5");
+        a *= dec("-2");
+        assert_eq!(a, dec("3"));
+        a *= &dec("0.5");
+        assert_eq!(a, dec("1.5"));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn signed_decimal_mul_overflow_panics() {
+        let _ = SignedDecimal::MIN * SignedDecimal::negative_one();
+    }
+
+    #[test]
+    fn signed_decimal_checked_mul() {
+        assert_eq!(dec("-2").checked_mul(dec("3")).unwrap(), dec("-6"));
+        assert_eq!(dec("-2").checked_mul(dec("-3.5")).unwrap(), dec("7"));
+
+        let err = SignedDecimal::MIN
+            .checked_mul(SignedDecimal::negative_one())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OverflowError::new(
+                OverflowOperation::Mul,
+                SignedDecimal::MIN,
+                SignedDecimal::negative_one()
+            )
+        );
+        assert!(SignedDecimal::MAX.checked_mul(dec("-2")).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn signed_decimal_implements_div() {
+        assert_eq!(dec("6") / dec("-3"), dec("-2"));
+        assert_eq!(dec("-1") / dec("-4"), dec("0.25"));
+        assert_eq!(dec("0") / dec("-4"), SignedDecimal::zero());
+
+        // rounding is towards zero
+        assert_eq!(dec("-1") / dec("3"), dec("-0.333333333333333333"));
+        assert_eq!(dec("2") / dec("-3"), dec("-0.666666666666666666"));
+
+        // large values
+        assert_eq!(
+            SignedDecimal::MIN / SignedDecimal::MIN,
+            SignedDecimal::one()
+        );
+        assert_eq!(
+            SignedDecimal::MIN / dec("10000000000000000000"),
+            dec("-17.014118346046923173")
+        );
+
+        let a = dec("-1.5");
+        let b = dec("2");
+        assert_eq!(a / &b, dec("-0.75"));
+        assert_eq!(&a / b, dec("-0.75"));
+        assert_eq!(&a / &b, dec("-0.75"));
+
+        let mut a = dec("-1.5");
+        a /= dec("-3");
+        assert_eq!(a, dec("0.5"));
+        a /= &dec("0.25");
+        assert_eq!(a, dec("2"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Division failed - multiplication overflow")]
+    fn signed_decimal_div_overflow_panics() {
+        let _ = SignedDecimal::MIN / dec("0.1");
+    }
+
+    #[test]
+    #[should_panic(expected = "Division failed - denominator must not be zero")]
+    fn signed_decimal_div_by_zero_panics() {
+        let _ = dec("-1") / SignedDecimal::zero();
+    }
+
+    #[test]
+    fn signed_decimal_int128_division() {
+        assert_eq!(dec("-4.5") / Int128::new(3), dec("-1.5"));
+        assert_eq!(dec("-4.5") / Int128::new(-3), dec("1.5"));
+
+        let mut value = dec("7.5");
+        value /= Int128::new(-5);
+        assert_eq!(value, dec("-1.5"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_decimal_int128_divide_by_zero() {
+        let _ = dec("-1") / Int128::zero();
+    }
+
+    #[test]
+    fn signed_decimal_checked_pow() {
+        for exp in 0..10 {
+            assert_eq!(
+                SignedDecimal::one().checked_pow(exp).unwrap(),
+                SignedDecimal::one()
+            );
+        }
+        assert_eq!(SignedDecimal::negative_one().pow(2), SignedDecimal::one());
+        assert_eq!(
+            SignedDecimal::negative_one().pow(3),
+            SignedDecimal::negative_one()
+        );
+        assert_eq!(SignedDecimal::zero().pow(0), SignedDecimal::one());
+        assert_eq!(dec("-2").checked_pow(3).unwrap(), dec("-8"));
+        assert_eq!(dec("-0.5").checked_pow(2).unwrap(), dec("0.25"));
+        assert_eq!(dec("-1.1").checked_pow(3).unwrap(), dec("-1.331"));
+
+        assert_eq!(
+            dec("-2").checked_pow(100).unwrap_err(),
+            OverflowError::new(OverflowOperation::Pow, dec("-2"), 100)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiplication overflow")]
+    fn signed_decimal_pow_overflow_panics() {
+        let _ = SignedDecimal::MIN.pow(2);
+    }
+
+    #[test]
+    fn signed_decimal_to_string() {
+        assert_eq!(SignedDecimal::zero().to_string(), "0");
+        assert_eq!(SignedDecimal::one().to_string(), "1");
+        assert_eq!(SignedDecimal::negative_one().to_string(), "-1");
+        assert_eq!(SignedDecimal::percent(-50).to_string(), "-0.5");
+        assert_eq!(SignedDecimal::raw(-1).to_string(), "-0.000000000000000001");
+        assert_eq!(
+            SignedDecimal::raw(-1_230_000_000_000_000_000).to_string(),
+            "-1.23"
+        );
+        assert_eq!(
+            SignedDecimal::MAX.to_string(),
+            "170141183460469231731.687303715884105727"
+        );
+        assert_eq!(
+            SignedDecimal::MIN.to_string(),
+            "-170141183460469231731.687303715884105728"
+        );
+    }
+
+    #[test]
+    fn signed_decimal_iter_sum() {
+        let items = vec![dec("2"), dec("-3.25"), dec("0.5")];
+        assert_eq!(items.iter().sum::<SignedDecimal>(), dec("-0.75"));
+        assert_eq!(items.into_iter().sum::<SignedDecimal>(), dec("-0.75"));
+
+        let empty: Vec<SignedDecimal> = vec![];
+        assert_eq!(SignedDecimal::zero(), empty.iter().sum::<SignedDecimal>());
+    }
+
+    #[test]
+    fn signed_decimal_serialize() {
+        assert_eq!(to_vec(&SignedDecimal::zero()).unwrap(), br#""0""#);
+        assert_eq!(to_vec(&SignedDecimal::one()).unwrap(), br#""1""#);
+        assert_eq!(to_vec(&SignedDecimal::percent(-87)).unwrap(), br#""-0.87""#);
+        assert_eq!(
+            to_vec(&SignedDecimal::MIN).unwrap(),
+            br#""-170141183460469231731.687303715884105728""#
+        );
+    }
+
+    #[test]
+    fn signed_decimal_deserialize() {
+        assert_eq!(
+            from_slice::<SignedDecimal>(br#""0""#).unwrap(),
+            SignedDecimal::zero()
+        );
+        assert_eq!(
+            from_slice::<SignedDecimal>(br#""-0""#).unwrap(),
+            SignedDecimal::zero()
+        );
+        assert_eq!(
+            from_slice::<SignedDecimal>(br#""-1""#).unwrap(),
+            SignedDecimal::negative_one()
+        );
+        assert_eq!(
+            from_slice::<SignedDecimal>(br#""-0.87""#).unwrap(),
+            SignedDecimal::percent(-87)
+        );
+
+        let err = from_slice::<SignedDecimal>(br#""--1""#).unwrap_err();
+        assert!(err.to_string().contains("Error parsing decimal '--1'"));
+    }
+
+    #[test]
+    fn signed_decimal_abs_and_abs_diff_work() {
+        assert_eq!(dec("-1.5").abs(), dec("1.5"));
+        assert_eq!(dec("1.5").abs(), dec("1.5"));
+        assert_eq!(SignedDecimal::zero().abs(), SignedDecimal::zero());
+
+        assert_eq!(dec("-1.5").abs_diff(dec("2")), Decimal::percent(350));
+        assert_eq!(dec("2").abs_diff(dec("-1.5")), Decimal::percent(350));
+        assert_eq!(dec("-2").abs_diff(dec("-1.5")), Decimal::percent(50));
+        assert_eq!(
+            SignedDecimal::MAX.abs_diff(SignedDecimal::MIN),
+            Decimal::MAX
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_decimal_abs_panics_for_min() {
+        let _ = SignedDecimal::MIN.abs();
+    }
+
+    #[test]
+    fn signed_decimal_neg_works() {
+        assert_eq!(-dec("1.5"), dec("-1.5"));
+        assert_eq!(-dec("-1.5"), dec("1.5"));
+        assert_eq!(-SignedDecimal::zero(), SignedDecimal::zero());
+        assert_eq!(
+            -SignedDecimal::MAX,
+            SignedDecimal::MIN + dec("0.000000000000000001")
+        );
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn signed_decimal_rem_works() {
+        // The sign follows the dividend
+        assert_eq!(dec("7.5") % dec("2"), dec("1.5"));
+        assert_eq!(dec("-7.5") % dec("2"), dec("-1.5"));
+        assert_eq!(dec("7.5") % dec("-2"), dec("1.5"));
+        assert_eq!(dec("-7.5") % &dec("-2"), dec("-1.5"));
+        assert_eq!(
+            SignedDecimal::MIN % SignedDecimal::raw(-1),
+            SignedDecimal::zero()
+        );
+
+        let mut value = dec("-7.5");
+        value %= dec("2");
+        assert_eq!(value, dec("-1.5"));
+    }
+
+    #[test]
+    #[should_panic(expected = "divisor of zero")]
+    fn signed_decimal_rem_panics_for_zero() {
+        let _ = dec("-1") % SignedDecimal::zero();
+    }
+
+    #[test]
+    fn signed_decimal_checked_methods() {
+        assert_eq!(dec("-1.5").checked_add(dec("1")).unwrap(), dec("-0.5"));
+        assert!(matches!(
+            SignedDecimal::MIN.checked_add(dec("-1")),
+            Err(OverflowError { .. })
+        ));
+
+        assert_eq!(dec("-1.5").checked_sub(dec("-1")).unwrap(), dec("-0.5"));
+        assert!(matches!(
+            SignedDecimal::MAX.checked_sub(dec("-1")),
+            Err(OverflowError { .. })
+        ));
+
+        assert_eq!(dec("-3").checked_div(dec("2")).unwrap(), dec("-1.5"));
+        assert_eq!(
+            dec("-3").checked_div(SignedDecimal::zero()),
+            Err(CheckedFromRatioError::DivideByZero)
+        );
+        assert_eq!(
+            SignedDecimal::MIN.checked_div(dec("0.1")),
+            Err(CheckedFromRatioError::Overflow)
+        );
+
+        assert_eq!(dec("-3").checked_rem(dec("2")).unwrap(), dec("-1"));
+        assert!(matches!(
+            dec("-3").checked_rem(SignedDecimal::zero()),
+            Err(DivideByZeroError { .. })
+        ));
+    }
+
+    #[test]
+    fn signed_decimal_saturating_works() {
+        assert_eq!(dec("-1").saturating_add(dec("2")), dec("1"));
+        assert_eq!(
+            SignedDecimal::MAX.saturating_add(dec("1")),
+            SignedDecimal::MAX
+        );
+        assert_eq!(
+            SignedDecimal::MIN.saturating_add(dec("-1")),
+            SignedDecimal::MIN
+        );
+
+        assert_eq!(dec("-1").saturating_sub(dec("2")), dec("-3"));
+        assert_eq!(
+            SignedDecimal::MIN.saturating_sub(dec("1")),
+            SignedDecimal::MIN
+        );
+        assert_eq!(
+            SignedDecimal::MAX.saturating_sub(dec("-1")),
+            SignedDecimal::MAX
+        );
+
+        assert_eq!(dec("-2").saturating_mul(dec("3")), dec("-6"));
+        assert_eq!(
+            SignedDecimal::MAX.saturating_mul(dec("2")),
+            SignedDecimal::MAX
+        );
+        assert_eq!(
+            SignedDecimal::MAX.saturating_mul(dec("-2")),
+            SignedDecimal::MIN
+        );
+        assert_eq!(
+            SignedDecimal::MIN.saturating_mul(dec("2")),
+            SignedDecimal::MIN
+        );
+        assert_eq!(
+            SignedDecimal::MIN.saturating_mul(dec("-2")),
+            SignedDecimal::MAX
+        );
+
+        assert_eq!(dec("-3").saturating_pow(3), dec("-27"));
+        assert_eq!(SignedDecimal::MIN.saturating_pow(2), SignedDecimal::MAX);
+        assert_eq!(SignedDecimal::MIN.saturating_pow(3), SignedDecimal::MIN);
+        assert_eq!(SignedDecimal::MAX.saturating_pow(3), SignedDecimal::MAX);
+    }
+
+    #[test]
+    fn signed_decimal_rounding() {
+        assert_eq!(dec("1.5").trunc(), dec("1"));
+        assert_eq!(dec("1.5").floor(), dec("1"));
+        assert_eq!(dec("1.5").ceil(), dec("2"));
+
+        assert_eq!(dec("-1.5").trunc(), dec("-1"));
+        assert_eq!(dec("-1.5").floor(), dec("-2"));
+        assert_eq!(dec("-1.5").ceil(), dec("-1"));
+
+        assert_eq!(dec("-2").trunc(), dec("-2"));
+        assert_eq!(dec("-2").floor(), dec("-2"));
+        assert_eq!(dec("-2").ceil(), dec("-2"));
+
+        assert_eq!(dec("-0.000000000000000001").trunc(), SignedDecimal::zero());
+        assert_eq!(dec("-0.000000000000000001").floor(), dec("-1"));
+        assert_eq!(dec("-0.000000000000000001").ceil(), SignedDecimal::zero());
+
+        assert_eq!(SignedDecimal::MIN.ceil(), dec("-170141183460469231731"));
+        assert_eq!(SignedDecimal::MAX.floor(), dec("170141183460469231731"));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to ceil with overflow")]
+    fn signed_decimal_ceil_panics() {
+        let _ = SignedDecimal::MAX.ceil();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to floor with overflow")]
+    fn signed_decimal_floor_panics() {
+        let _ = SignedDecimal::MIN.floor();
+    }
+
+    #[test]
+    fn signed_decimal_checked_ceil_and_floor() {
+        assert_eq!(dec("-0.5").checked_ceil(), Ok(SignedDecimal::zero()));
+        assert_eq!(SignedDecimal::MAX.checked_ceil(), Err(RoundUpOverflowError));
+
+        assert_eq!(dec("0.5").checked_floor(), Ok(SignedDecimal::zero()));
+        assert_eq!(
+            SignedDecimal::MIN.checked_floor(),
+            Err(RoundDownOverflowError)
+        );
+    }
+
+    #[test]
+    fn signed_decimal_converts_from_and_to_decimal() {
+        assert_eq!(
+            SignedDecimal::try_from(Decimal::percent(150)).unwrap(),
+            dec("1.5")
+        );
+        assert_eq!(
+            SignedDecimal::try_from(Decimal::new(Uint128::new(i128::MAX as u128))).unwrap(),
+            SignedDecimal::MAX
+        );
+        assert_eq!(
+            SignedDecimal::try_from(Decimal::MAX),
+            Err(SignedDecimalRangeExceeded)
+        );
+
+        assert_eq!(
+            Decimal::try_from(dec("1.5")).unwrap(),
+            Decimal::percent(150)
+        );
+        assert_eq!(
+            Decimal::try_from(SignedDecimal::MAX).unwrap(),
+            Decimal::new(Uint128::new(i128::MAX as u128))
+        );
+        assert_eq!(Decimal::try_from(dec("-0")).unwrap(), Decimal::zero());
+        assert_eq!(
+            Decimal::try_from(dec("-0.000000000000000001")),
+            Err(DecimalRangeExceeded)
+        );
+    }
+
+    #[test]
+    fn signed_decimal_partial_eq() {
+        let test_cases = [
+            ("-1", "-1", true),
+            ("-0.5", "-0.5", true),
+            ("-0.5", "0.5", false),
+        ]
+        .into_iter()
+        .map(|(lhs, rhs, expected)| (dec(lhs), dec(rhs), expected));
+
+        #[allow(clippy::op_ref)]
+        for (lhs, rhs, expected) in test_cases {
+            assert_eq!(lhs == rhs, expected);
+            assert_eq!(&lhs == rhs, expected);
+            assert_eq!(lhs == &rhs, expected);
+            assert_eq!(&lhs == &rhs, expected);
+        }
+    }
+
+    #[test]
+    fn signed_decimal_ordering_works() {
+        assert!(SignedDecimal::MIN < dec("-1"));
+        assert!(dec("-1") < dec("-0.5"));
+        assert!(dec("-0.5") < SignedDecimal::zero());
+        assert!(SignedDecimal::zero() < dec("0.5"));
+        assert!(dec("0.5") < SignedDecimal::MAX);
+    }
+}
@@ -0,0 +1,245 @@
+//! Deterministic, integer-only implementations of `ln`, `log2`, `exp` and `pow`
+//! for the decimal types.
+//!
+//! All inputs and outputs are atomics of decimals with 18 decimal places. Internally
+//! the calculations use [`Int512`] with 60 decimal places, such that the error of the
+//! intermediate results is many orders of magnitude below the precision of the inputs.
+
+use super::{Int128, Int256, Int512, Uint256};
+
+/// 1*10**60, the scale of the internal fixed-point representation
+const WIDE_FRACTIONAL: Int512 = Int512::from_be_bytes([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 159, 79, 39, 38, 23, 154, 34, 69, 1, 215, 98, 66, 44, 148, 101, 144, 217,
+    16, 0, 0, 0, 0, 0, 0, 0,
+]);
+
+/// 1*10**42, the factor between the internal representation and 18 decimal places
+const WIDE_FACTOR: Int512 = Int512::from_be_bytes([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 122, 188, 98, 112, 80, 48, 90, 223, 20, 163, 217,
+    228, 0, 0, 0, 0, 0,
+]);
+
+/// ln(2) with 60 decimal places (truncated)
+const LN_2: Int512 = Int512::from_be_bytes([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 110, 108, 186, 59, 25, 188, 1, 226, 169, 224, 236, 125, 222, 133, 253,
+    255, 41, 165, 214, 126, 185, 22, 138, 72, 232,
+]);
+
+/// 1*10**18, the atomics of the decimal 1.0
+const ONE_ATOMICS: Int512 = Int512::from_i128(1_000_000_000_000_000_000);
+
+/// Arguments of `exp` above this bound (in whole units) overflow every decimal type.
+/// e^200 is about 7*10^86, which is larger than the maximum of `Decimal256`.
+const EXP_BOUND: i128 = 200;
+
+/// Returns the natural logarithm of `x` in atomics, rounded to the nearest value.
+/// `None` is returned for `x == 0`.
+pub(crate) fn ln(x: Uint256) -> Option<Int256> {
+    let (exponent, ln_mantissa) = ln_parts(x)?;
+    let ln = Int512::from(exponent) * LN_2 + ln_mantissa;
+    Some(to_atomics(ln).try_into().unwrap()) // |ln(x)| < 200 always fits
+}
+
+/// Returns the base 2 logarithm of `x` in atomics, rounded to the nearest value.
+/// `None` is returned for `x == 0`.
+pub(crate) fn log2(x: Uint256) -> Option<Int256> {
+    let (exponent, ln_mantissa) = ln_parts(x)?;
+    let log2 = Int512::from(exponent) * WIDE_FRACTIONAL + ln_mantissa * WIDE_FRACTIONAL / LN_2;
+    Some(to_atomics(log2).try_into().unwrap()) // |log2(x)| < 300 always fits
+}
+
+/// Returns e^x in atomics, rounded to the nearest value.
+/// `None` is returned if the result does not fit into a `Uint256`.
+pub(crate) fn exp(x: Uint256) -> Option<Uint256> {
+    let x = Int512::from(x);
+    if x > Int512::from(EXP_BOUND) * ONE_ATOMICS {
+        return None;
+    }
+    to_atomics(exp_wide(x * WIDE_FACTOR)).try_into().ok()
+}
+
+/// Returns base^exponent in atomics, rounded to the nearest value.
+/// `None` is returned if the result does not fit into a `Uint256`.
+pub(crate) fn pow(base: Uint256, exponent: Uint256) -> Option<Uint256> {
+    if exponent.is_zero() {
+        return Some(Uint256::from(1_000_000_000_000_000_000u128));
+    }
+    let (base_exponent, ln_mantissa) = match ln_parts(base) {
+        Some(parts) => parts,
+        None => return Some(Uint256::zero()), // 0^y = 0 for y > 0
+    };
+    let ln_base = Int512::from(base_exponent) * LN_2 + ln_mantissa;
+    // |ln_base| < 2^208 and exponent < 2^256, so the product cannot overflow
+    let product = ln_base * Int512::from(exponent) / ONE_ATOMICS;
+
+    let bound = Int512::from(EXP_BOUND) * WIDE_FRACTIONAL;
+    if product > bound {
+        None
+    } else if product < -bound {
+        // e^-200 is far below the smallest representable decimal
+        Some(Uint256::zero())
+    } else {
+        to_atomics(exp_wide(product)).try_into().ok()
+    }
+}
+
+/// Splits `ln(x)` into `k` and `ln(m)` such that `x = 2^k * m` with `1 <= m < 2`,
+/// where `ln(m)` uses the wide representation.
+///
+/// Every step truncates by less than 10^-60 and there are fewer than 40 series terms,
+/// so `ln(m)` is off by less than 10^-58. Together with `|k| < 400` multiples of the
+/// truncated `LN_2`, `ln(x)` and `log2(x)` are off by less than 10^-56 before rounding.
+fn ln_parts(x: Uint256) -> Option<(i32, Int512)> {
+    if x.is_zero() {
+        return None;
+    }
+
+    let one = WIDE_FRACTIONAL;
+    let two = WIDE_FRACTIONAL << 1;
+    let mut mantissa = Int512::from(x) * WIDE_FACTOR;
+    let mut exponent = 0i32;
+    // Shifting right step by step truncates exactly like a single division by 2^k
+    while mantissa >= two {
+        mantissa >>= 1;
+        exponent += 1;
+    }
+    while mantissa < one {
+        mantissa <<= 1;
+        exponent -= 1;
+    }
+
+    // ln(m) = 2 * atanh(z) = 2 * (z + z^3/3 + z^5/5 + ...) with z = (m - 1) / (m + 1).
+    // Since 0 <= z < 1/3, every term is at least 9 times smaller than the previous one.
+    let z = (mantissa - one) * one / (mantissa + one);
+    let z_squared = z * z / one;
+    let mut power = z;
+    let mut sum = z;
+    let mut divisor = Int512::from(1u8);
+    loop {
+        power = power * z_squared / one;
+        divisor += Int512::from(2u8);
+        let term = power / divisor;
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+    }
+
+    Some((exponent, sum << 1))
+}
+
+/// Calculates e^x in the wide representation for `|x| <= EXP_BOUND`.
+fn exp_wide(x: Int512) -> Int512 {
+    let one = WIDE_FRACTIONAL;
+
+    // Reduce to e^x = 2^k * e^r with 0 <= r < ln(2)
+    let mut k = x / LN_2;
+    if x < k * LN_2 {
+        k -= Int512::from(1u8);
+    }
+    let r = x - k * LN_2;
+
+    // Taylor series e^r = 1 + r + r^2/2! + ..., which converges quickly for r < ln(2)
+    let mut sum = one;
+    let mut term = one;
+    let mut n = Int512::from(1u8);
+    loop {
+        term = term * r / (n * one);
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+        n += Int512::from(1u8);
+    }
+
+    // k is within (-300, 300), see EXP_BOUND
+    let k = Int128::try_from(k).unwrap().i128();
+    if k >= 0 {
+        sum << k as u32
+    } else if k > -512 {
+        sum >> (-k) as u32
+    } else {
+        Int512::zero()
+    }
+}
+
+/// Converts from the wide representation to atomics, rounding half away from zero.
+///
+/// Rounding to nearest ensures that exact results like `9^1.5 = 27` are not
+/// reduced by one atomic unit due to the tiny error of the intermediate results.
+fn to_atomics(wide: Int512) -> Int512 {
+    let half = WIDE_FACTOR >> 1;
+    if wide.is_negative() {
+        (wide - half) / WIDE_FACTOR
+    } else {
+        (wide + half) / WIDE_FACTOR
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn int512(input: &str) -> Int512 {
+        Int512::from_str(input).unwrap()
+    }
+
+    #[test]
+    fn constants_are_correct() {
+        assert_eq!(WIDE_FRACTIONAL, Int512::from(10u8).pow(60));
+        assert_eq!(WIDE_FACTOR, Int512::from(10u8).pow(42));
+        assert_eq!(
+            LN_2,
+            int512("693147180559945309417232121458176568075500134360255254120680")
+        );
+    }
+
+    #[test]
+    fn ln_parts_works() {
+        assert_eq!(ln_parts(Uint256::zero()), None);
+        assert_eq!(
+            ln_parts(Uint256::from(1_000_000_000_000_000_000u128)),
+            Some((0, Int512::zero()))
+        );
+        assert_eq!(
+            ln_parts(Uint256::from(2_000_000_000_000_000_000u128)),
+            Some((1, Int512::zero()))
+        );
+        assert_eq!(
+            ln_parts(Uint256::from(250_000_000_000_000_000u128)),
+            Some((-2, Int512::zero()))
+        );
+
+        // 3 = 2^1 * 1.5 and ln(1.5) = 0.405465108108164381978013115464349136571990423462494197614014...
+        let (exponent, ln_mantissa) =
+            ln_parts(Uint256::from(3_000_000_000_000_000_000u128)).unwrap();
+        assert_eq!(exponent, 1);
+        let expected = int512("405465108108164381978013115464349136571990423462494197614014");
+        assert!((ln_mantissa - expected).abs() < Int512::from(1000u32));
+    }
+
+    #[test]
+    fn exp_wide_works() {
+        assert_eq!(exp_wide(Int512::zero()), WIDE_FRACTIONAL);
+
+        // e = 2.718281828459045235360287471352662497757247093699959574966...
+        let e = exp_wide(WIDE_FRACTIONAL);
+        let expected = int512("2718281828459045235360287471352662497757247093699959574966");
+        assert!((e / Int512::from(1000u32) - expected).abs() <= Int512::from(1u8));
+
+        // 1/e = 0.367879441171442321595523770161460867445811131031767834507...
+        let inv_e = exp_wide(-WIDE_FRACTIONAL);
+        let expected = int512("367879441171442321595523770161460867445811131031767834507");
+        assert!((inv_e / Int512::from(1000u32) - expected).abs() <= Int512::from(1u8));
+
+        // Results can be tiny without panicking
+        assert_eq!(
+            exp_wide(-Int512::from(EXP_BOUND) * WIDE_FRACTIONAL),
+            Int512::zero()
+        );
+    }
+}
@@ -10,6 +10,7 @@ use std::str::FromStr;
 use crate::errors::{
     CheckedMultiplyRatioError, DivideByZeroError, OverflowError, OverflowOperation, StdError,
 };
+use crate::math::fraction::impl_mul_fraction;
 use crate::{ConversionOverflowError, Uint256, Uint64};
 
 /// A thin wrapper around u128 that is using strings for JSON encoding/decoding,
@@ -62,6 +63,16 @@ impl Uint128 {
         self.0
     }
 
+    /// Creates a Uint128 from its big endian representation.
+    pub const fn from_be_bytes(data: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(data))
+    }
+
+    /// Creates a Uint128 from its little endian representation.
+    pub const fn from_le_bytes(data: [u8; 16]) -> Self {
+        Self(u128::from_le_bytes(data))
+    }
+
     /// Returns a copy of the number as big endian bytes.
     pub const fn to_be_bytes(self) -> [u8; 16] {
         self.0.to_be_bytes()
@@ -118,6 +129,49 @@ impl Uint128 {
         }
     }
 
+    /// Returns `self * numerator / denominator`, rounded up.
+    ///
+    /// In contrast to [`Uint128::multiply_ratio`], the result is always rounded up.
+    /// E.g. 5 * 99/100 = 5.
+    pub fn multiply_ratio_ceil<A: Into<u128>, B: Into<u128>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Uint128 {
+        match self.checked_multiply_ratio_ceil(numerator, denominator) {
+            Ok(value) => value,
+            Err(CheckedMultiplyRatioError::DivideByZero) => {
+                panic!("Denominator must not be zero")
+            }
+            Err(CheckedMultiplyRatioError::Overflow) => panic!("Multiplication overflow"),
+        }
+    }
+
+    /// Returns `self * numerator / denominator`, rounded up.
+    ///
+    /// In contrast to [`Uint128::checked_multiply_ratio`], the result is always rounded up.
+    /// E.g. 5 * 99/100 = 5.
+    pub fn checked_multiply_ratio_ceil<A: Into<u128>, B: Into<u128>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Result<Uint128, CheckedMultiplyRatioError> {
+        let numerator: u128 = numerator.into();
+        let denominator: u128 = denominator.into();
+        if denominator == 0 {
+            return Err(CheckedMultiplyRatioError::DivideByZero);
+        }
+        let product = self.full_mul(numerator);
+        let denominator = Uint256::from(denominator);
+        let mut ratio = product / denominator;
+        if !(product % denominator).is_zero() {
+            ratio += Uint256::from(1u8);
+        }
+        ratio
+            .try_into()
+            .map_err(|_| CheckedMultiplyRatioError::Overflow)
+    }
+
     /// Multiplies two u128 values without overflow, producing an
     /// [`Uint256`].
     ///
@@ -228,8 +282,30 @@ impl Uint128 {
             self.0 - other.0
         })
     }
+
+    /// Strict integer addition. Computes `self + rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Uint128::add`] but const.
+    pub const fn strict_add(self, rhs: Self) -> Self {
+        match self.0.checked_add(rhs.0) {
+            None => panic!("attempt to add with overflow"),
+            Some(sum) => Self(sum),
+        }
+    }
+
+    /// Strict integer subtraction. Computes `self - rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Uint128::sub`] but const.
+    pub const fn strict_sub(self, other: Self) -> Self {
+        match self.0.checked_sub(other.0) {
+            None => panic!("attempt to subtract with overflow"),
+            Some(diff) => Self(diff),
+        }
+    }
 }
 
+impl_mul_fraction!(Uint128);
+
 // `From<u{128,64,32,16,8}>` is implemented manually instead of
 // using `impl<T: Into<u128>> From<T> for Uint128` because
 // of the conflict with `TryFrom<&str>` as described here
@@ -538,7 +614,7 @@ impl PartialEq<Uint128> for &Uint128 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{from_slice, to_vec};
+    use crate::{from_slice, to_vec, Decimal, Decimal256};
 
     #[test]
     fn uint128_zero_works() {
@@ -871,6 +947,166 @@ mod tests {
         );
     }
 
+    #[test]
+    fn uint128_multiply_ratio_ceil_works() {
+        let base = Uint128(500u128);
+
+        // factor 1/1
+        assert_eq!(base.multiply_ratio_ceil(1u128, 1u128), base);
+        assert_eq!(base.multiply_ratio_ceil(3u128, 3u128), base);
+
+        // factor 3/2
+        assert_eq!(base.multiply_ratio_ceil(3u128, 2u128), Uint128(750u128));
+
+        // factor 3/7 rounds up, while multiply_ratio rounds down
+        assert_eq!(base.multiply_ratio(3u128, 7u128), Uint128(214u128));
+        assert_eq!(base.multiply_ratio_ceil(3u128, 7u128), Uint128(215u128));
+
+        // zero
+        assert_eq!(
+            Uint128(0u128).multiply_ratio_ceil(3u128, 7u128),
+            Uint128(0u128)
+        );
+        assert_eq!(base.multiply_ratio_ceil(0u128, 7u128), Uint128(0u128));
+    }
+
+    #[test]
+    fn uint128_multiply_ratio_ceil_does_not_overflow_when_result_fits() {
+        // Almost max value for Uint128.
+        let base = Uint128::MAX - Uint128(9u128);
+
+        assert_eq!(base.multiply_ratio_ceil(2u128, 2u128), base);
+        assert_eq!(
+            Uint128::MAX.multiply_ratio_ceil(u128::MAX, u128::MAX),
+            Uint128::MAX
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Denominator must not be zero")]
+    fn uint128_multiply_ratio_ceil_panics_for_zero_denominator() {
+        Uint128(500u128).multiply_ratio_ceil(1u128, 0u128);
+    }
+
+    #[test]
+    fn uint128_checked_multiply_ratio_ceil_does_not_panic() {
+        assert_eq!(
+            Uint128(500u128).checked_multiply_ratio_ceil(1u128, 0u128),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            Uint128(500u128).checked_multiply_ratio_ceil(u128::MAX, 1u128),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        // The rounding itself can overflow
+        assert_eq!(
+            Uint128::MAX.checked_multiply_ratio_ceil(u128::MAX, u128::MAX - u128::from(1u8)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    fn uint128_mul_floor_and_mul_ceil_work() {
+        let five = Uint128(5u128);
+        assert_eq!(five.mul_floor(Decimal::percent(99)), Uint128(4u128));
+        assert_eq!(five.mul_ceil(Decimal::percent(99)), five);
+        assert_eq!(five.mul_floor(Decimal256::percent(99)), Uint128(4u128));
+        assert_eq!(five.mul_ceil(Decimal256::percent(99)), five);
+
+        // exact results are not changed
+        assert_eq!(
+            Uint128(100u128).mul_floor(Decimal::percent(50)),
+            Uint128(50u128)
+        );
+        assert_eq!(
+            Uint128(100u128).mul_ceil(Decimal::percent(50)),
+            Uint128(50u128)
+        );
+        assert_eq!(five.mul_floor(Decimal::zero()), Uint128(0u128));
+        assert_eq!(five.mul_ceil(Decimal::zero()), Uint128(0u128));
+
+        // the intermediate product does not overflow
+        assert_eq!(Uint128::MAX.mul_floor(Decimal::one()), Uint128::MAX);
+        assert_eq!(Uint128::MAX.mul_ceil(Decimal256::one()), Uint128::MAX);
+        assert_eq!(
+            Uint128::MAX.mul_floor(Decimal::percent(50)),
+            Uint128::MAX / Uint128(2u128)
+        );
+        assert_eq!(
+            Uint128::MAX.mul_ceil(Decimal::percent(50)),
+            Uint128::MAX / Uint128(2u128) + Uint128(1u128)
+        );
+
+        assert_eq!(
+            Uint128::MAX.checked_mul_floor(Decimal::percent(101)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        assert_eq!(
+            Uint128::MAX.checked_mul_ceil(Decimal256::MAX),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiplication overflow")]
+    fn uint128_mul_floor_panics_on_overflow() {
+        Uint128::MAX.mul_floor(Decimal::percent(200));
+    }
+
+    #[test]
+    fn uint128_div_floor_and_div_ceil_work() {
+        let five = Uint128(5u128);
+        assert_eq!(five.div_floor(Decimal::percent(200)), Uint128(2u128));
+        assert_eq!(five.div_ceil(Decimal::percent(200)), Uint128(3u128));
+        assert_eq!(five.div_floor(Decimal256::percent(200)), Uint128(2u128));
+        assert_eq!(five.div_ceil(Decimal256::percent(200)), Uint128(3u128));
+
+        // exact results are not changed
+        assert_eq!(
+            Uint128(10u128).div_floor(Decimal::percent(50)),
+            Uint128(20u128)
+        );
+        assert_eq!(
+            Uint128(10u128).div_ceil(Decimal::percent(50)),
+            Uint128(20u128)
+        );
+
+        // the intermediate product does not overflow
+        assert_eq!(Uint128::MAX.div_floor(Decimal::one()), Uint128::MAX);
+        assert_eq!(Uint128::MAX.div_ceil(Decimal256::one()), Uint128::MAX);
+        assert_eq!(
+            Uint128::MAX.div_floor(Decimal::percent(200)),
+            Uint128::MAX / Uint128(2u128)
+        );
+        assert_eq!(
+            Uint128::MAX.div_ceil(Decimal::percent(200)),
+            Uint128::MAX / Uint128(2u128) + Uint128(1u128)
+        );
+
+        assert_eq!(
+            five.checked_div_floor(Decimal::zero()),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            five.checked_div_ceil(Decimal256::zero()),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            Uint128::MAX.checked_div_floor(Decimal::percent(99)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        assert_eq!(
+            Uint128::MAX.checked_div_ceil(Decimal256::percent(99)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Division failed - denominator must not be zero")]
+    fn uint128_div_floor_panics_for_zero() {
+        Uint128(5u128).div_floor(Decimal::zero());
+    }
+
     #[test]
     fn sum_works() {
         let nums = vec![Uint128(17), Uint128(123), Uint128(540), Uint128(82)];
@@ -1020,6 +1256,53 @@ mod tests {
         assert_eq!(b.abs_diff(a), expected);
     }
 
+    #[test]
+    fn uint128_strict_add_works() {
+        let a = Uint128::from(5u32);
+        let b = Uint128::from(3u32);
+        assert_eq!(a.strict_add(b), Uint128::from(8u32));
+        assert_eq!(b.strict_add(a), Uint128::from(8u32));
+        assert_eq!(Uint128::MAX.strict_add(Uint128::zero()), Uint128::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn uint128_strict_add_panics_on_overflow() {
+        let _ = Uint128::MAX.strict_add(Uint128::one());
+    }
+
+    #[test]
+    fn uint128_strict_sub_works() {
+        let a = Uint128::from(5u32);
+        let b = Uint128::from(3u32);
+        assert_eq!(a.strict_sub(b), Uint128::from(2u32));
+        assert_eq!(Uint128::MAX.strict_sub(Uint128::MAX), Uint128::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn uint128_strict_sub_panics_on_overflow() {
+        let _ = Uint128::zero().strict_sub(Uint128::one());
+    }
+
+    #[test]
+    fn uint128_from_be_bytes_and_from_le_bytes_work() {
+        let mut be = [0u8; 16];
+        be[15] = 42;
+        assert_eq!(Uint128::from_be_bytes(be), Uint128::from(42u32));
+        assert_eq!(
+            Uint128::from_be_bytes(Uint128::MAX.to_be_bytes()),
+            Uint128::MAX
+        );
+        let mut le = [0u8; 16];
+        le[0] = 42;
+        assert_eq!(Uint128::from_le_bytes(le), Uint128::from(42u32));
+        assert_eq!(
+            Uint128::from_le_bytes(Uint128::MAX.to_le_bytes()),
+            Uint128::MAX
+        );
+    }
+
     #[test]
     fn uint128_partial_eq() {
         let test_cases = [(1, 1, true), (42, 42, true), (42, 24, false), (0, 0, true)]
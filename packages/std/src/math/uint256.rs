@@ -12,6 +12,7 @@ use crate::errors::{
     CheckedMultiplyRatioError, ConversionOverflowError, DivideByZeroError, OverflowError,
     OverflowOperation, StdError,
 };
+use crate::math::fraction::impl_mul_fraction;
 use crate::{Uint128, Uint512, Uint64};
 
 /// This module is purely a workaround that lets us ignore lints for all the code
@@ -201,6 +202,49 @@ impl Uint256 {
         }
     }
 
+    /// Returns `self * numerator / denominator`, rounded up.
+    ///
+    /// In contrast to [`Uint256::multiply_ratio`], the result is always rounded up.
+    /// E.g. 5 * 99/100 = 5.
+    pub fn multiply_ratio_ceil<A: Into<Uint256>, B: Into<Uint256>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Uint256 {
+        match self.checked_multiply_ratio_ceil(numerator, denominator) {
+            Ok(value) => value,
+            Err(CheckedMultiplyRatioError::DivideByZero) => {
+                panic!("Denominator must not be zero")
+            }
+            Err(CheckedMultiplyRatioError::Overflow) => panic!("Multiplication overflow"),
+        }
+    }
+
+    /// Returns `self * numerator / denominator`, rounded up.
+    ///
+    /// In contrast to [`Uint256::checked_multiply_ratio`], the result is always rounded up.
+    /// E.g. 5 * 99/100 = 5.
+    pub fn checked_multiply_ratio_ceil<A: Into<Uint256>, B: Into<Uint256>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Result<Uint256, CheckedMultiplyRatioError> {
+        let numerator: Uint256 = numerator.into();
+        let denominator: Uint256 = denominator.into();
+        if denominator.is_zero() {
+            return Err(CheckedMultiplyRatioError::DivideByZero);
+        }
+        let product = self.full_mul(numerator);
+        let denominator = Uint512::from(denominator);
+        let mut ratio = product / denominator;
+        if !(product % denominator).is_zero() {
+            ratio += Uint512::from(1u8);
+        }
+        ratio
+            .try_into()
+            .map_err(|_| CheckedMultiplyRatioError::Overflow)
+    }
+
     /// Multiplies two u256 values without overflow, producing an
     /// [`Uint512`].
     ///
@@ -334,8 +378,26 @@ impl Uint256 {
             self - other
         }
     }
+
+    /// Strict integer addition. Computes `self + rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Uint256::add`].
+    pub fn strict_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs)
+            .unwrap_or_else(|_| panic!("attempt to add with overflow"))
+    }
+
+    /// Strict integer subtraction. Computes `self - rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Uint256::sub`].
+    pub fn strict_sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .unwrap_or_else(|_| panic!("attempt to subtract with overflow"))
+    }
 }
 
+impl_mul_fraction!(Uint256);
+
 impl From<Uint128> for Uint256 {
     fn from(val: Uint128) -> Self {
         val.u128().into()
@@ -666,7 +728,7 @@ impl PartialEq<Uint256> for &Uint256 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{from_slice, to_vec};
+    use crate::{from_slice, to_vec, Decimal, Decimal256};
 
     #[test]
     fn uint256_new_works() {
@@ -1458,6 +1520,176 @@ mod tests {
         );
     }
 
+    #[test]
+    fn uint256_multiply_ratio_ceil_works() {
+        let base = Uint256::from(500u32);
+
+        // factor 1/1
+        assert_eq!(base.multiply_ratio_ceil(1u128, 1u128), base);
+        assert_eq!(base.multiply_ratio_ceil(3u128, 3u128), base);
+
+        // factor 3/2
+        assert_eq!(
+            base.multiply_ratio_ceil(3u128, 2u128),
+            Uint256::from(750u32)
+        );
+
+        // factor 3/7 rounds up, while multiply_ratio rounds down
+        assert_eq!(base.multiply_ratio(3u128, 7u128), Uint256::from(214u32));
+        assert_eq!(
+            base.multiply_ratio_ceil(3u128, 7u128),
+            Uint256::from(215u32)
+        );
+
+        // zero
+        assert_eq!(
+            Uint256::from(0u32).multiply_ratio_ceil(3u128, 7u128),
+            Uint256::from(0u32)
+        );
+        assert_eq!(base.multiply_ratio_ceil(0u128, 7u128), Uint256::from(0u32));
+    }
+
+    #[test]
+    fn uint256_multiply_ratio_ceil_does_not_overflow_when_result_fits() {
+        // Almost max value for Uint256.
+        let base = Uint256::MAX - Uint256::from(9u32);
+
+        assert_eq!(base.multiply_ratio_ceil(2u128, 2u128), base);
+        assert_eq!(
+            Uint256::MAX.multiply_ratio_ceil(Uint256::MAX, Uint256::MAX),
+            Uint256::MAX
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Denominator must not be zero")]
+    fn uint256_multiply_ratio_ceil_panics_for_zero_denominator() {
+        Uint256::from(500u32).multiply_ratio_ceil(1u128, 0u128);
+    }
+
+    #[test]
+    fn uint256_checked_multiply_ratio_ceil_does_not_panic() {
+        assert_eq!(
+            Uint256::from(500u32).checked_multiply_ratio_ceil(1u128, 0u128),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            Uint256::from(500u32).checked_multiply_ratio_ceil(Uint256::MAX, 1u128),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        // The rounding itself can overflow
+        assert_eq!(
+            Uint256::MAX
+                .checked_multiply_ratio_ceil(Uint256::MAX, Uint256::MAX - Uint256::from(1u8)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    fn uint256_mul_floor_and_mul_ceil_work() {
+        let five = Uint256::from(5u32);
+        assert_eq!(five.mul_floor(Decimal::percent(99)), Uint256::from(4u32));
+        assert_eq!(five.mul_ceil(Decimal::percent(99)), five);
+        assert_eq!(five.mul_floor(Decimal256::percent(99)), Uint256::from(4u32));
+        assert_eq!(five.mul_ceil(Decimal256::percent(99)), five);
+
+        // exact results are not changed
+        assert_eq!(
+            Uint256::from(100u32).mul_floor(Decimal::percent(50)),
+            Uint256::from(50u32)
+        );
+        assert_eq!(
+            Uint256::from(100u32).mul_ceil(Decimal::percent(50)),
+            Uint256::from(50u32)
+        );
+        assert_eq!(five.mul_floor(Decimal::zero()), Uint256::from(0u32));
+        assert_eq!(five.mul_ceil(Decimal::zero()), Uint256::from(0u32));
+
+        // the intermediate product does not overflow
+        assert_eq!(Uint256::MAX.mul_floor(Decimal::one()), Uint256::MAX);
+        assert_eq!(Uint256::MAX.mul_ceil(Decimal256::one()), Uint256::MAX);
+        assert_eq!(
+            Uint256::MAX.mul_floor(Decimal::percent(50)),
+            Uint256::MAX / Uint256::from(2u32)
+        );
+        assert_eq!(
+            Uint256::MAX.mul_ceil(Decimal::percent(50)),
+            Uint256::MAX / Uint256::from(2u32) + Uint256::from(1u32)
+        );
+
+        assert_eq!(
+            Uint256::MAX.checked_mul_floor(Decimal::percent(101)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        assert_eq!(
+            Uint256::MAX.checked_mul_ceil(Decimal256::MAX),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiplication overflow")]
+    fn uint256_mul_floor_panics_on_overflow() {
+        Uint256::MAX.mul_floor(Decimal::percent(200));
+    }
+
+    #[test]
+    fn uint256_div_floor_and_div_ceil_work() {
+        let five = Uint256::from(5u32);
+        assert_eq!(five.div_floor(Decimal::percent(200)), Uint256::from(2u32));
+        assert_eq!(five.div_ceil(Decimal::percent(200)), Uint256::from(3u32));
+        assert_eq!(
+            five.div_floor(Decimal256::percent(200)),
+            Uint256::from(2u32)
+        );
+        assert_eq!(five.div_ceil(Decimal256::percent(200)), Uint256::from(3u32));
+
+        // exact results are not changed
+        assert_eq!(
+            Uint256::from(10u32).div_floor(Decimal::percent(50)),
+            Uint256::from(20u32)
+        );
+        assert_eq!(
+            Uint256::from(10u32).div_ceil(Decimal::percent(50)),
+            Uint256::from(20u32)
+        );
+
+        // the intermediate product does not overflow
+        assert_eq!(Uint256::MAX.div_floor(Decimal::one()), Uint256::MAX);
+        assert_eq!(Uint256::MAX.div_ceil(Decimal256::one()), Uint256::MAX);
+        assert_eq!(
+            Uint256::MAX.div_floor(Decimal::percent(200)),
+            Uint256::MAX / Uint256::from(2u32)
+        );
+        assert_eq!(
+            Uint256::MAX.div_ceil(Decimal::percent(200)),
+            Uint256::MAX / Uint256::from(2u32) + Uint256::from(1u32)
+        );
+
+        assert_eq!(
+            five.checked_div_floor(Decimal::zero()),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            five.checked_div_ceil(Decimal256::zero()),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            Uint256::MAX.checked_div_floor(Decimal::percent(99)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        assert_eq!(
+            Uint256::MAX.checked_div_ceil(Decimal256::percent(99)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Division failed - denominator must not be zero")]
+    fn uint256_div_floor_panics_for_zero() {
+        Uint256::from(5u32).div_floor(Decimal::zero());
+    }
+
     #[test]
     fn uint256_shr_works() {
         let original = Uint256::new([
@@ -1643,6 +1875,35 @@ mod tests {
         assert_eq!(b.abs_diff(a), expected);
     }
 
+    #[test]
+    fn uint256_strict_add_works() {
+        let a = Uint256::from(5u32);
+        let b = Uint256::from(3u32);
+        assert_eq!(a.strict_add(b), Uint256::from(8u32));
+        assert_eq!(b.strict_add(a), Uint256::from(8u32));
+        assert_eq!(Uint256::MAX.strict_add(Uint256::zero()), Uint256::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn uint256_strict_add_panics_on_overflow() {
+        let _ = Uint256::MAX.strict_add(Uint256::one());
+    }
+
+    #[test]
+    fn uint256_strict_sub_works() {
+        let a = Uint256::from(5u32);
+        let b = Uint256::from(3u32);
+        assert_eq!(a.strict_sub(b), Uint256::from(2u32));
+        assert_eq!(Uint256::MAX.strict_sub(Uint256::MAX), Uint256::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn uint256_strict_sub_panics_on_overflow() {
+        let _ = Uint256::zero().strict_sub(Uint256::one());
+    }
+
     #[test]
     fn uint256_partial_eq() {
         let test_cases = [(1, 1, true), (42, 42, true), (42, 24, false), (0, 0, true)]
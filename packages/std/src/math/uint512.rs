@@ -8,8 +8,10 @@ use std::ops::{
 use std::str::FromStr;
 
 use crate::errors::{
-    ConversionOverflowError, DivideByZeroError, OverflowError, OverflowOperation, StdError,
+    CheckedMultiplyRatioError, ConversionOverflowError, DivideByZeroError, OverflowError,
+    OverflowOperation, StdError,
 };
+use crate::math::fraction::{checked_multiply_ratio_rounded, impl_mul_fraction};
 use crate::{Uint128, Uint256, Uint64};
 
 /// This module is purely a workaround that lets us ignore lints for all the code
@@ -199,6 +201,66 @@ impl Uint512 {
         Self(res)
     }
 
+    /// Returns `self * numerator / denominator`.
+    ///
+    /// Due to the nature of the integer division involved, the result is always floored.
+    /// E.g. 5 * 99/100 = 4.
+    pub fn multiply_ratio<A: Into<Uint256>, B: Into<Uint256>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Uint512 {
+        match self.checked_multiply_ratio(numerator, denominator) {
+            Ok(value) => value,
+            Err(CheckedMultiplyRatioError::DivideByZero) => {
+                panic!("Denominator must not be zero")
+            }
+            Err(CheckedMultiplyRatioError::Overflow) => panic!("Multiplication overflow"),
+        }
+    }
+
+    /// Returns `self * numerator / denominator`.
+    ///
+    /// Due to the nature of the integer division involved, the result is always floored.
+    /// E.g. 5 * 99/100 = 4.
+    pub fn checked_multiply_ratio<A: Into<Uint256>, B: Into<Uint256>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Result<Uint512, CheckedMultiplyRatioError> {
+        checked_multiply_ratio_rounded(*self, numerator.into(), denominator.into(), false)
+    }
+
+    /// Returns `self * numerator / denominator`, rounded up.
+    ///
+    /// In contrast to [`Uint512::multiply_ratio`], the result is always rounded up.
+    /// E.g. 5 * 99/100 = 5.
+    pub fn multiply_ratio_ceil<A: Into<Uint256>, B: Into<Uint256>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Uint512 {
+        match self.checked_multiply_ratio_ceil(numerator, denominator) {
+            Ok(value) => value,
+            Err(CheckedMultiplyRatioError::DivideByZero) => {
+                panic!("Denominator must not be zero")
+            }
+            Err(CheckedMultiplyRatioError::Overflow) => panic!("Multiplication overflow"),
+        }
+    }
+
+    /// Returns `self * numerator / denominator`, rounded up.
+    ///
+    /// In contrast to [`Uint512::checked_multiply_ratio`], the result is always rounded up.
+    /// E.g. 5 * 99/100 = 5.
+    pub fn checked_multiply_ratio_ceil<A: Into<Uint256>, B: Into<Uint256>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Result<Uint512, CheckedMultiplyRatioError> {
+        checked_multiply_ratio_rounded(*self, numerator.into(), denominator.into(), true)
+    }
+
     pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
         self.0
             .checked_add(other.0)
@@ -303,8 +365,26 @@ impl Uint512 {
             self - other
         }
     }
+
+    /// Strict integer addition. Computes `self + rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Uint512::add`].
+    pub fn strict_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs)
+            .unwrap_or_else(|_| panic!("attempt to add with overflow"))
+    }
+
+    /// Strict integer subtraction. Computes `self - rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Uint512::sub`].
+    pub fn strict_sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .unwrap_or_else(|_| panic!("attempt to subtract with overflow"))
+    }
 }
 
+impl_mul_fraction!(Uint512);
+
 impl From<Uint256> for Uint512 {
     fn from(val: Uint256) -> Self {
         let bytes = [[0u8; 32], val.to_be_bytes()].concat();
@@ -384,6 +464,16 @@ impl TryFrom<Uint512> for Uint128 {
     }
 }
 
+impl TryFrom<Uint512> for Uint64 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: Uint512) -> Result<Self, Self::Error> {
+        Ok(Uint64::new(value.0.try_into().map_err(|_| {
+            ConversionOverflowError::new("Uint512", "Uint64", value.to_string())
+        })?))
+    }
+}
+
 impl TryFrom<&str> for Uint512 {
     type Error = StdError;
 
@@ -621,7 +711,7 @@ impl PartialEq<Uint512> for &Uint512 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{from_slice, to_vec};
+    use crate::{from_slice, to_vec, Decimal, Decimal256};
 
     #[test]
     fn uint512_new_works() {
@@ -732,6 +822,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn uint512_convert_to_uint64() {
+        let source = Uint512::from(42u64);
+        let target = Uint64::try_from(source);
+        assert_eq!(target, Ok(Uint64::new(42u64)));
+
+        let source = Uint512::from(u64::MAX) + Uint512::one();
+        let target = Uint64::try_from(source);
+        assert_eq!(
+            target,
+            Err(ConversionOverflowError::new(
+                "Uint512",
+                "Uint64",
+                "18446744073709551616"
+            ))
+        );
+    }
+
     #[test]
     fn uint512_from_uint256() {
         assert_eq!(
@@ -1083,6 +1191,172 @@ mod tests {
         Uint512::MAX.pow(2u32);
     }
 
+    #[test]
+    fn uint512_multiply_ratio_works() {
+        let base = Uint512::from(500u32);
+
+        // factor 1/1
+        assert_eq!(base.multiply_ratio(1u128, 1u128), base);
+        assert_eq!(base.multiply_ratio(3u128, 3u128), base);
+        assert_eq!(base.multiply_ratio(Uint256::MAX, Uint256::MAX), base);
+
+        // factor 3/2
+        assert_eq!(base.multiply_ratio(3u128, 2u128), Uint512::from(750u32));
+
+        // factor 2/3 (integer devision always floors the result)
+        assert_eq!(base.multiply_ratio(2u128, 3u128), Uint512::from(333u32));
+
+        // the product of self and numerator does not need to fit into a Uint512
+        assert_eq!(
+            Uint512::MAX.multiply_ratio(Uint256::MAX, Uint256::MAX),
+            Uint512::MAX
+        );
+        assert_eq!(
+            Uint512::MAX.multiply_ratio(2u128, 3u128),
+            Uint512::MAX / Uint512::from(3u32) * Uint512::from(2u32)
+        );
+        assert_eq!(
+            Uint512::MAX.multiply_ratio(1u128, 2u128),
+            Uint512::MAX / Uint512::from(2u32)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiplication overflow")]
+    fn uint512_multiply_ratio_panicks_on_overflow() {
+        Uint512::MAX.multiply_ratio(2u128, 1u128);
+    }
+
+    #[test]
+    #[should_panic(expected = "Denominator must not be zero")]
+    fn uint512_multiply_ratio_panics_for_zero_denominator() {
+        Uint512::from(500u32).multiply_ratio(1u128, 0u128);
+    }
+
+    #[test]
+    fn uint512_checked_multiply_ratio_does_not_panic() {
+        assert_eq!(
+            Uint512::from(500u32).checked_multiply_ratio(1u128, 0u128),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            Uint512::MAX.checked_multiply_ratio(Uint256::MAX, 1u128),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    fn uint512_multiply_ratio_ceil_works() {
+        let base = Uint512::from(500u32);
+
+        // exact results are not changed
+        assert_eq!(base.multiply_ratio_ceil(1u128, 1u128), base);
+        assert_eq!(
+            base.multiply_ratio_ceil(3u128, 2u128),
+            Uint512::from(750u32)
+        );
+
+        // factor 3/7 rounds up, while multiply_ratio rounds down
+        assert_eq!(base.multiply_ratio(3u128, 7u128), Uint512::from(214u32));
+        assert_eq!(
+            base.multiply_ratio_ceil(3u128, 7u128),
+            Uint512::from(215u32)
+        );
+
+        // zero remains zero
+        assert_eq!(
+            Uint512::zero().multiply_ratio_ceil(3u128, 7u128),
+            Uint512::zero()
+        );
+        assert_eq!(base.multiply_ratio_ceil(0u128, 7u128), Uint512::zero());
+
+        // the product of self and numerator does not need to fit into a Uint512
+        assert_eq!(
+            Uint512::MAX.multiply_ratio_ceil(Uint256::MAX, Uint256::MAX),
+            Uint512::MAX
+        );
+        assert_eq!(
+            Uint512::MAX.multiply_ratio_ceil(1u128, 2u128),
+            Uint512::MAX / Uint512::from(2u32) + Uint512::one()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Denominator must not be zero")]
+    fn uint512_multiply_ratio_ceil_panics_for_zero_denominator() {
+        Uint512::from(500u32).multiply_ratio_ceil(1u128, 0u128);
+    }
+
+    #[test]
+    fn uint512_checked_multiply_ratio_ceil_does_not_panic() {
+        assert_eq!(
+            Uint512::from(500u32).checked_multiply_ratio_ceil(1u128, 0u128),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            Uint512::MAX.checked_multiply_ratio_ceil(2u128, 1u128),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        // the rounding itself can overflow
+        assert_eq!(
+            Uint512::MAX.checked_multiply_ratio_ceil(Uint256::MAX, Uint256::MAX - Uint256::one()),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    fn uint512_mul_floor_and_mul_ceil_work() {
+        let five = Uint512::from(5u32);
+        assert_eq!(five.mul_floor(Decimal::percent(99)), Uint512::from(4u32));
+        assert_eq!(five.mul_ceil(Decimal::percent(99)), five);
+        assert_eq!(five.mul_floor(Decimal256::percent(99)), Uint512::from(4u32));
+        assert_eq!(five.mul_ceil(Decimal256::percent(99)), five);
+
+        // the intermediate product does not overflow
+        assert_eq!(Uint512::MAX.mul_floor(Decimal::one()), Uint512::MAX);
+        assert_eq!(Uint512::MAX.mul_ceil(Decimal256::one()), Uint512::MAX);
+        assert_eq!(
+            Uint512::MAX.mul_floor(Decimal::percent(50)),
+            Uint512::MAX / Uint512::from(2u32)
+        );
+        assert_eq!(
+            Uint512::MAX.mul_ceil(Decimal::percent(50)),
+            Uint512::MAX / Uint512::from(2u32) + Uint512::one()
+        );
+
+        assert_eq!(
+            Uint512::MAX.checked_mul_floor(Decimal::percent(101)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        assert_eq!(
+            Uint512::MAX.checked_mul_ceil(Decimal256::MAX),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    fn uint512_div_floor_and_div_ceil_work() {
+        let five = Uint512::from(5u32);
+        assert_eq!(five.div_floor(Decimal::percent(200)), Uint512::from(2u32));
+        assert_eq!(five.div_ceil(Decimal256::percent(200)), Uint512::from(3u32));
+
+        // the intermediate product does not overflow
+        assert_eq!(Uint512::MAX.div_floor(Decimal::one()), Uint512::MAX);
+        assert_eq!(
+            Uint512::MAX.div_ceil(Decimal::percent(200)),
+            Uint512::MAX / Uint512::from(2u32) + Uint512::one()
+        );
+
+        assert_eq!(
+            five.checked_div_floor(Decimal::zero()),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            Uint512::MAX.checked_div_ceil(Decimal256::percent(99)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
     #[test]
     fn uint512_shr_works() {
         let original = Uint512::new([
@@ -1272,6 +1546,35 @@ mod tests {
         assert_eq!(b.abs_diff(a), expected);
     }
 
+    #[test]
+    fn uint512_strict_add_works() {
+        let a = Uint512::from(5u32);
+        let b = Uint512::from(3u32);
+        assert_eq!(a.strict_add(b), Uint512::from(8u32));
+        assert_eq!(b.strict_add(a), Uint512::from(8u32));
+        assert_eq!(Uint512::MAX.strict_add(Uint512::zero()), Uint512::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn uint512_strict_add_panics_on_overflow() {
+        let _ = Uint512::MAX.strict_add(Uint512::one());
+    }
+
+    #[test]
+    fn uint512_strict_sub_works() {
+        let a = Uint512::from(5u32);
+        let b = Uint512::from(3u32);
+        assert_eq!(a.strict_sub(b), Uint512::from(2u32));
+        assert_eq!(Uint512::MAX.strict_sub(Uint512::MAX), Uint512::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn uint512_strict_sub_panics_on_overflow() {
+        let _ = Uint512::zero().strict_sub(Uint512::one());
+    }
+
     #[test]
     fn uint512_partial_eq() {
         let test_cases = [(1, 1, true), (42, 42, true), (42, 24, false), (0, 0, true)]
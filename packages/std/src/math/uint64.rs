@@ -9,6 +9,7 @@ use std::ops::{
 use crate::errors::{
     CheckedMultiplyRatioError, DivideByZeroError, OverflowError, OverflowOperation, StdError,
 };
+use crate::math::fraction::impl_mul_fraction;
 use crate::Uint128;
 
 /// A thin wrapper around u64 that is using strings for JSON encoding/decoding,
@@ -58,6 +59,16 @@ impl Uint64 {
         self.0
     }
 
+    /// Creates a Uint64 from its big endian representation.
+    pub const fn from_be_bytes(data: [u8; 8]) -> Self {
+        Self(u64::from_be_bytes(data))
+    }
+
+    /// Creates a Uint64 from its little endian representation.
+    pub const fn from_le_bytes(data: [u8; 8]) -> Self {
+        Self(u64::from_le_bytes(data))
+    }
+
     /// Returns a copy of the number as big endian bytes.
     pub const fn to_be_bytes(self) -> [u8; 8] {
         self.0.to_be_bytes()
@@ -114,6 +125,49 @@ impl Uint64 {
         }
     }
 
+    /// Returns `self * numerator / denominator`, rounded up.
+    ///
+    /// In contrast to [`Uint64::multiply_ratio`], the result is always rounded up.
+    /// E.g. 5 * 99/100 = 5.
+    pub fn multiply_ratio_ceil<A: Into<u64>, B: Into<u64>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Uint64 {
+        match self.checked_multiply_ratio_ceil(numerator, denominator) {
+            Ok(value) => value,
+            Err(CheckedMultiplyRatioError::DivideByZero) => {
+                panic!("Denominator must not be zero")
+            }
+            Err(CheckedMultiplyRatioError::Overflow) => panic!("Multiplication overflow"),
+        }
+    }
+
+    /// Returns `self * numerator / denominator`, rounded up.
+    ///
+    /// In contrast to [`Uint64::checked_multiply_ratio`], the result is always rounded up.
+    /// E.g. 5 * 99/100 = 5.
+    pub fn checked_multiply_ratio_ceil<A: Into<u64>, B: Into<u64>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Result<Uint64, CheckedMultiplyRatioError> {
+        let numerator: u64 = numerator.into();
+        let denominator: u64 = denominator.into();
+        if denominator == 0 {
+            return Err(CheckedMultiplyRatioError::DivideByZero);
+        }
+        let product = self.full_mul(numerator);
+        let denominator = Uint128::from(denominator);
+        let mut ratio = product / denominator;
+        if !(product % denominator).is_zero() {
+            ratio += Uint128::from(1u8);
+        }
+        ratio
+            .try_into()
+            .map_err(|_| CheckedMultiplyRatioError::Overflow)
+    }
+
     /// Multiplies two `Uint64`/`u64` values without overflow, producing an
     /// [`Uint128`].
     ///
@@ -224,8 +278,30 @@ impl Uint64 {
             self.0 - other.0
         })
     }
+
+    /// Strict integer addition. Computes `self + rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Uint64::add`] but const.
+    pub const fn strict_add(self, rhs: Self) -> Self {
+        match self.0.checked_add(rhs.0) {
+            None => panic!("attempt to add with overflow"),
+            Some(sum) => Self(sum),
+        }
+    }
+
+    /// Strict integer subtraction. Computes `self - rhs`, panicking if overflow occurred.
+    ///
+    /// This is the same as [`Uint64::sub`] but const.
+    pub const fn strict_sub(self, other: Self) -> Self {
+        match self.0.checked_sub(other.0) {
+            None => panic!("attempt to subtract with overflow"),
+            Some(diff) => Self(diff),
+        }
+    }
 }
 
+impl_mul_fraction!(Uint64);
+
 // `From<u{128,64,32,16,8}>` is implemented manually instead of
 // using `impl<T: Into<u64>> From<T> for Uint64` because
 // of the conflict with `TryFrom<&str>` as described here
@@ -492,7 +568,7 @@ impl PartialEq<Uint64> for &Uint64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{from_slice, to_vec};
+    use crate::{from_slice, to_vec, Decimal, Decimal256};
 
     #[test]
     fn uint64_zero_works() {
@@ -786,6 +862,154 @@ mod tests {
         );
     }
 
+    #[test]
+    fn uint64_multiply_ratio_ceil_works() {
+        let base = Uint64(500u64);
+
+        // factor 1/1
+        assert_eq!(base.multiply_ratio_ceil(1u64, 1u64), base);
+        assert_eq!(base.multiply_ratio_ceil(3u64, 3u64), base);
+
+        // factor 3/2
+        assert_eq!(base.multiply_ratio_ceil(3u64, 2u64), Uint64(750u64));
+
+        // factor 3/7 rounds up, while multiply_ratio rounds down
+        assert_eq!(base.multiply_ratio(3u64, 7u64), Uint64(214u64));
+        assert_eq!(base.multiply_ratio_ceil(3u64, 7u64), Uint64(215u64));
+
+        // zero
+        assert_eq!(Uint64(0u64).multiply_ratio_ceil(3u64, 7u64), Uint64(0u64));
+        assert_eq!(base.multiply_ratio_ceil(0u64, 7u64), Uint64(0u64));
+    }
+
+    #[test]
+    fn uint64_multiply_ratio_ceil_does_not_overflow_when_result_fits() {
+        // Almost max value for Uint64.
+        let base = Uint64::MAX - Uint64(9u64);
+
+        assert_eq!(base.multiply_ratio_ceil(2u64, 2u64), base);
+        assert_eq!(
+            Uint64::MAX.multiply_ratio_ceil(u64::MAX, u64::MAX),
+            Uint64::MAX
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Denominator must not be zero")]
+    fn uint64_multiply_ratio_ceil_panics_for_zero_denominator() {
+        Uint64(500u64).multiply_ratio_ceil(1u64, 0u64);
+    }
+
+    #[test]
+    fn uint64_checked_multiply_ratio_ceil_does_not_panic() {
+        assert_eq!(
+            Uint64(500u64).checked_multiply_ratio_ceil(1u64, 0u64),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            Uint64(500u64).checked_multiply_ratio_ceil(u64::MAX, 1u64),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        // The rounding itself can overflow
+        assert_eq!(
+            Uint64::MAX.checked_multiply_ratio_ceil(u64::MAX, u64::MAX - u64::from(1u8)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    fn uint64_mul_floor_and_mul_ceil_work() {
+        let five = Uint64(5u64);
+        assert_eq!(five.mul_floor(Decimal::percent(99)), Uint64(4u64));
+        assert_eq!(five.mul_ceil(Decimal::percent(99)), five);
+        assert_eq!(five.mul_floor(Decimal256::percent(99)), Uint64(4u64));
+        assert_eq!(five.mul_ceil(Decimal256::percent(99)), five);
+
+        // exact results are not changed
+        assert_eq!(
+            Uint64(100u64).mul_floor(Decimal::percent(50)),
+            Uint64(50u64)
+        );
+        assert_eq!(Uint64(100u64).mul_ceil(Decimal::percent(50)), Uint64(50u64));
+        assert_eq!(five.mul_floor(Decimal::zero()), Uint64(0u64));
+        assert_eq!(five.mul_ceil(Decimal::zero()), Uint64(0u64));
+
+        // the intermediate product does not overflow
+        assert_eq!(Uint64::MAX.mul_floor(Decimal::one()), Uint64::MAX);
+        assert_eq!(Uint64::MAX.mul_ceil(Decimal256::one()), Uint64::MAX);
+        assert_eq!(
+            Uint64::MAX.mul_floor(Decimal::percent(50)),
+            Uint64::MAX / Uint64(2u64)
+        );
+        assert_eq!(
+            Uint64::MAX.mul_ceil(Decimal::percent(50)),
+            Uint64::MAX / Uint64(2u64) + Uint64(1u64)
+        );
+
+        assert_eq!(
+            Uint64::MAX.checked_mul_floor(Decimal::percent(101)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        assert_eq!(
+            Uint64::MAX.checked_mul_ceil(Decimal256::MAX),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiplication overflow")]
+    fn uint64_mul_floor_panics_on_overflow() {
+        Uint64::MAX.mul_floor(Decimal::percent(200));
+    }
+
+    #[test]
+    fn uint64_div_floor_and_div_ceil_work() {
+        let five = Uint64(5u64);
+        assert_eq!(five.div_floor(Decimal::percent(200)), Uint64(2u64));
+        assert_eq!(five.div_ceil(Decimal::percent(200)), Uint64(3u64));
+        assert_eq!(five.div_floor(Decimal256::percent(200)), Uint64(2u64));
+        assert_eq!(five.div_ceil(Decimal256::percent(200)), Uint64(3u64));
+
+        // exact results are not changed
+        assert_eq!(Uint64(10u64).div_floor(Decimal::percent(50)), Uint64(20u64));
+        assert_eq!(Uint64(10u64).div_ceil(Decimal::percent(50)), Uint64(20u64));
+
+        // the intermediate product does not overflow
+        assert_eq!(Uint64::MAX.div_floor(Decimal::one()), Uint64::MAX);
+        assert_eq!(Uint64::MAX.div_ceil(Decimal256::one()), Uint64::MAX);
+        assert_eq!(
+            Uint64::MAX.div_floor(Decimal::percent(200)),
+            Uint64::MAX / Uint64(2u64)
+        );
+        assert_eq!(
+            Uint64::MAX.div_ceil(Decimal::percent(200)),
+            Uint64::MAX / Uint64(2u64) + Uint64(1u64)
+        );
+
+        assert_eq!(
+            five.checked_div_floor(Decimal::zero()),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            five.checked_div_ceil(Decimal256::zero()),
+            Err(CheckedMultiplyRatioError::DivideByZero),
+        );
+        assert_eq!(
+            Uint64::MAX.checked_div_floor(Decimal::percent(99)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+        assert_eq!(
+            Uint64::MAX.checked_div_ceil(Decimal256::percent(99)),
+            Err(CheckedMultiplyRatioError::Overflow),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Division failed - denominator must not be zero")]
+    fn uint64_div_floor_panics_for_zero() {
+        Uint64(5u64).div_floor(Decimal::zero());
+    }
+
     #[test]
     fn sum_works() {
         let nums = vec![Uint64(17), Uint64(123), Uint64(540), Uint64(82)];
@@ -936,6 +1160,53 @@ mod tests {
         assert_eq!(b.abs_diff(a), expected);
     }
 
+    #[test]
+    fn uint64_strict_add_works() {
+        let a = Uint64::from(5u32);
+        let b = Uint64::from(3u32);
+        assert_eq!(a.strict_add(b), Uint64::from(8u32));
+        assert_eq!(b.strict_add(a), Uint64::from(8u32));
+        assert_eq!(Uint64::MAX.strict_add(Uint64::zero()), Uint64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn uint64_strict_add_panics_on_overflow() {
+        let _ = Uint64::MAX.strict_add(Uint64::one());
+    }
+
+    #[test]
+    fn uint64_strict_sub_works() {
+        let a = Uint64::from(5u32);
+        let b = Uint64::from(3u32);
+        assert_eq!(a.strict_sub(b), Uint64::from(2u32));
+        assert_eq!(Uint64::MAX.strict_sub(Uint64::MAX), Uint64::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn uint64_strict_sub_panics_on_overflow() {
+        let _ = Uint64::zero().strict_sub(Uint64::one());
+    }
+
+    #[test]
+    fn uint64_from_be_bytes_and_from_le_bytes_work() {
+        let mut be = [0u8; 8];
+        be[7] = 42;
+        assert_eq!(Uint64::from_be_bytes(be), Uint64::from(42u32));
+        assert_eq!(
+            Uint64::from_be_bytes(Uint64::MAX.to_be_bytes()),
+            Uint64::MAX
+        );
+        let mut le = [0u8; 8];
+        le[0] = 42;
+        assert_eq!(Uint64::from_le_bytes(le), Uint64::from(42u32));
+        assert_eq!(
+            Uint64::from_le_bytes(Uint64::MAX.to_le_bytes()),
+            Uint64::MAX
+        );
+    }
+
     #[test]
     fn uint64_partial_eq() {
         let test_cases = [(1, 1, true), (42, 42, true), (42, 24, false), (0, 0, true)]
@@ -0,0 +1,32 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Replicates the cosmos-sdk bank module Metadata type
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/bank/v1beta1/bank.proto#L74-L96
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DenomMetadata {
+    pub description: String,
+    /// The units of the denom. One of them must be the base denom with exponent 0.
+    pub denom_units: Vec<DenomUnit>,
+    /// The base denom, i.e. the smallest unit of the token (e.g. uatom)
+    pub base: String,
+    /// The denom unit that is shown to users (e.g. atom)
+    pub display: String,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub uri_hash: String,
+}
+
+/// Replicates the cosmos-sdk bank module DenomUnit type
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/bank/v1beta1/bank.proto#L62-L72
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DenomUnit {
+    pub denom: String,
+    /// The power of 10 the base denom needs to be multiplied with to get
+    /// one unit of this denom (e.g. 6 for atom if the base is uatom)
+    pub exponent: u32,
+    pub aliases: Vec<String>,
+}
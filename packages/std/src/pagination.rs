@@ -0,0 +1,56 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::Binary;
+
+/// Simplified version of the PageRequest type for pagination from the cosmos-sdk
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/base/query/v1beta1/pagination.proto#L13-L42
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PageRequest {
+    /// The key of the first entry to return. Use the `next_key` of a previous
+    /// `PageResponse` here to get the next page. `None` starts at the beginning.
+    pub key: Option<Binary>,
+    /// The maximum number of entries to return
+    pub limit: u32,
+    /// If true, entries are returned in descending order
+    pub reverse: bool,
+}
+
+/// Simplified version of the PageResponse type for pagination from the cosmos-sdk
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/base/query/v1beta1/pagination.proto#L44-L55
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct PageResponse {
+    /// The key to be passed to `PageRequest::key` to query the next page.
+    /// This is `None` if there are no more results.
+    pub next_key: Option<Binary>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_slice, to_vec};
+
+    #[test]
+    fn page_request_serialization_works() {
+        let request = PageRequest {
+            key: Some(Binary::from(b"foo")),
+            limit: 10,
+            reverse: true,
+        };
+        let json = to_vec(&request).unwrap();
+        assert_eq!(json, br#"{"key":"Zm9v","limit":10,"reverse":true}"#);
+        let parsed: PageRequest = from_slice(&json).unwrap();
+        assert_eq!(parsed, request);
+    }
+
+    #[test]
+    fn page_response_serialization_works() {
+        let response = PageResponse { next_key: None };
+        let json = to_vec(&response).unwrap();
+        assert_eq!(json, br#"{"next_key":null}"#);
+        let parsed: PageResponse = from_slice(&json).unwrap();
+        assert_eq!(parsed, response);
+    }
+}
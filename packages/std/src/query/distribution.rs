@@ -0,0 +1,196 @@
+#![cfg(feature = "cosmwasm_1_3")]
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{Addr, DecCoin};
+
+/// The queries of the distribution module.
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/distribution/v1beta1/query.proto
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DistributionQuery {
+    /// Returns the address that receives the delegator's rewards.
+    /// This is the delegator address itself if no withdraw address was set.
+    ///
+    /// The query response type is `DelegatorWithdrawAddressResponse`.
+    DelegatorWithdrawAddress { delegator_address: String },
+    /// Returns the rewards accrued by a single delegation.
+    ///
+    /// The query response type is `DelegationRewardsResponse`.
+    DelegationRewards {
+        delegator_address: String,
+        validator_address: String,
+    },
+    /// Returns the rewards accrued by all delegations of a delegator.
+    ///
+    /// The query response type is `DelegationTotalRewardsResponse`.
+    DelegationTotalRewards { delegator_address: String },
+    /// Returns the addresses of all validators a delegator is bonded to.
+    ///
+    /// The query response type is `DelegatorValidatorsResponse`.
+    DelegatorValidators { delegator_address: String },
+}
+
+/// The data format returned from DistributionQuery::DelegatorWithdrawAddress query
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/distribution/v1beta1/query.proto#L202-L207
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct DelegatorWithdrawAddressResponse {
+    pub withdraw_address: Addr,
+}
+
+impl DelegatorWithdrawAddressResponse {
+    /// Convenience constructor for tests / mocks
+    #[doc(hidden)]
+    pub fn new(withdraw_address: Addr) -> Self {
+        Self { withdraw_address }
+    }
+}
+
+/// The data format returned from DistributionQuery::DelegationRewards query
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/distribution/v1beta1/query.proto#L149-L153
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct DelegationRewardsResponse {
+    pub rewards: Vec<DecCoin>,
+}
+
+impl DelegationRewardsResponse {
+    /// Convenience constructor for tests / mocks
+    #[doc(hidden)]
+    pub fn new(rewards: Vec<DecCoin>) -> Self {
+        Self { rewards }
+    }
+}
+
+/// The data format returned from DistributionQuery::DelegationTotalRewards query
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/distribution/v1beta1/query.proto#L164-L171
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct DelegationTotalRewardsResponse {
+    /// All the rewards accrued by a delegator, one entry per validator
+    pub rewards: Vec<DelegatorReward>,
+    /// The sum of all rewards
+    pub total: Vec<DecCoin>,
+}
+
+impl DelegationTotalRewardsResponse {
+    /// Convenience constructor for tests / mocks
+    #[doc(hidden)]
+    pub fn new(rewards: Vec<DelegatorReward>, total: Vec<DecCoin>) -> Self {
+        Self { rewards, total }
+    }
+}
+
+/// The rewards accrued by a delegator at a single validator.
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/distribution/v1beta1/distribution.proto#L148-L156
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct DelegatorReward {
+    /// A validator address (e.g. cosmosvaloper1...)
+    pub validator_address: String,
+    pub reward: Vec<DecCoin>,
+}
+
+impl DelegatorReward {
+    /// Convenience constructor for tests / mocks
+    #[doc(hidden)]
+    pub fn new(validator_address: impl Into<String>, reward: Vec<DecCoin>) -> Self {
+        Self {
+            validator_address: validator_address.into(),
+            reward,
+        }
+    }
+}
+
+/// The data format returned from DistributionQuery::DelegatorValidators query
+///
+/// See https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/distribution/v1beta1/query.proto#L186-L190
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct DelegatorValidatorsResponse {
+    /// Validator addresses (e.g. cosmosvaloper1...)
+    pub validators: Vec<String>,
+}
+
+impl DelegatorValidatorsResponse {
+    /// Convenience constructor for tests / mocks
+    #[doc(hidden)]
+    pub fn new(validators: Vec<String>) -> Self {
+        Self { validators }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{to_binary, Decimal256};
+
+    #[test]
+    fn distribution_query_serialization_works() {
+        let query = DistributionQuery::DelegatorWithdrawAddress {
+            delegator_address: "bob".to_string(),
+        };
+        let json = to_binary(&query).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"delegator_withdraw_address":{"delegator_address":"bob"}}"#,
+        );
+
+        let query = DistributionQuery::DelegationRewards {
+            delegator_address: "bob".to_string(),
+            validator_address: "val1".to_string(),
+        };
+        let json = to_binary(&query).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"delegation_rewards":{"delegator_address":"bob","validator_address":"val1"}}"#,
+        );
+
+        let query = DistributionQuery::DelegationTotalRewards {
+            delegator_address: "bob".to_string(),
+        };
+        let json = to_binary(&query).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"delegation_total_rewards":{"delegator_address":"bob"}}"#,
+        );
+
+        let query = DistributionQuery::DelegatorValidators {
+            delegator_address: "bob".to_string(),
+        };
+        let json = to_binary(&query).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"delegator_validators":{"delegator_address":"bob"}}"#,
+        );
+    }
+
+    #[test]
+    fn delegation_total_rewards_response_serialization_works() {
+        let response = DelegationTotalRewardsResponse {
+            rewards: vec![DelegatorReward {
+                validator_address: "val1".to_string(),
+                reward: vec![DecCoin::new(Decimal256::permille(1500), "ucosm")],
+            }],
+            total: vec![DecCoin::new(Decimal256::permille(1500), "ucosm")],
+        };
+        let json = to_binary(&response).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"rewards":[{"validator_address":"val1","reward":[{"denom":"ucosm","amount":"1.5"}]}],"total":[{"denom":"ucosm","amount":"1.5"}]}"#,
+        );
+    }
+}
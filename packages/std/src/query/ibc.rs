@@ -29,7 +29,34 @@ pub enum IbcQuery {
         channel_id: String,
         port_id: Option<String>,
     },
-    // TODO: Add more
+    /// Gets information about the given connection.
+    ///
+    /// Returns a `ConnectionResponse`.
+    #[cfg(feature = "cosmwasm_1_4")]
+    Connection { connection_id: String },
+    /// Gets the client ID and chain ID of the counterparty of the given connection.
+    ///
+    /// Returns a `CounterpartyResponse`.
+    #[cfg(feature = "cosmwasm_1_4")]
+    Counterparty { connection_id: String },
+    /// Gets the sequence number of the next packet sent over the given channel.
+    /// If port_id is omitted, it will default to the contract's own port.
+    ///
+    /// Returns a `NextSequenceSendResponse`.
+    #[cfg(feature = "cosmwasm_1_4")]
+    NextSequenceSend {
+        channel_id: String,
+        port_id: Option<String>,
+    },
+    /// Checks whether the given channel supports the ICS-29 fee middleware.
+    /// If port_id is omitted, it will default to the contract's own port.
+    ///
+    /// Returns a `FeeEnabledChannelResponse`.
+    #[cfg(feature = "cosmwasm_1_4")]
+    FeeEnabledChannel {
+        channel_id: String,
+        port_id: Option<String>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -46,3 +73,47 @@ pub struct ListChannelsResponse {
 pub struct ChannelResponse {
     pub channel: Option<IbcChannel>,
 }
+
+#[cfg(feature = "cosmwasm_1_4")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ConnectionResponse {
+    pub connection: Option<IbcConnection>,
+}
+
+/// An IBC connection between a light client on this chain and one on the counterparty chain.
+#[cfg(feature = "cosmwasm_1_4")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcConnection {
+    pub connection_id: String,
+    /// The client on this chain that tracks the counterparty chain
+    pub client_id: String,
+    /// The client on the counterparty chain that tracks this chain
+    pub counterparty_client_id: String,
+    /// The connection ID on the counterparty chain.
+    /// This is `None` if the connection handshake is not yet complete.
+    pub counterparty_connection_id: Option<String>,
+    /// Delay period in nanoseconds that must pass before a consensus state
+    /// can be used for packet verification
+    pub delay_period: u64,
+}
+
+#[cfg(feature = "cosmwasm_1_4")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct CounterpartyResponse {
+    /// The client ID on the counterparty chain
+    pub client_id: String,
+    /// The chain ID of the counterparty chain
+    pub chain_id: String,
+}
+
+#[cfg(feature = "cosmwasm_1_4")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct NextSequenceSendResponse {
+    pub sequence: u64,
+}
+
+#[cfg(feature = "cosmwasm_1_4")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct FeeEnabledChannelResponse {
+    pub fee_enabled: bool,
+}
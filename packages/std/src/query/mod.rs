@@ -1,11 +1,12 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "stargate")]
+#[cfg(any(feature = "stargate", feature = "cosmwasm_1_4"))]
 use crate::Binary;
 use crate::Empty;
 
 mod bank;
+mod distribution;
 mod ibc;
 mod staking;
 mod wasm;
@@ -13,13 +14,27 @@ mod wasm;
 #[cfg(feature = "cosmwasm_1_1")]
 pub use bank::SupplyResponse;
 pub use bank::{AllBalanceResponse, BalanceResponse, BankQuery};
+#[cfg(feature = "cosmwasm_1_3")]
+pub use bank::{AllDenomMetadataResponse, DenomMetadataResponse};
+#[cfg(feature = "cosmwasm_1_3")]
+pub use distribution::{
+    DelegationRewardsResponse, DelegationTotalRewardsResponse, DelegatorReward,
+    DelegatorValidatorsResponse, DelegatorWithdrawAddressResponse, DistributionQuery,
+};
 #[cfg(feature = "stargate")]
 pub use ibc::{ChannelResponse, IbcQuery, ListChannelsResponse, PortIdResponse};
+#[cfg(all(feature = "stargate", feature = "cosmwasm_1_4"))]
+pub use ibc::{
+    ConnectionResponse, CounterpartyResponse, FeeEnabledChannelResponse, IbcConnection,
+    NextSequenceSendResponse,
+};
 #[cfg(feature = "staking")]
 pub use staking::{
     AllDelegationsResponse, AllValidatorsResponse, BondedDenomResponse, Delegation,
     DelegationResponse, FullDelegation, StakingQuery, Validator, ValidatorResponse,
 };
+#[cfg(feature = "cosmwasm_1_2")]
+pub use wasm::CodeInfoResponse;
 pub use wasm::{ContractInfoResponse, WasmQuery};
 
 #[non_exhaustive]
@@ -30,6 +45,8 @@ pub enum QueryRequest<C> {
     Custom(C),
     #[cfg(feature = "staking")]
     Staking(StakingQuery),
+    #[cfg(feature = "cosmwasm_1_3")]
+    Distribution(DistributionQuery),
     /// A Stargate query is encoded the same way as abci_query, with path and protobuf encoded request data.
     /// The format is defined in [ADR-21](https://github.com/cosmos/cosmos-sdk/blob/master/docs/architecture/adr-021-protobuf-query-encoding.md).
     /// The response is protobuf encoded data directly without a JSON response wrapper.
@@ -45,6 +62,20 @@ pub enum QueryRequest<C> {
     #[cfg(feature = "stargate")]
     Ibc(IbcQuery),
     Wasm(WasmQuery),
+    /// A gRPC query is routed to the query service of a Cosmos SDK module with the given path
+    /// and protobuf encoded request data.
+    ///
+    /// In contrast to `Stargate`, the response is returned as raw protobuf encoded bytes
+    /// and does not need to be whitelisted with a JSON conversion by the chain.
+    /// The caller is responsible for decoding the response, e.g. with its own `prost` types.
+    #[cfg(feature = "cosmwasm_1_4")]
+    Grpc {
+        /// The fully qualified service path used for routing,
+        /// eg. "/cosmos.bank.v1beta1.Query/Balance"
+        path: String,
+        /// The expected protobuf message type (not [Any](https://protobuf.dev/programming-guides/proto3/#any)), binary encoded
+        data: Binary,
+    },
 }
 
 /// A trait that is required to avoid conflicts with other query types like BankQuery and WasmQuery
@@ -91,6 +122,13 @@ impl<C: CustomQuery> From<StakingQuery> for QueryRequest<C> {
     }
 }
 
+#[cfg(feature = "cosmwasm_1_3")]
+impl<C: CustomQuery> From<DistributionQuery> for QueryRequest<C> {
+    fn from(msg: DistributionQuery) -> Self {
+        QueryRequest::Distribution(msg)
+    }
+}
+
 impl<C: CustomQuery> From<WasmQuery> for QueryRequest<C> {
     fn from(msg: WasmQuery) -> Self {
         QueryRequest::Wasm(msg)
@@ -2,6 +2,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::Binary;
+#[cfg(feature = "cosmwasm_1_2")]
+use crate::HexBinary;
 
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -24,6 +26,9 @@ pub enum WasmQuery {
     },
     /// returns a ContractInfoResponse with metadata on the contract from the runtime
     ContractInfo { contract_addr: String },
+    /// returns a CodeInfoResponse with metadata on the code
+    #[cfg(feature = "cosmwasm_1_2")]
+    CodeInfo { code_id: u64 },
 }
 
 #[non_exhaustive]
@@ -53,3 +58,81 @@ impl ContractInfoResponse {
         }
     }
 }
+
+/// The essential data from wasmd's [CodeInfo]/[CodeInfoResponse].
+///
+/// `code_hash`/`data_hash` was renamed to `checksum` to follow the CosmWasm
+/// convention and naming in `instantiate2_address`.
+///
+/// [CodeInfo]: https://github.com/CosmWasm/wasmd/blob/v0.30.0/proto/cosmwasm/wasm/v1/types.proto#L62-L72
+/// [CodeInfoResponse]: https://github.com/CosmWasm/wasmd/blob/v0.30.0/proto/cosmwasm/wasm/v1/query.proto#L184-L199
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[cfg(feature = "cosmwasm_1_2")]
+pub struct CodeInfoResponse {
+    pub code_id: u64,
+    /// The address that initially stored the code
+    pub creator: String,
+    /// The hash of the Wasm blob
+    pub checksum: HexBinary,
+}
+
+#[cfg(feature = "cosmwasm_1_2")]
+impl CodeInfoResponse {
+    /// Convenience constructor for tests / mocks
+    #[doc(hidden)]
+    pub fn new(code_id: u64, creator: impl Into<String>, checksum: HexBinary) -> Self {
+        Self {
+            code_id,
+            creator: creator.into(),
+            checksum,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::to_binary;
+
+    #[test]
+    fn wasm_query_contract_info_serialization() {
+        let query = WasmQuery::ContractInfo {
+            contract_addr: "aabbccdd456".into(),
+        };
+        let json = to_binary(&query).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"contract_info":{"contract_addr":"aabbccdd456"}}"#,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "cosmwasm_1_2")]
+    fn wasm_query_code_info_serialization() {
+        let query = WasmQuery::CodeInfo { code_id: 70 };
+        let json = to_binary(&query).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"code_info":{"code_id":70}}"#,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "cosmwasm_1_2")]
+    fn code_info_response_serialization() {
+        let response = CodeInfoResponse {
+            code_id: 67,
+            creator: "jane".to_string(),
+            checksum: HexBinary::from_hex(
+                "f7bb7b18fb01bbf425cf4ed2cd4b7fb26a019a7fc75a4dc87e8a0b768c501f00",
+            )
+            .unwrap(),
+        };
+        let json = to_binary(&response).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"code_id":67,"creator":"jane","checksum":"f7bb7b18fb01bbf425cf4ed2cd4b7fb26a019a7fc75a4dc87e8a0b768c501f00"}"#,
+        );
+    }
+}
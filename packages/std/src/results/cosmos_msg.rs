@@ -111,6 +111,21 @@ pub enum DistributionMsg {
         /// The `validator_address`
         validator: String,
     },
+    /// This is translated to a [MsgFundCommunityPool](https://github.com/cosmos/cosmos-sdk/blob/v0.45.8/proto/cosmos/distribution/v1beta1/tx.proto#L69-L76).
+    /// `depositor` is automatically filled with the current contract's address.
+    #[cfg(feature = "cosmwasm_1_3")]
+    FundCommunityPool {
+        /// The amount to spend
+        amount: Vec<Coin>,
+    },
+    /// This is translated to a [MsgDepositValidatorRewardsPool](https://github.com/cosmos/cosmos-sdk/blob/v0.50.0/proto/cosmos/distribution/v1beta1/tx.proto#L212-L224).
+    /// `depositor` is automatically filled with the current contract's address.
+    #[cfg(feature = "cosmwasm_1_3")]
+    DepositValidatorRewardsPool {
+        /// The `validator_address`
+        validator: String,
+        amount: Vec<Coin>,
+    },
 }
 
 fn binary_to_string(data: &Binary, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
@@ -153,6 +168,25 @@ pub enum WasmMsg {
         /// A human-readbale label for the contract
         label: String,
     },
+    /// Instantiates a new contracts from previously uploaded Wasm code
+    /// using a predictable address derivation algorithm implemented in
+    /// [`instantiate2_address`](crate::instantiate2_address).
+    ///
+    /// This is translated to a [MsgInstantiateContract2](https://github.com/CosmWasm/wasmd/blob/v0.29.2/proto/cosmwasm/wasm/v1/tx.proto#L73-L96).
+    /// `sender` is automatically filled with the current contract's address.
+    /// `fix_msg` is automatically set to false.
+    #[cfg(feature = "cosmwasm_1_2")]
+    Instantiate2 {
+        admin: Option<String>,
+        code_id: u64,
+        /// A human-readbale label for the contract
+        label: String,
+        /// msg is the JSON-encoded InstantiateMsg struct (as raw Binary)
+        #[derivative(Debug(format_with = "binary_to_string"))]
+        msg: Binary,
+        funds: Vec<Coin>,
+        salt: Binary,
+    },
     /// Migrates a given contracts to use new wasm code. Passes a MigrateMsg to allow us to
     /// customize behavior.
     ///
@@ -229,6 +263,28 @@ pub fn wasm_instantiate(
     })
 }
 
+/// Shortcut helper as the construction of WasmMsg::Instantiate2 can be quite verbose in contract code.
+///
+/// When using this, `admin` is always unset. If you need more flexibility, create the message directly.
+#[cfg(feature = "cosmwasm_1_2")]
+pub fn wasm_instantiate2(
+    code_id: u64,
+    msg: &impl Serialize,
+    funds: Vec<Coin>,
+    label: String,
+    salt: impl Into<Binary>,
+) -> StdResult<WasmMsg> {
+    let payload = to_binary(msg)?;
+    Ok(WasmMsg::Instantiate2 {
+        admin: None,
+        code_id,
+        label,
+        msg: payload,
+        funds,
+        salt: salt.into(),
+    })
+}
+
 /// Shortcut helper as the construction of WasmMsg::Instantiate can be quite verbose in contract code
 pub fn wasm_execute(
     contract_addr: impl Into<String>,
@@ -300,11 +356,166 @@ mod tests {
         }
     }
 
+    #[test]
+    fn msg_bank_send_serializes_to_correct_json() {
+        let msg = BankMsg::Send {
+            to_address: "you".to_string(),
+            amount: coins(1015, "earth"),
+        };
+        let json = to_binary(&msg).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"send":{"to_address":"you","amount":[{"denom":"earth","amount":"1015"}]}}"#,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "staking")]
+    fn msg_distribution_serializes_to_correct_json() {
+        // SetWithdrawAddress
+        let msg = DistributionMsg::SetWithdrawAddress {
+            address: "withdrawer".to_string(),
+        };
+        let json = to_binary(&msg).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"set_withdraw_address":{"address":"withdrawer"}}"#,
+        );
+
+        // WithdrawDelegatorReward
+        let msg = DistributionMsg::WithdrawDelegatorReward {
+            validator: "fancyoperator".to_string(),
+        };
+        let json = to_binary(&msg).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"withdraw_delegator_reward":{"validator":"fancyoperator"}}"#,
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "staking", feature = "cosmwasm_1_3"))]
+    fn msg_distribution_fund_community_pool_serializes_to_correct_json() {
+        let msg = DistributionMsg::FundCommunityPool {
+            amount: vec![coin(200, "feathers"), coin(200, "stones")],
+        };
+        let json = to_binary(&msg).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"fund_community_pool":{"amount":[{"denom":"feathers","amount":"200"},{"denom":"stones","amount":"200"}]}}"#,
+        );
+        let parsed: DistributionMsg = crate::from_binary(&json).unwrap();
+        assert_eq!(parsed, msg);
+    }
+
+    #[test]
+    #[cfg(all(feature = "staking", feature = "cosmwasm_1_3"))]
+    fn msg_distribution_deposit_validator_rewards_pool_serializes_to_correct_json() {
+        let msg = DistributionMsg::DepositValidatorRewardsPool {
+            validator: "cosmosvaloper1xyz".to_string(),
+            amount: coins(5, "ucosm"),
+        };
+        let json = to_binary(&msg).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"deposit_validator_rewards_pool":{"validator":"cosmosvaloper1xyz","amount":[{"denom":"ucosm","amount":"5"}]}}"#,
+        );
+        let parsed: DistributionMsg = crate::from_binary(&json).unwrap();
+        assert_eq!(parsed, msg);
+    }
+
+    #[test]
+    #[cfg(all(feature = "staking", feature = "cosmwasm_1_3"))]
+    fn msg_distribution_schema_contains_new_variants() {
+        let schema = schemars::schema_for!(DistributionMsg);
+        let json = serde_json::to_string(&schema).unwrap();
+        assert!(json.contains(r#""fund_community_pool""#));
+        assert!(json.contains(r#""deposit_validator_rewards_pool""#));
+    }
+
     #[cosmwasm_schema::cw_serde]
     enum ExecuteMsg {
         Mint { coin: Coin },
     }
 
+    #[test]
+    #[cfg(feature = "cosmwasm_1_2")]
+    fn wasm_msg_serializes_to_correct_json() {
+        // Instantiate with admin
+        let msg = WasmMsg::Instantiate2 {
+            admin: Some("king".to_string()),
+            code_id: 7897,
+            label: "my instance".to_string(),
+            msg: Binary::from_base64("eyJjbGFpbSI6e319").unwrap(),
+            funds: vec![],
+            salt: Binary::from_base64("UkOVazhiwoo=").unwrap(),
+        };
+        let json = to_binary(&msg).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"instantiate2":{"admin":"king","code_id":7897,"label":"my instance","msg":"eyJjbGFpbSI6e319","funds":[],"salt":"UkOVazhiwoo="}}"#,
+        );
+
+        // Instantiate without admin
+        let msg = WasmMsg::Instantiate2 {
+            admin: None,
+            code_id: 7897,
+            label: "my instance".to_string(),
+            msg: Binary::from_base64("eyJjbGFpbSI6e319").unwrap(),
+            funds: vec![],
+            salt: Binary::from_base64("UkOVazhiwoo=").unwrap(),
+        };
+        let json = to_binary(&msg).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"instantiate2":{"admin":null,"code_id":7897,"label":"my instance","msg":"eyJjbGFpbSI6e319","funds":[],"salt":"UkOVazhiwoo="}}"#,
+        );
+
+        // Instantiate with funds
+        let msg = WasmMsg::Instantiate2 {
+            admin: None,
+            code_id: 7897,
+            label: "my instance".to_string(),
+            msg: Binary::from_base64("eyJjbGFpbSI6e319").unwrap(),
+            funds: vec![coin(321, "stones")],
+            salt: Binary::from_base64("Zm9v").unwrap(),
+        };
+        let json = to_binary(&msg).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&json),
+            r#"{"instantiate2":{"admin":null,"code_id":7897,"label":"my instance","msg":"eyJjbGFpbSI6e319","funds":[{"denom":"stones","amount":"321"}],"salt":"Zm9v"}}"#,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "cosmwasm_1_2")]
+    fn wasm_instantiate2_works() {
+        let msg = wasm_instantiate2(
+            7897,
+            &ExecuteMsg::Mint {
+                coin: coin(10, "BTC"),
+            },
+            vec![coin(321, "stones")],
+            "my instance".to_string(),
+            b"salt".as_slice(),
+        )
+        .unwrap();
+        assert_eq!(
+            msg,
+            WasmMsg::Instantiate2 {
+                admin: None,
+                code_id: 7897,
+                label: "my instance".to_string(),
+                msg: to_binary(&ExecuteMsg::Mint {
+                    coin: coin(10, "BTC"),
+                })
+                .unwrap(),
+                funds: vec![coin(321, "stones")],
+                salt: Binary::from(b"salt"),
+            }
+        );
+    }
+
     #[test]
     fn wasm_msg_debug_decodes_binary_string_when_possible() {
         let msg = WasmMsg::Execute {
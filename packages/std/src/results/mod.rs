@@ -10,6 +10,8 @@ mod submessages;
 mod system_result;
 
 pub use contract_result::ContractResult;
+#[cfg(feature = "cosmwasm_1_2")]
+pub use cosmos_msg::wasm_instantiate2;
 #[cfg(all(feature = "stargate", feature = "cosmwasm_1_2"))]
 pub use cosmos_msg::WeightedVoteOption;
 pub use cosmos_msg::{wasm_execute, wasm_instantiate, BankMsg, CosmosMsg, CustomMsg, WasmMsg};
@@ -1,12 +1,20 @@
 use serde::de::DeserializeOwned;
 #[cfg(feature = "stargate")]
 use serde::Serialize;
+#[cfg(feature = "cosmwasm_1_3")]
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+#[cfg(all(feature = "stargate", feature = "cosmwasm_1_4"))]
+use std::collections::HashSet;
 use std::marker::PhantomData;
+#[cfg(feature = "cosmwasm_1_3")]
+use std::ops::Bound;
 
 use crate::addresses::{Addr, CanonicalAddr};
 use crate::binary::Binary;
 use crate::coin::Coin;
+#[cfg(feature = "cosmwasm_1_3")]
+use crate::deccoin::DecCoin;
 use crate::deps::OwnedDeps;
 use crate::errors::{RecoverPubkeyError, StdError, StdResult, SystemError, VerificationError};
 #[cfg(feature = "stargate")]
@@ -15,7 +23,13 @@ use crate::ibc::{
     IbcEndpoint, IbcOrder, IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg,
     IbcTimeoutBlock,
 };
+#[cfg(feature = "cosmwasm_1_3")]
+use crate::math::Decimal256;
 use crate::math::Uint128;
+#[cfg(feature = "cosmwasm_1_3")]
+use crate::metadata::DenomMetadata;
+#[cfg(feature = "cosmwasm_1_3")]
+use crate::pagination::{PageRequest, PageResponse};
 #[cfg(feature = "cosmwasm_1_1")]
 use crate::query::SupplyResponse;
 use crate::query::{
@@ -26,6 +40,19 @@ use crate::query::{
     AllDelegationsResponse, AllValidatorsResponse, BondedDenomResponse, DelegationResponse,
     FullDelegation, StakingQuery, Validator, ValidatorResponse,
 };
+#[cfg(feature = "cosmwasm_1_3")]
+use crate::query::{
+    AllDenomMetadataResponse, DelegationRewardsResponse, DelegationTotalRewardsResponse,
+    DelegatorReward, DelegatorValidatorsResponse, DelegatorWithdrawAddressResponse,
+    DenomMetadataResponse, DistributionQuery,
+};
+#[cfg(feature = "stargate")]
+use crate::query::{ChannelResponse, IbcQuery, ListChannelsResponse, PortIdResponse};
+#[cfg(all(feature = "stargate", feature = "cosmwasm_1_4"))]
+use crate::query::{
+    ConnectionResponse, CounterpartyResponse, FeeEnabledChannelResponse, IbcConnection,
+    NextSequenceSendResponse,
+};
 use crate::results::{ContractResult, Empty, SystemResult};
 use crate::serde::{from_slice, to_binary};
 use crate::storage::MemoryStorage;
@@ -419,13 +446,27 @@ pub fn mock_ibc_packet_timeout(
 /// cosmwasm-vm. It might diverge from QuerierResult at some point.
 pub type MockQuerierCustomHandlerResult = SystemResult<ContractResult<Binary>>;
 
+/// A handler for gRPC queries receiving the path and the protobuf encoded request.
+#[cfg(feature = "cosmwasm_1_4")]
+type GrpcHandler = Box<dyn for<'a> Fn(&'a str, &'a Binary) -> QuerierResult>;
+
 /// MockQuerier holds an immutable table of bank balances
 /// and configurable handlers for Wasm queries and custom queries.
 pub struct MockQuerier<C: DeserializeOwned = Empty> {
     bank: BankQuerier,
     #[cfg(feature = "staking")]
     staking: StakingQuerier,
+    #[cfg(feature = "cosmwasm_1_3")]
+    distribution: DistributionQuerier,
     wasm: WasmQuerier,
+    #[cfg(feature = "stargate")]
+    ibc: IbcQuerier,
+    /// A handler to handle gRPC queries. This is set to a dummy handler that
+    /// always errors by default. Update it via `update_grpc`.
+    ///
+    /// Use box to avoid the need of another generic type
+    #[cfg(feature = "cosmwasm_1_4")]
+    grpc_handler: GrpcHandler,
     /// A handler to handle custom queries. This is set to a dummy handler that
     /// always errors by default. Update it via `with_custom_handler`.
     ///
@@ -439,7 +480,17 @@ impl<C: DeserializeOwned> MockQuerier<C> {
             bank: BankQuerier::new(balances),
             #[cfg(feature = "staking")]
             staking: StakingQuerier::default(),
+            #[cfg(feature = "cosmwasm_1_3")]
+            distribution: DistributionQuerier::default(),
             wasm: WasmQuerier::default(),
+            #[cfg(feature = "stargate")]
+            ibc: IbcQuerier::default(),
+            #[cfg(feature = "cosmwasm_1_4")]
+            grpc_handler: Box::from(|_: &str, _: &Binary| -> QuerierResult {
+                SystemResult::Err(SystemError::UnsupportedRequest {
+                    kind: "Grpc".to_string(),
+                })
+            }),
             // strange argument notation suggested as a workaround here: https://github.com/rust-lang/rust/issues/41078#issuecomment-294296365
             custom_handler: Box::from(|_: &_| -> MockQuerierCustomHandlerResult {
                 SystemResult::Err(SystemError::UnsupportedRequest {
@@ -458,6 +509,12 @@ impl<C: DeserializeOwned> MockQuerier<C> {
         self.bank.update_balance(addr, balance)
     }
 
+    /// Replaces all denom metadata with the given entries
+    #[cfg(feature = "cosmwasm_1_3")]
+    pub fn set_denom_metadata(&mut self, denom_metadata: &[DenomMetadata]) {
+        self.bank.set_denom_metadata(denom_metadata)
+    }
+
     #[cfg(feature = "staking")]
     pub fn update_staking(
         &mut self,
@@ -468,6 +525,11 @@ impl<C: DeserializeOwned> MockQuerier<C> {
         self.staking = StakingQuerier::new(denom, validators, delegations);
     }
 
+    #[cfg(feature = "cosmwasm_1_3")]
+    pub fn update_distribution(&mut self, distribution: DistributionQuerier) {
+        self.distribution = distribution;
+    }
+
     pub fn update_wasm<WH: 'static>(&mut self, handler: WH)
     where
         WH: Fn(&WasmQuery) -> QuerierResult,
@@ -475,6 +537,21 @@ impl<C: DeserializeOwned> MockQuerier<C> {
         self.wasm.update_handler(handler)
     }
 
+    #[cfg(feature = "stargate")]
+    pub fn update_ibc(&mut self, ibc: IbcQuerier) {
+        self.ibc = ibc;
+    }
+
+    /// Sets the handler for `QueryRequest::Grpc`. It receives the path and the
+    /// protobuf encoded request and returns the protobuf encoded response.
+    #[cfg(feature = "cosmwasm_1_4")]
+    pub fn update_grpc<GH>(&mut self, handler: GH)
+    where
+        GH: Fn(&str, &Binary) -> QuerierResult + 'static,
+    {
+        self.grpc_handler = Box::from(handler)
+    }
+
     pub fn with_custom_handler<CH: 'static>(mut self, handler: CH) -> Self
     where
         CH: Fn(&C) -> MockQuerierCustomHandlerResult,
@@ -512,15 +589,19 @@ impl<C: CustomQuery + DeserializeOwned> MockQuerier<C> {
             QueryRequest::Custom(custom_query) => (*self.custom_handler)(custom_query),
             #[cfg(feature = "staking")]
             QueryRequest::Staking(staking_query) => self.staking.query(staking_query),
+            #[cfg(feature = "cosmwasm_1_3")]
+            QueryRequest::Distribution(distribution_query) => {
+                self.distribution.query(distribution_query)
+            }
             QueryRequest::Wasm(msg) => self.wasm.query(msg),
             #[cfg(feature = "stargate")]
             QueryRequest::Stargate { .. } => SystemResult::Err(SystemError::UnsupportedRequest {
                 kind: "Stargate".to_string(),
             }),
             #[cfg(feature = "stargate")]
-            QueryRequest::Ibc(_) => SystemResult::Err(SystemError::UnsupportedRequest {
-                kind: "Ibc".to_string(),
-            }),
+            QueryRequest::Ibc(ibc_query) => self.ibc.query(ibc_query),
+            #[cfg(feature = "cosmwasm_1_4")]
+            QueryRequest::Grpc { path, data } => (*self.grpc_handler)(path, data),
         }
     }
 }
@@ -557,6 +638,11 @@ impl Default for WasmQuerier {
                 WasmQuery::Smart { contract_addr, .. } => contract_addr,
                 WasmQuery::Raw { contract_addr, .. } => contract_addr,
                 WasmQuery::ContractInfo { contract_addr, .. } => contract_addr,
+                #[cfg(feature = "cosmwasm_1_2")]
+                WasmQuery::CodeInfo { code_id, .. } => {
+                    let err = SystemError::NoSuchCode { code_id: *code_id };
+                    return SystemResult::Err(err);
+                }
             }
             .clone();
             SystemResult::Err(SystemError::NoSuchContract { addr })
@@ -572,6 +658,11 @@ pub struct BankQuerier {
     supplies: HashMap<String, Uint128>,
     /// HashMap<address, coins>
     balances: HashMap<String, Vec<Coin>>,
+    /// BTreeMap<base denom, metadata>
+    ///
+    /// Sorted by denom to allow pagination.
+    #[cfg(feature = "cosmwasm_1_3")]
+    denom_metadata: BTreeMap<String, DenomMetadata>,
 }
 
 impl BankQuerier {
@@ -584,9 +675,20 @@ impl BankQuerier {
         BankQuerier {
             supplies: Self::calculate_supplies(&balances),
             balances,
+            #[cfg(feature = "cosmwasm_1_3")]
+            denom_metadata: BTreeMap::new(),
         }
     }
 
+    /// Replaces all denom metadata with the given entries
+    #[cfg(feature = "cosmwasm_1_3")]
+    pub fn set_denom_metadata(&mut self, denom_metadata: &[DenomMetadata]) {
+        self.denom_metadata = denom_metadata
+            .iter()
+            .map(|metadata| (metadata.base.clone(), metadata.clone()))
+            .collect();
+    }
+
     pub fn update_balance(
         &mut self,
         addr: impl Into<String>,
@@ -651,6 +753,67 @@ impl BankQuerier {
                 };
                 to_binary(&bank_res).into()
             }
+            #[cfg(feature = "cosmwasm_1_3")]
+            BankQuery::DenomMetadata { denom } => match self.denom_metadata.get(denom) {
+                Some(metadata) => {
+                    let bank_res = DenomMetadataResponse {
+                        metadata: metadata.clone(),
+                    };
+                    to_binary(&bank_res).into()
+                }
+                None => ContractResult::Err(format!("denom metadata not found: {}", denom)),
+            },
+            #[cfg(feature = "cosmwasm_1_3")]
+            BankQuery::AllDenomMetadata { pagination } => {
+                let default_pagination = PageRequest {
+                    key: None,
+                    limit: 100,
+                    reverse: false,
+                };
+                let pagination = pagination.as_ref().unwrap_or(&default_pagination);
+                // like in the SDK, a limit of 0 means the default limit
+                let limit = match pagination.limit {
+                    0 => default_pagination.limit,
+                    limit => limit,
+                };
+
+                // the key is inclusive, i.e. it is the first entry of the page
+                let key = pagination
+                    .key
+                    .as_ref()
+                    .map(|key| String::from_utf8_lossy(key).into_owned());
+                let range = match (pagination.reverse, key) {
+                    (_, None) => (Bound::Unbounded, Bound::Unbounded),
+                    (true, Some(key)) => (Bound::Unbounded, Bound::Included(key)),
+                    (false, Some(key)) => (Bound::Included(key), Bound::Unbounded),
+                };
+                let iter = self.denom_metadata.range(range);
+                // dynamic dispatch is fine here since this is only testing code
+                let iter: Box<dyn Iterator<Item = _>> = if pagination.reverse {
+                    Box::new(iter.rev())
+                } else {
+                    Box::new(iter)
+                };
+
+                // take one more element than requested to find the next key
+                let mut metadata: Vec<_> = iter
+                    .take(limit.saturating_add(1) as usize)
+                    .map(|(_, metadata)| metadata.clone())
+                    .collect();
+                let next_key = if metadata.len() > limit as usize {
+                    metadata
+                        .pop()
+                        .map(|metadata| Binary::from(metadata.base.as_bytes()))
+                } else {
+                    None
+                };
+
+                let bank_res = AllDenomMetadataResponse {
+                    metadata,
+                    pagination: PageResponse { next_key },
+                };
+                to_binary(&bank_res).into()
+            }
         };
         // system result is always ok in the mock implementation
         SystemResult::Ok(contract_result)
@@ -728,6 +891,290 @@ impl StakingQuerier {
     }
 }
 
+#[cfg(feature = "cosmwasm_1_3")]
+#[derive(Clone, Default)]
+pub struct DistributionQuerier {
+    /// HashMap<delegator, withdraw address>
+    withdraw_addresses: HashMap<String, String>,
+    /// BTreeMap<delegator, BTreeMap<validator, rewards>>
+    ///
+    /// A delegator is considered to be bonded to all validators it has a rewards entry for.
+    rewards: BTreeMap<String, BTreeMap<String, Vec<DecCoin>>>,
+}
+
+#[cfg(feature = "cosmwasm_1_3")]
+impl DistributionQuerier {
+    pub fn new(withdraw_addresses: &[(&str, &str)]) -> Self {
+        DistributionQuerier {
+            withdraw_addresses: withdraw_addresses
+                .iter()
+                .map(|(delegator, withdraw)| (delegator.to_string(), withdraw.to_string()))
+                .collect(),
+            rewards: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the withdraw address of the given delegator and returns the previous one (if any)
+    pub fn set_withdraw_address(
+        &mut self,
+        delegator_address: impl Into<String>,
+        withdraw_address: impl Into<String>,
+    ) -> Option<String> {
+        self.withdraw_addresses
+            .insert(delegator_address.into(), withdraw_address.into())
+    }
+
+    /// Sets the accumulated rewards of the delegation from `delegator_address` to `validator_address`
+    /// and returns the previous ones (if any)
+    pub fn set_rewards(
+        &mut self,
+        validator_address: impl Into<String>,
+        delegator_address: impl Into<String>,
+        rewards: Vec<DecCoin>,
+    ) -> Option<Vec<DecCoin>> {
+        self.rewards
+            .entry(delegator_address.into())
+            .or_default()
+            .insert(validator_address.into(), rewards)
+    }
+
+    pub fn query(&self, request: &DistributionQuery) -> QuerierResult {
+        let contract_result: ContractResult<Binary> = match request {
+            DistributionQuery::DelegatorWithdrawAddress { delegator_address } => {
+                let withdraw_address = self
+                    .withdraw_addresses
+                    .get(delegator_address)
+                    .unwrap_or(delegator_address);
+                let res = DelegatorWithdrawAddressResponse {
+                    withdraw_address: Addr::unchecked(withdraw_address),
+                };
+                to_binary(&res).into()
+            }
+            DistributionQuery::DelegationRewards {
+                delegator_address,
+                validator_address,
+            } => {
+                let rewards = self
+                    .rewards
+                    .get(delegator_address)
+                    .and_then(|by_validator| by_validator.get(validator_address))
+                    .cloned()
+                    .unwrap_or_default();
+                let res = DelegationRewardsResponse { rewards };
+                to_binary(&res).into()
+            }
+            DistributionQuery::DelegationTotalRewards { delegator_address } => {
+                let rewards: Vec<_> = self
+                    .rewards
+                    .get(delegator_address)
+                    .map(|by_validator| {
+                        by_validator
+                            .iter()
+                            .map(|(validator, reward)| DelegatorReward {
+                                validator_address: validator.clone(),
+                                reward: reward.clone(),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let mut total = BTreeMap::<&str, Decimal256>::new();
+                for coin in rewards.iter().flat_map(|r| &r.reward) {
+                    *total.entry(&coin.denom).or_default() += coin.amount;
+                }
+                let res = DelegationTotalRewardsResponse {
+                    total: total
+                        .into_iter()
+                        .map(|(denom, amount)| DecCoin::new(amount, denom))
+                        .collect(),
+                    rewards,
+                };
+                to_binary(&res).into()
+            }
+            DistributionQuery::DelegatorValidators { delegator_address } => {
+                let validators = self
+                    .rewards
+                    .get(delegator_address)
+                    .map(|by_validator| by_validator.keys().cloned().collect())
+                    .unwrap_or_default();
+                let res = DelegatorValidatorsResponse { validators };
+                to_binary(&res).into()
+            }
+        };
+        // system result is always ok in the mock implementation
+        SystemResult::Ok(contract_result)
+    }
+}
+
+#[cfg(feature = "stargate")]
+#[derive(Clone, Default)]
+pub struct IbcQuerier {
+    /// The port the contract is bound to
+    port_id: String,
+    channels: Vec<IbcChannel>,
+    /// HashMap<connection id, connection>
+    #[cfg(feature = "cosmwasm_1_4")]
+    connections: HashMap<String, IbcConnection>,
+    /// HashMap<connection id, counterparty>
+    #[cfg(feature = "cosmwasm_1_4")]
+    counterparties: HashMap<String, CounterpartyResponse>,
+    /// HashMap<(port id, channel id), next sequence>
+    ///
+    /// Channels without an entry start at sequence 1.
+    #[cfg(feature = "cosmwasm_1_4")]
+    next_sequences: HashMap<(String, String), u64>,
+    /// HashSet<(port id, channel id)>
+    #[cfg(feature = "cosmwasm_1_4")]
+    fee_enabled_channels: HashSet<(String, String)>,
+}
+
+#[cfg(feature = "stargate")]
+impl IbcQuerier {
+    /// Create a mock querier where the contract is bound to `port_id`
+    /// and the given channels exist
+    pub fn new(port_id: &str, channels: &[IbcChannel]) -> Self {
+        IbcQuerier {
+            port_id: port_id.to_string(),
+            channels: channels.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the given connection and returns the previous one with the same ID (if any)
+    #[cfg(feature = "cosmwasm_1_4")]
+    pub fn set_connection(&mut self, connection: IbcConnection) -> Option<IbcConnection> {
+        self.connections
+            .insert(connection.connection_id.clone(), connection)
+    }
+
+    /// Sets the counterparty client ID and chain ID of the given connection
+    #[cfg(feature = "cosmwasm_1_4")]
+    pub fn set_counterparty(
+        &mut self,
+        connection_id: impl Into<String>,
+        client_id: impl Into<String>,
+        chain_id: impl Into<String>,
+    ) {
+        self.counterparties.insert(
+            connection_id.into(),
+            CounterpartyResponse {
+                client_id: client_id.into(),
+                chain_id: chain_id.into(),
+            },
+        );
+    }
+
+    /// Sets the sequence of the next packet sent over the given channel
+    #[cfg(feature = "cosmwasm_1_4")]
+    pub fn set_next_sequence_send(
+        &mut self,
+        port_id: impl Into<String>,
+        channel_id: impl Into<String>,
+        sequence: u64,
+    ) {
+        self.next_sequences
+            .insert((port_id.into(), channel_id.into()), sequence);
+    }
+
+    /// Sets whether the given channel supports the ICS-29 fee middleware
+    #[cfg(feature = "cosmwasm_1_4")]
+    pub fn set_fee_enabled(
+        &mut self,
+        port_id: impl Into<String>,
+        channel_id: impl Into<String>,
+        fee_enabled: bool,
+    ) {
+        let key = (port_id.into(), channel_id.into());
+        if fee_enabled {
+            self.fee_enabled_channels.insert(key);
+        } else {
+            self.fee_enabled_channels.remove(&key);
+        }
+    }
+
+    fn find_channel(&self, channel_id: &str, port_id: &str) -> Option<&IbcChannel> {
+        self.channels
+            .iter()
+            .find(|c| c.endpoint.channel_id == channel_id && c.endpoint.port_id == port_id)
+    }
+
+    pub fn query(&self, request: &IbcQuery) -> QuerierResult {
+        let contract_result: ContractResult<Binary> = match request {
+            IbcQuery::PortId {} => {
+                let res = PortIdResponse {
+                    port_id: self.port_id.clone(),
+                };
+                to_binary(&res).into()
+            }
+            IbcQuery::ListChannels { port_id } => {
+                let port_id = port_id.as_ref().unwrap_or(&self.port_id);
+                let channels = self
+                    .channels
+                    .iter()
+                    .filter(|c| &c.endpoint.port_id == port_id)
+                    .cloned()
+                    .collect();
+                let res = ListChannelsResponse { channels };
+                to_binary(&res).into()
+            }
+            IbcQuery::Channel {
+                channel_id,
+                port_id,
+            } => {
+                let port_id = port_id.as_ref().unwrap_or(&self.port_id);
+                let channel = self.find_channel(channel_id, port_id).cloned();
+                let res = ChannelResponse { channel };
+                to_binary(&res).into()
+            }
+            #[cfg(feature = "cosmwasm_1_4")]
+            IbcQuery::Connection { connection_id } => {
+                let connection = self.connections.get(connection_id).cloned();
+                let res = ConnectionResponse { connection };
+                to_binary(&res).into()
+            }
+            #[cfg(feature = "cosmwasm_1_4")]
+            IbcQuery::Counterparty { connection_id } => {
+                match self.counterparties.get(connection_id) {
+                    Some(res) => to_binary(res).into(),
+                    None => ContractResult::Err(format!("connection not found: {}", connection_id)),
+                }
+            }
+            #[cfg(feature = "cosmwasm_1_4")]
+            IbcQuery::NextSequenceSend {
+                channel_id,
+                port_id,
+            } => {
+                let port_id = port_id.as_ref().unwrap_or(&self.port_id);
+                let key = (port_id.clone(), channel_id.clone());
+                let sequence = match self.next_sequences.get(&key) {
+                    Some(sequence) => Some(*sequence),
+                    None => self.find_channel(channel_id, port_id).map(|_| 1),
+                };
+                match sequence {
+                    Some(sequence) => to_binary(&NextSequenceSendResponse { sequence }).into(),
+                    None => ContractResult::Err(format!(
+                        "channel not found: {}/{}",
+                        port_id, channel_id
+                    )),
+                }
+            }
+            #[cfg(feature = "cosmwasm_1_4")]
+            IbcQuery::FeeEnabledChannel {
+                channel_id,
+                port_id,
+            } => {
+                let port_id = port_id.as_ref().unwrap_or(&self.port_id);
+                let key = (port_id.clone(), channel_id.clone());
+                let res = FeeEnabledChannelResponse {
+                    fee_enabled: self.fee_enabled_channels.contains(&key),
+                };
+                to_binary(&res).into()
+            }
+        };
+        // system result is always ok in the mock implementation
+        SystemResult::Ok(contract_result)
+    }
+}
+
 /// Performs a perfect shuffle (in shuffle)
 ///
 /// https://en.wikipedia.org/wiki/Riffle_shuffle_permutation#Perfect_shuffles
@@ -1222,6 +1669,114 @@ mod tests {
         assert_eq!(res.amount, coin(0, "ELF"));
     }
 
+    #[cfg(feature = "cosmwasm_1_3")]
+    #[test]
+    fn bank_querier_denom_metadata() {
+        use crate::DenomUnit;
+
+        let mut bank = BankQuerier::new(&[]);
+        let metadata = |base: &str| DenomMetadata {
+            description: format!("The {} token", base),
+            denom_units: vec![
+                DenomUnit {
+                    denom: base.to_string(),
+                    exponent: 0,
+                    aliases: vec![],
+                },
+                DenomUnit {
+                    denom: base[1..].to_string(),
+                    exponent: 6,
+                    aliases: vec![],
+                },
+            ],
+            base: base.to_string(),
+            display: base[1..].to_string(),
+            name: base[1..].to_uppercase(),
+            symbol: base[1..].to_uppercase(),
+            uri: "".to_string(),
+            uri_hash: "".to_string(),
+        };
+        bank.set_denom_metadata(&[metadata("ufoo"), metadata("ubar")]);
+
+        // found
+        let res = bank
+            .query(&BankQuery::DenomMetadata {
+                denom: "ufoo".to_string(),
+            })
+            .unwrap()
+            .unwrap();
+        let res: DenomMetadataResponse = from_binary(&res).unwrap();
+        assert_eq!(res.metadata, metadata("ufoo"));
+
+        // not found
+        let res = bank
+            .query(&BankQuery::DenomMetadata {
+                denom: "uother".to_string(),
+            })
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(res, "denom metadata not found: uother");
+    }
+
+    #[cfg(feature = "cosmwasm_1_3")]
+    #[test]
+    fn bank_querier_all_denom_metadata_pagination() {
+        let mut bank = BankQuerier::new(&[]);
+        let all: Vec<_> = (0..10)
+            .map(|i| DenomMetadata {
+                base: format!("ucoin{}", i),
+                ..Default::default()
+            })
+            .collect();
+        bank.set_denom_metadata(&all);
+
+        let query_page = |key: Option<Binary>, limit: u32, reverse: bool| {
+            let res = bank
+                .query(&BankQuery::AllDenomMetadata {
+                    pagination: Some(PageRequest {
+                        key,
+                        limit,
+                        reverse,
+                    }),
+                })
+                .unwrap()
+                .unwrap();
+            from_binary::<AllDenomMetadataResponse>(&res).unwrap()
+        };
+
+        // no pagination returns everything
+        let res = bank
+            .query(&BankQuery::AllDenomMetadata { pagination: None })
+            .unwrap()
+            .unwrap();
+        let res: AllDenomMetadataResponse = from_binary(&res).unwrap();
+        assert_eq!(res.metadata, all);
+        assert_eq!(res.pagination.next_key, None);
+
+        // forward in pages of 4
+        let page1 = query_page(None, 4, false);
+        assert_eq!(page1.metadata, all[0..4]);
+        let page2 = query_page(page1.pagination.next_key, 4, false);
+        assert_eq!(page2.metadata, all[4..8]);
+        let page3 = query_page(page2.pagination.next_key, 4, false);
+        assert_eq!(page3.metadata, all[8..10]);
+        assert_eq!(page3.pagination.next_key, None);
+
+        // reverse in pages of 6
+        let page1 = query_page(None, 6, true);
+        let expected: Vec<_> = all[4..10].iter().rev().cloned().collect();
+        assert_eq!(page1.metadata, expected);
+        let page2 = query_page(page1.pagination.next_key, 6, true);
+        let expected: Vec<_> = all[0..4].iter().rev().cloned().collect();
+        assert_eq!(page2.metadata, expected);
+        assert_eq!(page2.pagination.next_key, None);
+
+        // a limit of 0 uses the default limit
+        let page = query_page(None, 0, false);
+        assert_eq!(page.metadata, all);
+        assert_eq!(page.pagination.next_key, None);
+    }
+
     #[cfg(feature = "staking")]
     #[test]
     fn staking_querier_all_validators() {
@@ -1417,6 +1972,297 @@ mod tests {
         assert_eq!(dels, Some(del2c));
     }
 
+    #[cfg(feature = "cosmwasm_1_3")]
+    #[test]
+    fn distribution_querier_delegator_withdraw_address() {
+        let mut distribution = DistributionQuerier::new(&[("addr0", "withdraw0")]);
+
+        let query = DistributionQuery::DelegatorWithdrawAddress {
+            delegator_address: "addr0".to_string(),
+        };
+        let res = distribution.query(&query).unwrap().unwrap();
+        let res: DelegatorWithdrawAddressResponse = from_binary(&res).unwrap();
+        assert_eq!(res.withdraw_address, "withdraw0");
+
+        // defaults to the delegator address
+        let query = DistributionQuery::DelegatorWithdrawAddress {
+            delegator_address: "addr1".to_string(),
+        };
+        let res = distribution.query(&query).unwrap().unwrap();
+        let res: DelegatorWithdrawAddressResponse = from_binary(&res).unwrap();
+        assert_eq!(res.withdraw_address, "addr1");
+
+        // can be updated
+        let previous = distribution.set_withdraw_address("addr0", "withdraw1");
+        assert_eq!(previous.as_deref(), Some("withdraw0"));
+        let query = DistributionQuery::DelegatorWithdrawAddress {
+            delegator_address: "addr0".to_string(),
+        };
+        let res = distribution.query(&query).unwrap().unwrap();
+        let res: DelegatorWithdrawAddressResponse = from_binary(&res).unwrap();
+        assert_eq!(res.withdraw_address, "withdraw1");
+    }
+
+    #[cfg(feature = "cosmwasm_1_3")]
+    #[test]
+    fn distribution_querier_rewards_and_validators() {
+        let mut distribution = DistributionQuerier::default();
+        distribution.set_rewards(
+            "valoper0",
+            "addr0",
+            vec![
+                DecCoin::new(Decimal256::percent(1234), "ucosm"),
+                DecCoin::new(Decimal256::percent(1), "ustake"),
+            ],
+        );
+        distribution.set_rewards(
+            "valoper1",
+            "addr0",
+            vec![DecCoin::new(Decimal256::percent(66), "ucosm")],
+        );
+        distribution.set_rewards(
+            "valoper1",
+            "addr1",
+            vec![DecCoin::new(Decimal256::percent(5), "ucosm")],
+        );
+
+        // single delegation
+        let query = DistributionQuery::DelegationRewards {
+            delegator_address: "addr0".to_string(),
+            validator_address: "valoper1".to_string(),
+        };
+        let res = distribution.query(&query).unwrap().unwrap();
+        let res: DelegationRewardsResponse = from_binary(&res).unwrap();
+        assert_eq!(
+            res.rewards,
+            vec![DecCoin::new(Decimal256::percent(66), "ucosm")]
+        );
+
+        // unknown delegation
+        let query = DistributionQuery::DelegationRewards {
+            delegator_address: "addr1".to_string(),
+            validator_address: "valoper0".to_string(),
+        };
+        let res = distribution.query(&query).unwrap().unwrap();
+        let res: DelegationRewardsResponse = from_binary(&res).unwrap();
+        assert_eq!(res.rewards, vec![]);
+
+        // total
+        let query = DistributionQuery::DelegationTotalRewards {
+            delegator_address: "addr0".to_string(),
+        };
+        let res = distribution.query(&query).unwrap().unwrap();
+        let res: DelegationTotalRewardsResponse = from_binary(&res).unwrap();
+        assert_eq!(
+            res.rewards,
+            vec![
+                DelegatorReward {
+                    validator_address: "valoper0".to_string(),
+                    reward: vec![
+                        DecCoin::new(Decimal256::percent(1234), "ucosm"),
+                        DecCoin::new(Decimal256::percent(1), "ustake"),
+                    ],
+                },
+                DelegatorReward {
+                    validator_address: "valoper1".to_string(),
+                    reward: vec![DecCoin::new(Decimal256::percent(66), "ucosm")],
+                },
+            ]
+        );
+        assert_eq!(
+            res.total,
+            vec![
+                DecCoin::new(Decimal256::percent(1300), "ucosm"),
+                DecCoin::new(Decimal256::percent(1), "ustake"),
+            ]
+        );
+
+        // validators
+        let query = DistributionQuery::DelegatorValidators {
+            delegator_address: "addr0".to_string(),
+        };
+        let res = distribution.query(&query).unwrap().unwrap();
+        let res: DelegatorValidatorsResponse = from_binary(&res).unwrap();
+        assert_eq!(res.validators, vec!["valoper0", "valoper1"]);
+
+        let query = DistributionQuery::DelegatorValidators {
+            delegator_address: "addr2".to_string(),
+        };
+        let res = distribution.query(&query).unwrap().unwrap();
+        let res: DelegatorValidatorsResponse = from_binary(&res).unwrap();
+        assert_eq!(res.validators, Vec::<String>::new());
+    }
+
+    #[cfg(feature = "stargate")]
+    #[test]
+    fn ibc_querier_channels() {
+        let chan1 = mock_ibc_channel("channel-0", IbcOrder::Ordered, "ibc");
+        let chan2 = mock_ibc_channel("channel-1", IbcOrder::Ordered, "ibc");
+        let ibc = IbcQuerier::new("my_port", &[chan1.clone(), chan2.clone()]);
+
+        let res = ibc.query(&IbcQuery::PortId {}).unwrap().unwrap();
+        let res: PortIdResponse = from_binary(&res).unwrap();
+        assert_eq!(res.port_id, "my_port");
+
+        // defaults to the contract's port
+        let query = IbcQuery::ListChannels { port_id: None };
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: ListChannelsResponse = from_binary(&res).unwrap();
+        assert_eq!(res.channels, vec![chan1.clone(), chan2]);
+
+        let query = IbcQuery::ListChannels {
+            port_id: Some("other_port".to_string()),
+        };
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: ListChannelsResponse = from_binary(&res).unwrap();
+        assert_eq!(res.channels, vec![]);
+
+        let query = IbcQuery::Channel {
+            channel_id: "channel-0".to_string(),
+            port_id: None,
+        };
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: ChannelResponse = from_binary(&res).unwrap();
+        assert_eq!(res.channel, Some(chan1));
+
+        let query = IbcQuery::Channel {
+            channel_id: "channel-2".to_string(),
+            port_id: None,
+        };
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: ChannelResponse = from_binary(&res).unwrap();
+        assert_eq!(res.channel, None);
+    }
+
+    #[cfg(all(feature = "stargate", feature = "cosmwasm_1_4"))]
+    #[test]
+    fn ibc_querier_connections() {
+        let mut ibc = IbcQuerier::default();
+        let connection = IbcConnection {
+            connection_id: "connection-2".to_string(),
+            client_id: "07-tendermint-0".to_string(),
+            counterparty_client_id: "07-tendermint-5".to_string(),
+            counterparty_connection_id: Some("connection-9".to_string()),
+            delay_period: 0,
+        };
+        assert_eq!(ibc.set_connection(connection.clone()), None);
+        ibc.set_counterparty("connection-2", "07-tendermint-5", "theta-testnet-001");
+
+        let query = IbcQuery::Connection {
+            connection_id: "connection-2".to_string(),
+        };
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: ConnectionResponse = from_binary(&res).unwrap();
+        assert_eq!(res.connection, Some(connection));
+
+        let query = IbcQuery::Connection {
+            connection_id: "connection-3".to_string(),
+        };
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: ConnectionResponse = from_binary(&res).unwrap();
+        assert_eq!(res.connection, None);
+
+        let query = IbcQuery::Counterparty {
+            connection_id: "connection-2".to_string(),
+        };
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: CounterpartyResponse = from_binary(&res).unwrap();
+        assert_eq!(res.client_id, "07-tendermint-5");
+        assert_eq!(res.chain_id, "theta-testnet-001");
+
+        let query = IbcQuery::Counterparty {
+            connection_id: "connection-3".to_string(),
+        };
+        let err = ibc.query(&query).unwrap().unwrap_err();
+        assert_eq!(err, "connection not found: connection-3");
+    }
+
+    #[cfg(all(feature = "stargate", feature = "cosmwasm_1_4"))]
+    #[test]
+    fn ibc_querier_next_sequence_send_and_fee_enabled() {
+        let chan = mock_ibc_channel("channel-0", IbcOrder::Unordered, "ics20-1");
+        let mut ibc = IbcQuerier::new("my_port", &[chan]);
+
+        // known channels start at 1
+        let query = IbcQuery::NextSequenceSend {
+            channel_id: "channel-0".to_string(),
+            port_id: None,
+        };
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: NextSequenceSendResponse = from_binary(&res).unwrap();
+        assert_eq!(res.sequence, 1);
+
+        ibc.set_next_sequence_send("my_port", "channel-0", 42);
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: NextSequenceSendResponse = from_binary(&res).unwrap();
+        assert_eq!(res.sequence, 42);
+
+        let query = IbcQuery::NextSequenceSend {
+            channel_id: "channel-0".to_string(),
+            port_id: Some("transfer".to_string()),
+        };
+        let err = ibc.query(&query).unwrap().unwrap_err();
+        assert_eq!(err, "channel not found: transfer/channel-0");
+
+        let query = IbcQuery::FeeEnabledChannel {
+            channel_id: "channel-0".to_string(),
+            port_id: None,
+        };
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: FeeEnabledChannelResponse = from_binary(&res).unwrap();
+        assert!(!res.fee_enabled);
+
+        ibc.set_fee_enabled("my_port", "channel-0", true);
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: FeeEnabledChannelResponse = from_binary(&res).unwrap();
+        assert!(res.fee_enabled);
+
+        ibc.set_fee_enabled("my_port", "channel-0", false);
+        let res = ibc.query(&query).unwrap().unwrap();
+        let res: FeeEnabledChannelResponse = from_binary(&res).unwrap();
+        assert!(!res.fee_enabled);
+    }
+
+    #[cfg(feature = "stargate")]
+    #[test]
+    fn mock_querier_handles_ibc_queries() {
+        let mut querier: MockQuerier = MockQuerier::new(&[]);
+        querier.update_ibc(IbcQuerier::new("wasm.cosmos2contract", &[]));
+
+        let res = querier
+            .handle_query(&IbcQuery::PortId {}.into())
+            .unwrap()
+            .unwrap();
+        let res: PortIdResponse = from_binary(&res).unwrap();
+        assert_eq!(res.port_id, "wasm.cosmos2contract");
+    }
+
+    #[cfg(feature = "cosmwasm_1_4")]
+    #[test]
+    fn mock_querier_grpc_works() {
+        let mut querier: MockQuerier = MockQuerier::default();
+        let request = QueryRequest::Grpc {
+            path: "/cosmos.bank.v1beta1.Query/Balance".to_string(),
+            data: Binary::from([0x0a, 0x03, 0x66, 0x6f, 0x6f]),
+        };
+
+        // unsupported by default
+        match querier.handle_query(&request) {
+            SystemResult::Err(SystemError::UnsupportedRequest { kind }) => {
+                assert_eq!(kind, "Grpc")
+            }
+            res => panic!("Unexpected result: {:?}", res),
+        }
+
+        querier.update_grpc(|path, data| {
+            assert_eq!(path, "/cosmos.bank.v1beta1.Query/Balance");
+            assert_eq!(data.as_slice(), [0x0a, 0x03, 0x66, 0x6f, 0x6f]);
+            SystemResult::Ok(ContractResult::Ok(Binary::from([0x12, 0x00])))
+        });
+        let response = querier.handle_query(&request).unwrap().unwrap();
+        assert_eq!(response, Binary::from([0x12, 0x00]));
+    }
+
     #[test]
     fn wasm_querier_works() {
         let mut querier = WasmQuerier::default();
@@ -1458,6 +2304,18 @@ mod tests {
             err => panic!("Unexpected error: {:?}", err),
         }
 
+        // Query WasmQuery::CodeInfo
+        #[cfg(feature = "cosmwasm_1_2")]
+        {
+            let system_err = querier
+                .query(&WasmQuery::CodeInfo { code_id: 4 })
+                .unwrap_err();
+            match system_err {
+                SystemError::NoSuchCode { code_id } => assert_eq!(code_id, 4),
+                err => panic!("Unexpected error: {:?}", err),
+            }
+        }
+
         querier.update_handler(|request| {
             let constract1 = Addr::unchecked("contract1");
             let mut storage1 = HashMap::<Binary, Binary>::default();
@@ -1511,6 +2369,24 @@ mod tests {
                         })
                     }
                 }
+                #[cfg(feature = "cosmwasm_1_2")]
+                WasmQuery::CodeInfo { code_id } => {
+                    use crate::{CodeInfoResponse, HexBinary};
+                    let code_id = *code_id;
+                    if code_id == 4 {
+                        let response = CodeInfoResponse {
+                            code_id,
+                            creator: "lalala".into(),
+                            checksum: HexBinary::from_hex(
+                                "84cf20810fd429caf58898c3210fcb71759a27becddae08dbde8668ea2f4725d",
+                            )
+                            .unwrap(),
+                        };
+                        SystemResult::Ok(ContractResult::Ok(to_binary(&response).unwrap()))
+                    } else {
+                        SystemResult::Err(SystemError::NoSuchCode { code_id })
+                    }
+                }
             }
         });
 
@@ -1567,6 +2443,24 @@ mod tests {
             ),
             res => panic!("Unexpected result: {:?}", res),
         }
+
+        // WasmQuery::CodeInfo
+        #[cfg(feature = "cosmwasm_1_2")]
+        {
+            let result = querier.query(&WasmQuery::CodeInfo { code_id: 4 });
+            match result {
+                SystemResult::Ok(ContractResult::Ok(value)) => assert_eq!(
+                    value,
+                    br#"{"code_id":4,"creator":"lalala","checksum":"84cf20810fd429caf58898c3210fcb71759a27becddae08dbde8668ea2f4725d"}"#
+                ),
+                res => panic!("Unexpected result: {:?}", res),
+            }
+            let result = querier.query(&WasmQuery::CodeInfo { code_id: 77 });
+            match result {
+                SystemResult::Err(SystemError::NoSuchCode { code_id }) => assert_eq!(code_id, 77),
+                res => panic!("Unexpected result: {:?}", res),
+            }
+        }
     }
 
     #[test]
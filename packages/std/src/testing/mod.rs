@@ -8,6 +8,10 @@ mod mock;
 
 pub use assertions::assert_approx_eq_impl;
 
+#[cfg(feature = "cosmwasm_1_3")]
+pub use mock::DistributionQuerier;
+#[cfg(feature = "stargate")]
+pub use mock::IbcQuerier;
 #[cfg(feature = "staking")]
 pub use mock::StakingQuerier;
 pub use mock::{
@@ -1,8 +1,11 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
 
+use crate::errors::{OverflowError, OverflowOperation};
 use crate::math::Uint64;
+use crate::Duration;
 
 /// A point in time in nanosecond precision.
 ///
@@ -74,6 +77,95 @@ impl Timestamp {
     pub fn subsec_nanos(&self) -> u64 {
         self.0.u64() % 1_000_000_000
     }
+
+    /// Adds the given duration, returning an error if the result is after the
+    /// maximum representable timestamp.
+    pub fn checked_add(self, duration: Duration) -> Result<Self, OverflowError> {
+        self.0
+            .checked_add(Uint64::new(duration.nanos()))
+            .map(Timestamp)
+            .map_err(|_| OverflowError::new(OverflowOperation::Add, self, duration))
+    }
+
+    /// Subtracts the given duration, returning an error if the result is before
+    /// the epoch.
+    pub fn checked_sub(self, duration: Duration) -> Result<Self, OverflowError> {
+        self.0
+            .checked_sub(Uint64::new(duration.nanos()))
+            .map(Timestamp)
+            .map_err(|_| OverflowError::new(OverflowOperation::Sub, self, duration))
+    }
+
+    /// Returns the duration that passed from `earlier` to `self`,
+    /// or an error if `earlier` is after `self`.
+    pub fn checked_duration_since(self, earlier: Timestamp) -> Result<Duration, OverflowError> {
+        self.0
+            .checked_sub(earlier.0)
+            .map(|nanos| Duration::from_nanos(nanos.u64()))
+            .map_err(|_| OverflowError::new(OverflowOperation::Sub, self, earlier))
+    }
+
+    /// Returns the duration between two timestamps, regardless of their order.
+    pub fn abs_diff(self, other: Timestamp) -> Duration {
+        if self >= other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    /// Returns true if `self` is strictly before `other`.
+    #[inline]
+    pub fn is_before(&self, other: &Timestamp) -> bool {
+        self < other
+    }
+
+    /// Returns true if `self` is strictly after `other`.
+    #[inline]
+    pub fn is_after(&self, other: &Timestamp) -> bool {
+        self > other
+    }
+}
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, rhs: Duration) -> Timestamp {
+        self.plus_nanos(rhs.nanos())
+    }
+}
+
+impl AddAssign<Duration> for Timestamp {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, rhs: Duration) -> Timestamp {
+        self.minus_nanos(rhs.nanos())
+    }
+}
+
+impl SubAssign<Duration> for Timestamp {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+/// The duration between two timestamps.
+///
+/// Panics if `rhs` is after `self`. See [`Timestamp::checked_duration_since`]
+/// for a non-panicking version.
+impl Sub<Timestamp> for Timestamp {
+    type Output = Duration;
+
+    fn sub(self, rhs: Timestamp) -> Duration {
+        // Uint64 subtraction panics on overflow, independent of the overflow-checks setting
+        Duration::from_nanos((self.0 - rhs.0).u64())
+    }
 }
 
 impl fmt::Display for Timestamp {
@@ -178,6 +270,94 @@ mod tests {
         assert_eq!(sum.subsec_nanos(), 8765436);
     }
 
+    #[test]
+    fn timestamp_checked_add_and_sub() {
+        let ts = Timestamp::from_seconds(100);
+        let d = Duration::from_nanos(5);
+        assert_eq!(
+            ts.checked_add(d),
+            Ok(Timestamp::from_nanos(100_000_000_005))
+        );
+        assert_eq!(ts.checked_sub(d), Ok(Timestamp::from_nanos(99_999_999_995)));
+
+        let max = Timestamp::from_nanos(u64::MAX);
+        assert_eq!(
+            max.checked_add(d),
+            Err(OverflowError::new(OverflowOperation::Add, max, d))
+        );
+        let long = Duration::from_seconds(101);
+        assert_eq!(
+            ts.checked_sub(long),
+            Err(OverflowError::new(OverflowOperation::Sub, ts, long))
+        );
+    }
+
+    #[test]
+    fn timestamp_duration_arithmetic() {
+        let mut ts = Timestamp::from_seconds(100);
+        assert_eq!(
+            ts + Duration::from_seconds(20),
+            Timestamp::from_seconds(120)
+        );
+        assert_eq!(ts - Duration::from_seconds(20), Timestamp::from_seconds(80));
+
+        ts += Duration::from_nanos(3);
+        assert_eq!(ts, Timestamp::from_nanos(100_000_000_003));
+        ts -= Duration::from_nanos(3);
+        assert_eq!(ts, Timestamp::from_seconds(100));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn timestamp_sub_duration_panics_on_overflow() {
+        let _ = Timestamp::from_seconds(1) - Duration::from_seconds(2);
+    }
+
+    #[test]
+    fn timestamp_difference() {
+        let start = Timestamp::from_seconds(100);
+        let end = Timestamp::from_nanos(160_000_000_001);
+        assert_eq!(end - start, Duration::from_nanos(60_000_000_001));
+        assert_eq!(start - start, Duration::zero());
+
+        assert_eq!(
+            end.checked_duration_since(start),
+            Ok(Duration::from_nanos(60_000_000_001))
+        );
+        assert_eq!(
+            start.checked_duration_since(end),
+            Err(OverflowError::new(OverflowOperation::Sub, start, end))
+        );
+
+        assert_eq!(start.abs_diff(end), Duration::from_nanos(60_000_000_001));
+        assert_eq!(end.abs_diff(start), Duration::from_nanos(60_000_000_001));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn timestamp_difference_panics_for_later_rhs() {
+        let _ = Timestamp::from_seconds(1) - Timestamp::from_seconds(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn timestamp_difference_does_not_wrap() {
+        // this would wrap to a duration of u64::MAX nanos without the overflow check
+        let _ = Timestamp::from_nanos(0) - Timestamp::from_nanos(1);
+    }
+
+    #[test]
+    fn timestamp_is_before_and_is_after() {
+        let early = Timestamp::from_seconds(1);
+        let late = Timestamp::from_nanos(1_000_000_001);
+        assert!(early.is_before(&late));
+        assert!(!early.is_after(&late));
+        assert!(late.is_after(&early));
+        assert!(!late.is_before(&early));
+        assert!(!early.is_before(&early));
+        assert!(!early.is_after(&early));
+    }
+
     #[test]
     fn timestamp_implements_display() {
         let embedded = format!("Time: {}", Timestamp::from_nanos(0));
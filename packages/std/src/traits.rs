@@ -8,6 +8,8 @@ use crate::coin::Coin;
 use crate::errors::{RecoverPubkeyError, StdError, StdResult, VerificationError};
 #[cfg(feature = "iterator")]
 use crate::iterator::{Order, Record};
+#[cfg(feature = "cosmwasm_1_2")]
+use crate::query::CodeInfoResponse;
 #[cfg(feature = "cosmwasm_1_1")]
 use crate::query::SupplyResponse;
 use crate::query::{
@@ -18,9 +20,17 @@ use crate::query::{
     AllDelegationsResponse, AllValidatorsResponse, BondedDenomResponse, Delegation,
     DelegationResponse, FullDelegation, StakingQuery, Validator, ValidatorResponse,
 };
+#[cfg(feature = "cosmwasm_1_3")]
+use crate::query::{
+    AllDenomMetadataResponse, DelegationRewardsResponse, DelegationTotalRewardsResponse,
+    DelegatorValidatorsResponse, DelegatorWithdrawAddressResponse, DenomMetadataResponse,
+    DistributionQuery,
+};
 use crate::results::{ContractResult, Empty, SystemResult};
 use crate::serde::{from_binary, to_binary, to_vec};
 use crate::ContractInfoResponse;
+#[cfg(feature = "cosmwasm_1_3")]
+use crate::{DecCoin, DenomMetadata, PageRequest};
 
 /// Storage provides read and write access to a persistent storage.
 /// If you only want to provide read access, provide `&Storage`
@@ -204,6 +214,37 @@ impl<'a, C: CustomQuery> QuerierWrapper<'a, C> {
         }
     }
 
+    /// Queries the gRPC query service of a Cosmos SDK module and returns the raw
+    /// protobuf encoded response.
+    ///
+    /// `path` is the fully qualified service path (e.g. "/cosmos.bank.v1beta1.Query/Balance")
+    /// and `data` the protobuf encoded request. Decoding the response is left to the caller.
+    #[cfg(feature = "cosmwasm_1_4")]
+    pub fn query_grpc(
+        &self,
+        path: impl Into<String>,
+        data: impl Into<Binary>,
+    ) -> StdResult<Binary> {
+        let request: QueryRequest<C> = QueryRequest::Grpc {
+            path: path.into(),
+            data: data.into(),
+        };
+        // we cannot use query, as it will try to parse the binary data, when we just want to return it
+        let raw = to_vec(&request).map_err(|serialize_err| {
+            StdError::generic_err(format!("Serializing QueryRequest: {}", serialize_err))
+        })?;
+        match self.raw_query(&raw) {
+            SystemResult::Err(system_err) => Err(StdError::generic_err(format!(
+                "Querier system error: {}",
+                system_err
+            ))),
+            SystemResult::Ok(ContractResult::Err(contract_err)) => Err(StdError::generic_err(
+                format!("Querier contract error: {}", contract_err),
+            )),
+            SystemResult::Ok(ContractResult::Ok(value)) => Ok(value),
+        }
+    }
+
     #[cfg(feature = "cosmwasm_1_1")]
     pub fn query_supply(&self, denom: impl Into<String>) -> StdResult<Coin> {
         let request = BankQuery::Supply {
@@ -237,6 +278,25 @@ impl<'a, C: CustomQuery> QuerierWrapper<'a, C> {
         Ok(res.amount)
     }
 
+    #[cfg(feature = "cosmwasm_1_3")]
+    pub fn query_denom_metadata(&self, denom: impl Into<String>) -> StdResult<DenomMetadata> {
+        let request = BankQuery::DenomMetadata {
+            denom: denom.into(),
+        }
+        .into();
+        let res: DenomMetadataResponse = self.query(&request)?;
+        Ok(res.metadata)
+    }
+
+    #[cfg(feature = "cosmwasm_1_3")]
+    pub fn query_all_denom_metadata(
+        &self,
+        pagination: Option<PageRequest>,
+    ) -> StdResult<AllDenomMetadataResponse> {
+        let request = BankQuery::AllDenomMetadata { pagination }.into();
+        self.query(&request)
+    }
+
     // this queries another wasm contract. You should know a priori the proper types for T and U
     // (response and request) based on the contract API
     pub fn query_wasm_smart<T: DeserializeOwned>(
@@ -304,6 +364,13 @@ impl<'a, C: CustomQuery> QuerierWrapper<'a, C> {
         self.query(&request)
     }
 
+    /// Given a code ID, query information about that code.
+    #[cfg(feature = "cosmwasm_1_2")]
+    pub fn query_wasm_code_info(&self, code_id: u64) -> StdResult<CodeInfoResponse> {
+        let request = WasmQuery::CodeInfo { code_id }.into();
+        self.query(&request)
+    }
+
     #[cfg(feature = "staking")]
     pub fn query_all_validators(&self) -> StdResult<Vec<Validator>> {
         let request = StakingQuery::AllValidators {}.into();
@@ -355,6 +422,59 @@ impl<'a, C: CustomQuery> QuerierWrapper<'a, C> {
         let res: DelegationResponse = self.query(&request)?;
         Ok(res.delegation)
     }
+
+    #[cfg(feature = "cosmwasm_1_3")]
+    pub fn query_delegator_withdraw_address(
+        &self,
+        delegator: impl Into<String>,
+    ) -> StdResult<Addr> {
+        let request = DistributionQuery::DelegatorWithdrawAddress {
+            delegator_address: delegator.into(),
+        }
+        .into();
+        let res: DelegatorWithdrawAddressResponse = self.query(&request)?;
+        Ok(res.withdraw_address)
+    }
+
+    #[cfg(feature = "cosmwasm_1_3")]
+    pub fn query_delegation_rewards(
+        &self,
+        delegator: impl Into<String>,
+        validator: impl Into<String>,
+    ) -> StdResult<Vec<DecCoin>> {
+        let request = DistributionQuery::DelegationRewards {
+            delegator_address: delegator.into(),
+            validator_address: validator.into(),
+        }
+        .into();
+        let res: DelegationRewardsResponse = self.query(&request)?;
+        Ok(res.rewards)
+    }
+
+    #[cfg(feature = "cosmwasm_1_3")]
+    pub fn query_delegation_total_rewards(
+        &self,
+        delegator: impl Into<String>,
+    ) -> StdResult<DelegationTotalRewardsResponse> {
+        let request = DistributionQuery::DelegationTotalRewards {
+            delegator_address: delegator.into(),
+        }
+        .into();
+        self.query(&request)
+    }
+
+    #[cfg(feature = "cosmwasm_1_3")]
+    pub fn query_delegator_validators(
+        &self,
+        delegator: impl Into<String>,
+    ) -> StdResult<Vec<String>> {
+        let request = DistributionQuery::DelegatorValidators {
+            delegator_address: delegator.into(),
+        }
+        .into();
+        let res: DelegatorValidatorsResponse = self.query(&request)?;
+        Ok(res.validators)
+    }
 }
 
 #[cfg(test)]
@@ -422,6 +542,39 @@ mod tests {
         assert_eq!(all_balances, vec![coin(123, "ELF"), coin(777, "FLY")]);
     }
 
+    #[cfg(feature = "cosmwasm_1_3")]
+    #[test]
+    fn denom_metadata_query_helpers_work() {
+        use crate::{DenomMetadata, DenomUnit};
+
+        let metadata = |base: &str| DenomMetadata {
+            base: base.to_string(),
+            denom_units: vec![DenomUnit {
+                denom: base.to_string(),
+                exponent: 0,
+                aliases: vec![],
+            }],
+            ..Default::default()
+        };
+
+        let mut querier: MockQuerier<Empty> = MockQuerier::new(&[]);
+        querier.set_denom_metadata(&[metadata("uatom"), metadata("ujuno"), metadata("uosmo")]);
+        let wrapper = QuerierWrapper::<Empty>::new(&querier);
+
+        let res = wrapper.query_denom_metadata("ujuno").unwrap();
+        assert_eq!(res, metadata("ujuno"));
+
+        let res = wrapper
+            .query_all_denom_metadata(Some(PageRequest {
+                key: None,
+                limit: 2,
+                reverse: false,
+            }))
+            .unwrap();
+        assert_eq!(res.metadata, vec![metadata("uatom"), metadata("ujuno")]);
+        assert_eq!(res.pagination.next_key, Some(Binary::from(b"uosmo")));
+    }
+
     #[test]
     fn contract_info() {
         const ACCT: &str = "foobar";
@@ -489,4 +642,123 @@ mod tests {
             } if msg == "Querier system error: No such contract: foobar"
         ));
     }
+
+    #[test]
+    #[cfg(feature = "cosmwasm_1_2")]
+    fn code_info() {
+        use crate::HexBinary;
+
+        const CODE_ID: u64 = 22;
+        const CREATOR: &str = "sender";
+        fn mock_resp() -> CodeInfoResponse {
+            CodeInfoResponse {
+                code_id: CODE_ID,
+                creator: CREATOR.to_string(),
+                checksum: HexBinary::from_hex(
+                    "f7bb7b18fb01bbf425cf4ed2cd4b7fb26a019a7fc75a4dc87e8a0b768c501f00",
+                )
+                .unwrap(),
+            }
+        }
+
+        let mut querier: MockQuerier<Empty> = MockQuerier::new(&[]);
+        querier.update_wasm(|q| -> QuerierResult {
+            match q {
+                WasmQuery::CodeInfo { code_id } if *code_id == CODE_ID => {
+                    SystemResult::Ok(ContractResult::Ok(to_binary(&mock_resp()).unwrap()))
+                }
+                WasmQuery::CodeInfo { code_id } => {
+                    SystemResult::Err(crate::SystemError::NoSuchCode { code_id: *code_id })
+                }
+                _ => panic!("unexpected query"),
+            }
+        });
+        let wrapper = QuerierWrapper::<Empty>::new(&querier);
+
+        let code_info = wrapper.query_wasm_code_info(CODE_ID).unwrap();
+        assert_eq!(code_info, mock_resp());
+
+        let err = wrapper.query_wasm_code_info(CODE_ID + 1).unwrap_err();
+        assert!(matches!(
+            err,
+            StdError::GenericErr {
+                msg,
+                ..
+            } if msg == "Querier system error: No such code: 23"
+        ));
+    }
+
+    #[cfg(feature = "cosmwasm_1_3")]
+    #[test]
+    fn distribution_query_helpers_work() {
+        use crate::testing::DistributionQuerier;
+        use crate::{DecCoin, Decimal256};
+
+        let mut distribution = DistributionQuerier::default();
+        distribution.set_withdraw_address("alice", "bob");
+        distribution.set_rewards(
+            "val1",
+            "alice",
+            vec![DecCoin::new(Decimal256::one(), "ucosm")],
+        );
+        distribution.set_rewards(
+            "val2",
+            "alice",
+            vec![DecCoin::new(Decimal256::percent(50), "ucosm")],
+        );
+
+        let mut querier: MockQuerier<Empty> = MockQuerier::new(&[]);
+        querier.update_distribution(distribution);
+        let wrapper = QuerierWrapper::<Empty>::new(&querier);
+
+        let withdraw_address = wrapper.query_delegator_withdraw_address("alice").unwrap();
+        assert_eq!(withdraw_address, Addr::unchecked("bob"));
+
+        let rewards = wrapper.query_delegation_rewards("alice", "val2").unwrap();
+        assert_eq!(
+            rewards,
+            vec![DecCoin::new(Decimal256::percent(50), "ucosm")]
+        );
+
+        let total = wrapper.query_delegation_total_rewards("alice").unwrap();
+        assert_eq!(total.rewards.len(), 2);
+        assert_eq!(
+            total.total,
+            vec![DecCoin::new(Decimal256::percent(150), "ucosm")]
+        );
+
+        let validators = wrapper.query_delegator_validators("alice").unwrap();
+        assert_eq!(validators, vec!["val1".to_string(), "val2".to_string()]);
+    }
+
+    #[cfg(feature = "cosmwasm_1_4")]
+    #[test]
+    fn query_grpc_works() {
+        const PATH: &str = "/cosmos.bank.v1beta1.Query/SupplyOf";
+
+        let mut querier: MockQuerier<Empty> = MockQuerier::new(&[]);
+        querier.update_grpc(|path, data| -> QuerierResult {
+            if path == PATH {
+                // echo the request data in reverse order
+                let mut response = data.to_vec();
+                response.reverse();
+                SystemResult::Ok(ContractResult::Ok(response.into()))
+            } else {
+                SystemResult::Ok(ContractResult::Err(format!("unknown path: {}", path)))
+            }
+        });
+        let wrapper = QuerierWrapper::<Empty>::new(&querier);
+
+        let response = wrapper.query_grpc(PATH, vec![0x0a, 0x05]).unwrap();
+        assert_eq!(response, Binary::from([0x05, 0x0a]));
+
+        let err = wrapper.query_grpc("/foo.Query/Bar", vec![]).unwrap_err();
+        assert!(matches!(
+            err,
+            StdError::GenericErr {
+                msg,
+                ..
+            } if msg == "Querier contract error: unknown path: /foo.Query/Bar"
+        ));
+    }
 }
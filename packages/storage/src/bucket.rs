@@ -1,9 +1,9 @@
 use serde::{de::DeserializeOwned, ser::Serialize};
 use std::marker::PhantomData;
 
-use cosmwasm_std::{to_vec, StdError, StdResult, Storage};
 #[cfg(feature = "iterator")]
-use cosmwasm_std::{Order, Record};
+use cosmwasm_std::{collect_page, page_limit, Bound, Order, Page, Record};
+use cosmwasm_std::{to_vec, StdError, StdResult, Storage};
 
 use crate::length_prefixed::{to_length_prefixed, to_length_prefixed_nested};
 #[cfg(feature = "iterator")]
@@ -94,6 +94,27 @@ where
         Box::new(mapped)
     }
 
+    /// Loads a page of records that starts after the key `start_after` in the given order.
+    /// At most `limit` records are returned, but never more than `max_limit`.
+    /// A limit of 0 is treated as 1, see [`page_limit`](cosmwasm_std::page_limit).
+    #[cfg(feature = "iterator")]
+    pub fn paginate(
+        &self,
+        start_after: Option<&[u8]>,
+        limit: Option<u32>,
+        max_limit: u32,
+        order: Order,
+    ) -> StdResult<Page<Record<T>>> {
+        paginate_with_prefix(
+            self.storage,
+            &self.prefix,
+            start_after,
+            limit,
+            max_limit,
+            order,
+        )
+    }
+
     /// Loads the data, perform the specified action, and store the result
     /// in the database. This is shorthand for some common sequences, which may be useful.
     ///
@@ -164,6 +185,52 @@ where
             .map(deserialize_kv::<T>);
         Box::new(mapped)
     }
+
+    /// Loads a page of records that starts after the key `start_after` in the given order.
+    /// At most `limit` records are returned, but never more than `max_limit`.
+    /// A limit of 0 is treated as 1, see [`page_limit`](cosmwasm_std::page_limit).
+    #[cfg(feature = "iterator")]
+    pub fn paginate(
+        &self,
+        start_after: Option<&[u8]>,
+        limit: Option<u32>,
+        max_limit: u32,
+        order: Order,
+    ) -> StdResult<Page<Record<T>>> {
+        paginate_with_prefix(
+            self.storage,
+            &self.prefix,
+            start_after,
+            limit,
+            max_limit,
+            order,
+        )
+    }
+}
+
+/// Loads a page of records under the given prefix.
+///
+/// The page starts after the key `start_after` in the given order, i.e. for descending
+/// order it contains keys lower than `start_after`. At most `limit` records are returned,
+/// but never more than `max_limit`. If `limit` is not set, `max_limit` is used.
+/// A limit of 0 is treated as 1.
+#[cfg(feature = "iterator")]
+fn paginate_with_prefix<T: DeserializeOwned>(
+    storage: &dyn Storage,
+    prefix: &[u8],
+    start_after: Option<&[u8]>,
+    limit: Option<u32>,
+    max_limit: u32,
+    order: Order,
+) -> StdResult<Page<Record<T>>> {
+    let after = start_after.map(Bound::exclusive);
+    let (start, end) = match order {
+        Order::Ascending => (after.map(|b| b.to_start_key()), None),
+        Order::Descending => (None, after.map(|b| b.to_end_key())),
+    };
+    let iter = range_with_prefix(storage, prefix, start.as_deref(), end.as_deref(), order)
+        .map(deserialize_kv::<T>);
+    collect_page(iter, page_limit(limit, max_limit), |(k, _)| k.clone())
 }
 
 #[cfg(test)]
@@ -457,4 +524,51 @@ mod tests {
         assert_eq!(data[0], (b"jose".to_vec(), jose));
         assert_eq!(data[1], (b"maria".to_vec(), maria));
     }
+
+    #[test]
+    #[cfg(feature = "iterator")]
+    fn paginate_over_data() {
+        let mut store = MockStorage::new();
+        let mut data = bucket::<u32>(&mut store, b"data");
+        for (key, value) in [(b"a", 1), (b"b", 2), (b"c", 3), (b"d", 4)] {
+            data.save(key, &value).unwrap();
+        }
+        // other namespaces are not included
+        bucket::<u32>(&mut store, b"dat").save(b"ae", &5).unwrap();
+
+        let data = bucket_read::<u32>(&store, b"data");
+        let page = data.paginate(None, Some(3), 10, Order::Ascending).unwrap();
+        assert_eq!(
+            page.items,
+            vec![(b"a".to_vec(), 1), (b"b".to_vec(), 2), (b"c".to_vec(), 3)]
+        );
+        assert_eq!(page.next_key, Some(b"c".to_vec()));
+
+        let page = data
+            .paginate(page.next_key.as_deref(), Some(3), 10, Order::Ascending)
+            .unwrap();
+        assert_eq!(page.items, vec![(b"d".to_vec(), 4)]);
+        assert_eq!(page.next_key, None);
+
+        // start_after is exclusive in both orders
+        let page = data
+            .paginate(Some(b"c"), None, 10, Order::Descending)
+            .unwrap();
+        assert_eq!(page.items, vec![(b"b".to_vec(), 2), (b"a".to_vec(), 1)]);
+        assert_eq!(page.next_key, None);
+
+        // limit is capped by max_limit
+        let mut store = MockStorage::new();
+        let mut data = bucket::<u32>(&mut store, b"data");
+        data.save(b"a", &1).unwrap();
+        data.save(b"b", &2).unwrap();
+        let page = data.paginate(None, Some(10), 1, Order::Descending).unwrap();
+        assert_eq!(page.items, vec![(b"b".to_vec(), 2)]);
+        assert_eq!(page.next_key, Some(b"b".to_vec()));
+
+        // a limit of 0 is treated as 1
+        let page = data.paginate(None, Some(0), 10, Order::Ascending).unwrap();
+        assert_eq!(page.items, vec![(b"a".to_vec(), 1)]);
+        assert_eq!(page.next_key, Some(b"a".to_vec()));
+    }
 }
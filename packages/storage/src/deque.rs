@@ -0,0 +1,416 @@
+use serde::{de::DeserializeOwned, ser::Serialize};
+use std::marker::PhantomData;
+
+use cosmwasm_std::{to_vec, StdError, StdResult, Storage};
+
+use crate::length_prefixed::to_length_prefixed;
+use crate::namespace_helpers::{get_with_prefix, remove_with_prefix, set_with_prefix};
+use crate::type_helpers::must_deserialize;
+
+/// The key of the metadata relative to the namespace. It is shorter than the
+/// 4 byte keys of the values, so they cannot collide.
+const META_KEY: &[u8] = b"";
+
+/// Deque is a double-ended queue of values of type `T`.
+///
+/// Values can be added and removed at both ends in constant time. The positions of the
+/// first and one past the last value are stored as `u32` counters in a metadata key,
+/// the values are stored under their position in big endian. Counters wrap around,
+/// such that a deque can hold up to `u32::MAX` values.
+///
+/// # Examples
+///
+/// ```
+/// # use cosmwasm_std::{testing::MockStorage, StdResult};
+/// use cosmwasm_storage::Deque;
+///
+/// const CLAIMS: Deque<u64> = Deque::new("claims");
+///
+/// let mut storage = MockStorage::new();
+/// CLAIMS.push_back(&mut storage, &2).unwrap();
+/// CLAIMS.push_back(&mut storage, &3).unwrap();
+/// CLAIMS.push_front(&mut storage, &1).unwrap();
+///
+/// let all: Vec<u64> = CLAIMS.iter(&storage).unwrap().collect::<StdResult<_>>().unwrap();
+/// assert_eq!(all, vec![1, 2, 3]);
+/// assert_eq!(CLAIMS.pop_front(&mut storage).unwrap(), Some(1));
+/// assert_eq!(CLAIMS.len(&storage).unwrap(), 2);
+/// ```
+pub struct Deque<'a, T> {
+    namespace: &'a [u8],
+    // see https://doc.rust-lang.org/std/marker/struct.PhantomData.html#unused-type-parameters for why this is needed
+    data_type: PhantomData<T>,
+}
+
+impl<'a, T> Deque<'a, T> {
+    pub const fn new(namespace: &'a str) -> Self {
+        Deque {
+            namespace: namespace.as_bytes(),
+            data_type: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Deque<'a, T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    /// Returns the number of values in the deque
+    pub fn len(&self, store: &dyn Storage) -> StdResult<u32> {
+        let (head, tail) = self.head_tail(store)?;
+        Ok(tail.wrapping_sub(head))
+    }
+
+    /// Returns true if the deque contains no values
+    pub fn is_empty(&self, store: &dyn Storage) -> StdResult<bool> {
+        Ok(self.len(store)? == 0)
+    }
+
+    /// Adds a value to the end of the deque
+    pub fn push_back(&self, store: &mut dyn Storage, value: &T) -> StdResult<()> {
+        let (head, tail) = self.head_tail(store)?;
+        if tail.wrapping_sub(head) == u32::MAX {
+            return Err(StdError::generic_err("Deque is full"));
+        }
+        self.set_value(store, tail, value)?;
+        self.set_head_tail(store, head, tail.wrapping_add(1));
+        Ok(())
+    }
+
+    /// Adds a value to the front of the deque
+    pub fn push_front(&self, store: &mut dyn Storage, value: &T) -> StdResult<()> {
+        let (head, tail) = self.head_tail(store)?;
+        if tail.wrapping_sub(head) == u32::MAX {
+            return Err(StdError::generic_err("Deque is full"));
+        }
+        let head = head.wrapping_sub(1);
+        self.set_value(store, head, value)?;
+        self.set_head_tail(store, head, tail);
+        Ok(())
+    }
+
+    /// Removes the last value of the deque and returns it, or None if the deque is empty
+    pub fn pop_back(&self, store: &mut dyn Storage) -> StdResult<Option<T>> {
+        let (head, tail) = self.head_tail(store)?;
+        if head == tail {
+            return Ok(None);
+        }
+        let tail = tail.wrapping_sub(1);
+        let value = self.take_value(store, tail)?;
+        self.set_head_tail(store, head, tail);
+        Ok(Some(value))
+    }
+
+    /// Removes the first value of the deque and returns it, or None if the deque is empty
+    pub fn pop_front(&self, store: &mut dyn Storage) -> StdResult<Option<T>> {
+        let (head, tail) = self.head_tail(store)?;
+        if head == tail {
+            return Ok(None);
+        }
+        let value = self.take_value(store, head)?;
+        self.set_head_tail(store, head.wrapping_add(1), tail);
+        Ok(Some(value))
+    }
+
+    /// Returns the first value of the deque without removing it
+    pub fn front(&self, store: &dyn Storage) -> StdResult<Option<T>> {
+        self.get(store, 0)
+    }
+
+    /// Returns the last value of the deque without removing it
+    pub fn back(&self, store: &dyn Storage) -> StdResult<Option<T>> {
+        match self.len(store)? {
+            0 => Ok(None),
+            len => self.get(store, len - 1),
+        }
+    }
+
+    /// Returns the value at the given index, where 0 is the front of the deque,
+    /// or None if the index is out of bounds
+    pub fn get(&self, store: &dyn Storage, index: u32) -> StdResult<Option<T>> {
+        let (head, tail) = self.head_tail(store)?;
+        if index >= tail.wrapping_sub(head) {
+            return Ok(None);
+        }
+        let value = get_with_prefix(
+            store,
+            &self.prefix(),
+            &head.wrapping_add(index).to_be_bytes(),
+        );
+        must_deserialize(&value).map(Some)
+    }
+
+    /// Returns an iterator over all values from front to back. Use `rev()` to
+    /// iterate from back to front.
+    pub fn iter<'b>(&self, store: &'b dyn Storage) -> StdResult<DequeIter<'b, T>> {
+        let (head, tail) = self.head_tail(store)?;
+        Ok(DequeIter {
+            store,
+            prefix: self.prefix(),
+            start: head,
+            end: tail,
+            data_type: PhantomData,
+        })
+    }
+
+    fn prefix(&self) -> Vec<u8> {
+        to_length_prefixed(self.namespace)
+    }
+
+    fn head_tail(&self, store: &dyn Storage) -> StdResult<(u32, u32)> {
+        match get_with_prefix(store, &self.prefix(), META_KEY) {
+            Some(meta) => {
+                let meta: [u8; 8] = meta
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| StdError::parse_err("Deque metadata", "wrong number of bytes"))?;
+                let head = u32::from_be_bytes([meta[0], meta[1], meta[2], meta[3]]);
+                let tail = u32::from_be_bytes([meta[4], meta[5], meta[6], meta[7]]);
+                Ok((head, tail))
+            }
+            None => Ok((0, 0)),
+        }
+    }
+
+    fn set_head_tail(&self, store: &mut dyn Storage, head: u32, tail: u32) {
+        let prefix = self.prefix();
+        if head == tail {
+            remove_with_prefix(store, &prefix, META_KEY);
+        } else {
+            let meta = [head.to_be_bytes(), tail.to_be_bytes()].concat();
+            set_with_prefix(store, &prefix, META_KEY, &meta);
+        }
+    }
+
+    fn set_value(&self, store: &mut dyn Storage, pos: u32, value: &T) -> StdResult<()> {
+        set_with_prefix(store, &self.prefix(), &pos.to_be_bytes(), &to_vec(value)?);
+        Ok(())
+    }
+
+    fn take_value(&self, store: &mut dyn Storage, pos: u32) -> StdResult<T> {
+        let prefix = self.prefix();
+        let value = must_deserialize(&get_with_prefix(store, &prefix, &pos.to_be_bytes()))?;
+        remove_with_prefix(store, &prefix, &pos.to_be_bytes());
+        Ok(value)
+    }
+}
+
+/// An iterator over the values of a [`Deque`], see [`Deque::iter`].
+pub struct DequeIter<'a, T> {
+    store: &'a dyn Storage,
+    prefix: Vec<u8>,
+    /// position of the next value from the front
+    start: u32,
+    /// position after the next value from the back
+    end: u32,
+    data_type: PhantomData<T>,
+}
+
+impl<'a, T> DequeIter<'a, T>
+where
+    T: DeserializeOwned,
+{
+    fn load(&self, pos: u32) -> StdResult<T> {
+        let value = get_with_prefix(self.store, &self.prefix, &pos.to_be_bytes());
+        must_deserialize(&value)
+    }
+}
+
+impl<'a, T> Iterator for DequeIter<'a, T>
+where
+    T: DeserializeOwned,
+{
+    type Item = StdResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let item = self.load(self.start);
+        self.start = self.start.wrapping_add(1);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end.wrapping_sub(self.start) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for DequeIter<'a, T>
+where
+    T: DeserializeOwned,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end = self.end.wrapping_sub(1);
+        Some(self.load(self.end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+    use serde::{Deserialize, Serialize};
+
+    const DEQUE: Deque<u32> = Deque::new("deque");
+
+    #[test]
+    fn push_and_pop() {
+        let mut store = MockStorage::new();
+
+        assert!(DEQUE.is_empty(&store).unwrap());
+        assert_eq!(DEQUE.pop_back(&mut store).unwrap(), None);
+        assert_eq!(DEQUE.pop_front(&mut store).unwrap(), None);
+
+        DEQUE.push_back(&mut store, &1).unwrap();
+        DEQUE.push_back(&mut store, &2).unwrap();
+        DEQUE.push_front(&mut store, &0).unwrap();
+        assert_eq!(DEQUE.len(&store).unwrap(), 3);
+        assert!(!DEQUE.is_empty(&store).unwrap());
+        assert_eq!(DEQUE.front(&store).unwrap(), Some(0));
+        assert_eq!(DEQUE.back(&store).unwrap(), Some(2));
+
+        assert_eq!(DEQUE.pop_front(&mut store).unwrap(), Some(0));
+        assert_eq!(DEQUE.pop_back(&mut store).unwrap(), Some(2));
+        assert_eq!(DEQUE.pop_back(&mut store).unwrap(), Some(1));
+        assert_eq!(DEQUE.pop_front(&mut store).unwrap(), None);
+        assert_eq!(DEQUE.front(&store).unwrap(), None);
+        assert_eq!(DEQUE.back(&store).unwrap(), None);
+        assert!(DEQUE.is_empty(&store).unwrap());
+
+        // an empty deque removes its metadata
+        assert_eq!(store.get(&to_length_prefixed(b"deque")), None);
+    }
+
+    #[test]
+    fn get_works() {
+        let mut store = MockStorage::new();
+
+        DEQUE.push_back(&mut store, &2).unwrap();
+        DEQUE.push_front(&mut store, &1).unwrap();
+        DEQUE.push_back(&mut store, &3).unwrap();
+
+        assert_eq!(DEQUE.get(&store, 0).unwrap(), Some(1));
+        assert_eq!(DEQUE.get(&store, 1).unwrap(), Some(2));
+        assert_eq!(DEQUE.get(&store, 2).unwrap(), Some(3));
+        assert_eq!(DEQUE.get(&store, 3).unwrap(), None);
+        assert_eq!(DEQUE.get(&store, u32::MAX).unwrap(), None);
+    }
+
+    #[test]
+    fn iter_works() {
+        let mut store = MockStorage::new();
+
+        for i in 3..6 {
+            DEQUE.push_back(&mut store, &i).unwrap();
+        }
+        for i in (0..3).rev() {
+            DEQUE.push_front(&mut store, &i).unwrap();
+        }
+
+        let all = DEQUE
+            .iter(&store)
+            .unwrap()
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(all, vec![0, 1, 2, 3, 4, 5]);
+
+        let reversed = DEQUE
+            .iter(&store)
+            .unwrap()
+            .rev()
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(reversed, vec![5, 4, 3, 2, 1, 0]);
+
+        // both ends can be consumed at the same time
+        let mut iter = DEQUE.iter(&store).unwrap();
+        assert_eq!(iter.size_hint(), (6, Some(6)));
+        assert_eq!(iter.next().unwrap().unwrap(), 0);
+        assert_eq!(iter.next_back().unwrap().unwrap(), 5);
+        assert_eq!(iter.next().unwrap().unwrap(), 1);
+        assert_eq!(iter.next_back().unwrap().unwrap(), 4);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next().unwrap().unwrap(), 2);
+        assert_eq!(iter.next_back().unwrap().unwrap(), 3);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+
+    #[test]
+    fn counters_wrap_around() {
+        let mut store = MockStorage::new();
+
+        // start close to the end of the u32 range
+        DEQUE.set_head_tail(&mut store, u32::MAX - 1, u32::MAX - 1);
+        for i in 0..4 {
+            DEQUE.push_back(&mut store, &i).unwrap();
+        }
+        DEQUE.push_front(&mut store, &100).unwrap();
+
+        assert_eq!(DEQUE.len(&store).unwrap(), 5);
+        assert_eq!(DEQUE.get(&store, 4).unwrap(), Some(3));
+        let all = DEQUE
+            .iter(&store)
+            .unwrap()
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(all, vec![100, 0, 1, 2, 3]);
+
+        assert_eq!(DEQUE.pop_back(&mut store).unwrap(), Some(3));
+        assert_eq!(DEQUE.pop_front(&mut store).unwrap(), Some(100));
+        assert_eq!(DEQUE.pop_front(&mut store).unwrap(), Some(0));
+        assert_eq!(DEQUE.len(&store).unwrap(), 2);
+    }
+
+    #[test]
+    fn push_to_full_deque_fails() {
+        let mut store = MockStorage::new();
+
+        DEQUE.set_head_tail(&mut store, 5, 4);
+        assert_eq!(DEQUE.len(&store).unwrap(), u32::MAX);
+        let err = DEQUE.push_back(&mut store, &1).unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+        let err = DEQUE.push_front(&mut store, &1).unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    fn works_with_structs() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Claim {
+            pub addr: String,
+            pub amount: u128,
+        }
+
+        let mut store = MockStorage::new();
+        let claims: Deque<Claim> = Deque::new("claims");
+        let other: Deque<u32> = Deque::new("claim");
+
+        claims
+            .push_back(
+                &mut store,
+                &Claim {
+                    addr: "alice".to_string(),
+                    amount: 5,
+                },
+            )
+            .unwrap();
+        other.push_back(&mut store, &7).unwrap();
+
+        assert_eq!(claims.len(&store).unwrap(), 1);
+        assert_eq!(other.len(&store).unwrap(), 1);
+        assert_eq!(
+            claims.pop_front(&mut store).unwrap(),
+            Some(Claim {
+                addr: "alice".to_string(),
+                amount: 5
+            })
+        );
+        assert_eq!(other.pop_front(&mut store).unwrap(), Some(7));
+    }
+}
@@ -0,0 +1,703 @@
+use serde::{de::DeserializeOwned, ser::Serialize};
+
+use cosmwasm_std::{to_vec, Order, StdError, StdResult, Storage};
+
+use crate::indexes::IndexList;
+use crate::keys::{KeyDeserialize, PrimaryKey};
+use crate::map::Map;
+use crate::prefix::Prefix;
+use crate::type_helpers::may_deserialize;
+
+/// IndexedMap is a [`Map`] that keeps a set of secondary indexes in sync with its entries.
+///
+/// The indexes are updated on every `save`, `update` and `remove`. All indexes are checked
+/// before anything is written, such that a violated unique constraint leaves the storage
+/// unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use cosmwasm_std::{testing::MockStorage, Addr, Order, StdResult};
+/// use cosmwasm_storage::{Index, IndexList, IndexedMap, MultiIndex};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Token {
+///     owner: Addr,
+/// }
+///
+/// struct TokenIndexes<'a> {
+///     owner: MultiIndex<'a, Addr, Token, &'a str>,
+/// }
+///
+/// impl<'a> IndexList<Token> for TokenIndexes<'a> {
+///     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Token>> + '_> {
+///         let v: Vec<&dyn Index<Token>> = vec![&self.owner];
+///         Box::new(v.into_iter())
+///     }
+/// }
+///
+/// fn tokens<'a>() -> IndexedMap<'a, &'a str, Token, TokenIndexes<'a>> {
+///     let indexes = TokenIndexes {
+///         owner: MultiIndex::new(|t| t.owner.clone(), "tokens", "tokens__owner"),
+///     };
+///     IndexedMap::new("tokens", indexes)
+/// }
+///
+/// let mut storage = MockStorage::new();
+/// let alice = Addr::unchecked("alice");
+/// tokens().save(&mut storage, "token1", &Token { owner: alice.clone() }).unwrap();
+/// let owned: Vec<String> = tokens()
+///     .idx
+///     .owner
+///     .prefix(alice)
+///     .keys(&storage, None, None, Order::Ascending)
+///     .collect::<StdResult<_>>()
+///     .unwrap();
+/// assert_eq!(owned, vec!["token1"]);
+/// ```
+pub struct IndexedMap<'a, K, T, I> {
+    primary: Map<'a, K, T>,
+    /// The indexes of this map, which can be queried directly
+    pub idx: I,
+}
+
+impl<'a, K, T, I> IndexedMap<'a, K, T, I> {
+    pub const fn new(namespace: &'a str, indexes: I) -> Self {
+        IndexedMap {
+            primary: Map::new(namespace),
+            idx: indexes,
+        }
+    }
+}
+
+impl<'a, K, T, I> IndexedMap<'a, K, T, I>
+where
+    K: PrimaryKey,
+    T: Serialize + DeserializeOwned,
+    I: IndexList<T>,
+{
+    /// save will serialize the model and store it along with all index entries,
+    /// returns an error on serialization issues or violated unique constraints
+    pub fn save(&self, store: &mut dyn Storage, k: K, data: &T) -> StdResult<()> {
+        let pk = k.joined_key();
+        let key = self.primary.key(k);
+        // serialize first such that an error does not leave the indexes updated
+        let bytes = to_vec(data)?;
+        let old_data = may_deserialize(&store.get(&key))?;
+        self.replace(store, &pk, Some(data), old_data.as_ref())?;
+        store.set(&key, &bytes);
+        Ok(())
+    }
+
+    /// remove deletes the entry along with all its index entries
+    pub fn remove(&self, store: &mut dyn Storage, k: K) -> StdResult<()> {
+        let pk = k.joined_key();
+        let key = self.primary.key(k);
+        let old_data = may_deserialize(&store.get(&key))?;
+        self.replace(store, &pk, None, old_data.as_ref())?;
+        store.remove(&key);
+        Ok(())
+    }
+
+    /// load will return an error if no data is set at the given key, or on parse error
+    pub fn load(&self, store: &dyn Storage, k: K) -> StdResult<T> {
+        self.primary.load(store, k)
+    }
+
+    /// may_load will parse the data stored at the key if present, returns Ok(None) if no data there.
+    /// returns an error on issues parsing
+    pub fn may_load(&self, store: &dyn Storage, k: K) -> StdResult<Option<T>> {
+        self.primary.may_load(store, k)
+    }
+
+    /// Returns true if a value is stored at the given key, without parsing it
+    pub fn has(&self, store: &dyn Storage, k: K) -> bool {
+        self.primary.has(store, k)
+    }
+
+    /// Loads the data, perform the specified action, and store the result
+    /// in the database along with all index entries.
+    ///
+    /// If the data exists, `action(Some(value))` is called. Otherwise `action(None)` is called.
+    pub fn update<A, E>(&self, store: &mut dyn Storage, k: K, action: A) -> Result<T, E>
+    where
+        A: FnOnce(Option<T>) -> Result<T, E>,
+        E: From<StdError>,
+    {
+        let pk = k.joined_key();
+        let key = self.primary.key(k);
+        let raw = store.get(&key);
+        // deserialized twice since the input is moved into the action
+        let input = may_deserialize(&raw)?;
+        let old_data: Option<T> = may_deserialize(&raw)?;
+        let output = action(input)?;
+        let bytes = to_vec(&output)?;
+        self.replace(store, &pk, Some(&output), old_data.as_ref())?;
+        store.set(&key, &bytes);
+        Ok(output)
+    }
+
+    /// Removes the index entries of `old_data` and adds the ones of `data`.
+    /// All indexes are checked before any of them is modified.
+    fn replace(
+        &self,
+        store: &mut dyn Storage,
+        pk: &[u8],
+        data: Option<&T>,
+        old_data: Option<&T>,
+    ) -> StdResult<()> {
+        if let Some(data) = data {
+            for index in self.idx.get_indexes() {
+                index.check(store, pk, data)?;
+            }
+        }
+        if let Some(old_data) = old_data {
+            for index in self.idx.get_indexes() {
+                index.remove(store, pk, old_data)?;
+            }
+        }
+        if let Some(data) = data {
+            for index in self.idx.get_indexes() {
+                index.save(store, pk, data)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, K, T, I> IndexedMap<'a, K, T, I>
+where
+    K: PrimaryKey,
+    K::Suffix: PrimaryKey,
+    K::SuperSuffix: PrimaryKey,
+    T: Serialize + DeserializeOwned,
+{
+    /// Selects all entries whose primary key starts with the given prefix, see [`Map::prefix`].
+    pub fn prefix(&self, p: K::Prefix) -> Prefix<K::Suffix, T> {
+        self.primary.prefix(p)
+    }
+
+    /// Selects all entries whose primary key starts with the given prefix, see [`Map::sub_prefix`].
+    pub fn sub_prefix(&self, p: K::SubPrefix) -> Prefix<K::SuperSuffix, T> {
+        self.primary.sub_prefix(p)
+    }
+}
+
+impl<'a, K, T, I> IndexedMap<'a, K, T, I>
+where
+    K: PrimaryKey + KeyDeserialize,
+    T: Serialize + DeserializeOwned,
+{
+    /// Iterates over the typed primary keys and values of the whole map.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn range<'c>(
+        &self,
+        store: &'c dyn Storage,
+        start: Option<K>,
+        end: Option<K>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(K::Output, T)>> + 'c>
+    where
+        T: 'c,
+        K::Output: 'c,
+    {
+        self.primary.range(store, start, end, order)
+    }
+
+    /// Iterates over the typed primary keys of the whole map without parsing the values.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn keys<'c>(
+        &self,
+        store: &'c dyn Storage,
+        start: Option<K>,
+        end: Option<K>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<K::Output>> + 'c>
+    where
+        K::Output: 'c,
+    {
+        self.primary.keys(store, start, end, order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexes::{Index, MultiIndex, UniqueIndex};
+    use cosmwasm_std::testing::MockStorage;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+    struct Data {
+        pub name: String,
+        pub last_name: String,
+        pub age: u32,
+    }
+
+    struct DataIndexes<'a> {
+        // index by name, which can have duplicates
+        pub name: MultiIndex<'a, String, Data, &'a str>,
+        // index by (name, age), which can have duplicates
+        pub name_age: MultiIndex<'a, (String, u32), Data, &'a str>,
+        // index by last name, which must be unique
+        pub last_name: UniqueIndex<'a, String, Data, &'a str>,
+    }
+
+    impl<'a> IndexList<Data> for DataIndexes<'a> {
+        fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Data>> + '_> {
+            let v: Vec<&dyn Index<Data>> = vec![&self.name, &self.name_age, &self.last_name];
+            Box::new(v.into_iter())
+        }
+    }
+
+    fn people<'a>() -> IndexedMap<'a, &'a str, Data, DataIndexes<'a>> {
+        let indexes = DataIndexes {
+            name: MultiIndex::new(|d| d.name.clone(), "people", "people__name"),
+            name_age: MultiIndex::new(|d| (d.name.clone(), d.age), "people", "people__name_age"),
+            last_name: UniqueIndex::new(|d| d.last_name.clone(), "people", "people__last_name"),
+        };
+        IndexedMap::new("people", indexes)
+    }
+
+    fn data(name: &str, last_name: &str, age: u32) -> Data {
+        Data {
+            name: name.to_string(),
+            last_name: last_name.to_string(),
+            age,
+        }
+    }
+
+    fn save_people(store: &mut dyn Storage) {
+        let map = people();
+        map.save(store, "1", &data("Maria", "Doe", 42)).unwrap();
+        map.save(store, "2", &data("Maria", "Williams", 23))
+            .unwrap();
+        map.save(store, "3", &data("John", "Wayne", 32)).unwrap();
+        map.save(store, "4", &data("Maria", "Rodriguez", 42))
+            .unwrap();
+    }
+
+    #[test]
+    fn save_and_load() {
+        let mut store = MockStorage::new();
+        let map = people();
+
+        assert_eq!(map.may_load(&store, "1").unwrap(), None);
+        map.save(&mut store, "1", &data("Maria", "Doe", 42))
+            .unwrap();
+        assert_eq!(map.load(&store, "1").unwrap(), data("Maria", "Doe", 42));
+        assert!(map.has(&store, "1"));
+
+        // the primary map is compatible with a plain Map
+        const PLAIN: Map<&str, Data> = Map::new("people");
+        assert_eq!(PLAIN.load(&store, "1").unwrap(), data("Maria", "Doe", 42));
+    }
+
+    #[test]
+    fn multi_index_works() {
+        let mut store = MockStorage::new();
+        save_people(&mut store);
+        let map = people();
+
+        let marias = map
+            .idx
+            .name
+            .prefix("Maria".to_string())
+            .range(&store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            marias,
+            vec![
+                ("1".to_string(), data("Maria", "Doe", 42)),
+                ("2".to_string(), data("Maria", "Williams", 23)),
+                ("4".to_string(), data("Maria", "Rodriguez", 42)),
+            ]
+        );
+
+        // bounds are primary keys relative to the index key
+        let keys: Vec<_> = map
+            .idx
+            .name
+            .prefix("Maria".to_string())
+            .keys(&store, Some("2"), None, Order::Descending)
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(keys, vec!["4", "2"]);
+
+        // no collision with a longer name
+        let count = map
+            .idx
+            .name
+            .prefix("Mari".to_string())
+            .keys(&store, None, None, Order::Ascending)
+            .count();
+        assert_eq!(count, 0);
+
+        // all is ordered by index key
+        let all: Vec<_> = map
+            .idx
+            .name
+            .all()
+            .keys(&store, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(all, vec!["3", "1", "2", "4"]);
+    }
+
+    #[test]
+    fn multi_index_composite_keys() {
+        let mut store = MockStorage::new();
+        save_people(&mut store);
+        let map = people();
+
+        let keys: Vec<_> = map
+            .idx
+            .name_age
+            .prefix(("Maria".to_string(), 42))
+            .keys(&store, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(keys, vec!["1", "4"]);
+
+        // sub_prefix iterates by name, ordered by age
+        let marias = map
+            .idx
+            .name_age
+            .sub_prefix("Maria".to_string())
+            .range(&store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        let ages: Vec<_> = marias.into_iter().map(|(_, d)| d.age).collect();
+        assert_eq!(ages, vec![23, 42, 42]);
+    }
+
+    #[test]
+    fn unique_index_works() {
+        let mut store = MockStorage::new();
+        save_people(&mut store);
+        let map = people();
+
+        let (pk, doe) = map
+            .idx
+            .last_name
+            .item(&store, "Doe".to_string())
+            .unwrap()
+            .unwrap();
+        assert_eq!(pk, "1");
+        assert_eq!(doe, data("Maria", "Doe", 42));
+        assert_eq!(
+            map.idx.last_name.item(&store, "Do".to_string()).unwrap(),
+            None
+        );
+
+        let last_names: Vec<_> = map
+            .idx
+            .last_name
+            .all()
+            .range(&store, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, d)| d.last_name))
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(last_names, vec!["Doe", "Rodriguez", "Wayne", "Williams"]);
+
+        // bounds are index keys
+        let keys: Vec<_> = map
+            .idx
+            .last_name
+            .all()
+            .keys(
+                &store,
+                Some("Rodriguez".to_string()),
+                None,
+                Order::Ascending,
+            )
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(keys, vec!["4", "3", "2"]);
+    }
+
+    #[test]
+    fn index_paginate_works() {
+        let mut store = MockStorage::new();
+        save_people(&mut store);
+        let map = people();
+
+        let marias = map.idx.name.prefix("Maria".to_string());
+        let page = marias
+            .paginate(&store, None, Some(2), 10, Order::Ascending)
+            .unwrap();
+        assert_eq!(
+            page.items,
+            vec![
+                ("1".to_string(), data("Maria", "Doe", 42)),
+                ("2".to_string(), data("Maria", "Williams", 23)),
+            ]
+        );
+        assert_eq!(page.next_key, Some("2".to_string()));
+        let page = marias
+            .paginate(
+                &store,
+                page.next_key.as_deref(),
+                Some(2),
+                10,
+                Order::Ascending,
+            )
+            .unwrap();
+        assert_eq!(
+            page.items,
+            vec![("4".to_string(), data("Maria", "Rodriguez", 42))]
+        );
+        assert_eq!(page.next_key, None);
+
+        // the next key of a unique index is an index key
+        let last_names = map.idx.last_name.all();
+        let page = last_names
+            .paginate(&store, None, None, 2, Order::Descending)
+            .unwrap();
+        let pks: Vec<_> = page.items.into_iter().map(|(pk, _)| pk).collect();
+        assert_eq!(pks, vec!["2", "3"]);
+        assert_eq!(page.next_key, Some("Wayne".to_string()));
+        let page = last_names
+            .paginate(&store, page.next_key, None, 2, Order::Descending)
+            .unwrap();
+        let pks: Vec<_> = page.items.into_iter().map(|(pk, _)| pk).collect();
+        assert_eq!(pks, vec!["4", "1"]);
+        assert_eq!(page.next_key, None);
+    }
+
+    /// A value that fails to serialize if the name is empty
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Faulty {
+        pub name: String,
+    }
+
+    impl Serialize for Faulty {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::{Error, SerializeStruct};
+            if self.name.is_empty() {
+                return Err(S::Error::custom("empty name"));
+            }
+            let mut state = serializer.serialize_struct("Faulty", 1)?;
+            state.serialize_field("name", &self.name)?;
+            state.end()
+        }
+    }
+
+    struct FaultyIndexes<'a> {
+        pub name: MultiIndex<'a, String, Faulty, &'a str>,
+    }
+
+    impl<'a> IndexList<Faulty> for FaultyIndexes<'a> {
+        fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Faulty>> + '_> {
+            let v: Vec<&dyn Index<Faulty>> = vec![&self.name];
+            Box::new(v.into_iter())
+        }
+    }
+
+    #[test]
+    fn serialization_error_changes_nothing() {
+        let mut store = MockStorage::new();
+        let indexes = FaultyIndexes {
+            name: MultiIndex::new(|f| f.name.clone(), "faulty", "faulty__name"),
+        };
+        let map: IndexedMap<&str, Faulty, _> = IndexedMap::new("faulty", indexes);
+        let maria = Faulty {
+            name: "Maria".to_string(),
+        };
+        let empty = Faulty {
+            name: String::new(),
+        };
+        map.save(&mut store, "1", &maria).unwrap();
+        let before: Vec<_> = store.range(None, None, Order::Ascending).collect();
+
+        // a new entry
+        map.save(&mut store, "2", &empty).unwrap_err();
+        // an existing entry
+        map.save(&mut store, "1", &empty).unwrap_err();
+        map.update(&mut store, "1", |_| -> StdResult<_> {
+            Ok(Faulty {
+                name: String::new(),
+            })
+        })
+        .unwrap_err();
+
+        let after: Vec<_> = store.range(None, None, Order::Ascending).collect();
+        assert_eq!(after, before);
+        assert_eq!(map.load(&store, "1").unwrap(), maria);
+    }
+
+    #[test]
+    fn unique_index_violation_changes_nothing() {
+        let mut store = MockStorage::new();
+        save_people(&mut store);
+        let map = people();
+
+        // a new entry with an existing last name
+        let err = map
+            .save(&mut store, "5", &data("Anna", "Doe", 1))
+            .unwrap_err();
+        match err {
+            StdError::GenericErr { msg, .. } => {
+                assert_eq!(msg, "Violates unique constraint on index people__last_name")
+            }
+            err => panic!("Unexpected error: {:?}", err),
+        }
+        assert!(!map.has(&store, "5"));
+        let annas = map
+            .idx
+            .name
+            .prefix("Anna".to_string())
+            .keys(&store, None, None, Order::Ascending)
+            .count();
+        assert_eq!(annas, 0);
+
+        // an existing entry changing to an existing last name
+        map.save(&mut store, "3", &data("John", "Doe", 33))
+            .unwrap_err();
+        assert_eq!(map.load(&store, "3").unwrap(), data("John", "Wayne", 32));
+        let johns: Vec<_> = map
+            .idx
+            .name_age
+            .prefix(("John".to_string(), 32))
+            .keys(&store, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(johns, vec!["3"]);
+
+        // overwriting an entry with its own last name is fine
+        map.save(&mut store, "1", &data("Maria", "Doe", 43))
+            .unwrap();
+    }
+
+    #[test]
+    fn save_updates_indexes() {
+        let mut store = MockStorage::new();
+        save_people(&mut store);
+        let map = people();
+
+        map.save(&mut store, "1", &data("Anna", "Smith", 42))
+            .unwrap();
+
+        let marias: Vec<_> = map
+            .idx
+            .name
+            .prefix("Maria".to_string())
+            .keys(&store, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(marias, vec!["2", "4"]);
+        let annas: Vec<_> = map
+            .idx
+            .name
+            .prefix("Anna".to_string())
+            .keys(&store, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(annas, vec!["1"]);
+
+        // the old last name is free again
+        assert_eq!(
+            map.idx.last_name.item(&store, "Doe".to_string()).unwrap(),
+            None
+        );
+        map.save(&mut store, "5", &data("Jane", "Doe", 1)).unwrap();
+    }
+
+    #[test]
+    fn update_updates_indexes() {
+        let mut store = MockStorage::new();
+        save_people(&mut store);
+        let map = people();
+
+        let output = map
+            .update(&mut store, "3", |d| -> StdResult<_> {
+                let mut d = d.unwrap();
+                d.age += 1;
+                Ok(d)
+            })
+            .unwrap();
+        assert_eq!(output, data("John", "Wayne", 33));
+
+        let keys: Vec<_> = map
+            .idx
+            .name_age
+            .prefix(("John".to_string(), 33))
+            .keys(&store, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(keys, vec!["3"]);
+        let count = map
+            .idx
+            .name_age
+            .prefix(("John".to_string(), 32))
+            .keys(&store, None, None, Order::Ascending)
+            .count();
+        assert_eq!(count, 0);
+
+        // inserting via update works as well
+        map.update(&mut store, "6", |d| -> StdResult<_> {
+            assert_eq!(d, None);
+            Ok(data("Jim", "Beam", 50))
+        })
+        .unwrap();
+        assert_eq!(
+            map.idx
+                .last_name
+                .item(&store, "Beam".to_string())
+                .unwrap()
+                .unwrap()
+                .0,
+            "6"
+        );
+    }
+
+    #[test]
+    fn remove_removes_index_entries() {
+        let mut store = MockStorage::new();
+        save_people(&mut store);
+        let map = people();
+
+        map.remove(&mut store, "1").unwrap();
+        assert!(!map.has(&store, "1"));
+
+        let marias: Vec<_> = map
+            .idx
+            .name
+            .prefix("Maria".to_string())
+            .keys(&store, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()
+            .unwrap();
+        assert_eq!(marias, vec!["2", "4"]);
+        assert_eq!(
+            map.idx.last_name.item(&store, "Doe".to_string()).unwrap(),
+            None
+        );
+
+        // removing a missing entry is a no-op
+        map.remove(&mut store, "1").unwrap();
+    }
+
+    #[test]
+    fn range_works() {
+        let mut store = MockStorage::new();
+        save_people(&mut store);
+        let map = people();
+
+        let keys = map
+            .keys(&store, Some("2"), None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(keys, vec!["2", "3", "4"]);
+
+        let all = map
+            .range(&store, None, None, Order::Descending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(all.len(), 4);
+        assert_eq!(all[0], ("4".to_string(), data("Maria", "Rodriguez", 42)));
+    }
+}
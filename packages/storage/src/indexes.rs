@@ -0,0 +1,374 @@
+//! Secondary indexes for [`IndexedMap`](crate::IndexedMap).
+//!
+//! Every index entry points to the primary key of an entry in the primary map. Values
+//! are not duplicated but loaded from the primary map when an index is queried.
+
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+use cosmwasm_std::{collect_page, page_limit, Bound, Order, Page, StdError, StdResult, Storage};
+
+use crate::keys::{KeyDeserialize, Prefixer, PrimaryKey};
+use crate::length_prefixed::to_length_prefixed;
+use crate::namespace_helpers::{
+    get_with_prefix, range_with_prefix, remove_with_prefix, set_with_prefix,
+};
+use crate::type_helpers::must_deserialize;
+
+/// A page of typed primary keys and values, as returned by [`IndexPrefix::paginate`].
+/// `B` is the bound type of the index prefix and `PK` the primary key type.
+pub type IndexPage<B, T, PK> =
+    Page<(<PK as KeyDeserialize>::Output, T), <B as KeyDeserialize>::Output>;
+
+/// An index that is kept in sync with the primary map of an [`IndexedMap`](crate::IndexedMap).
+///
+/// `pk` is the storage representation of the primary key, see [`PrimaryKey::joined_key`].
+pub trait Index<T> {
+    /// Returns an error if the given entry cannot be added to the index.
+    /// This is called for all indexes before any of them is modified.
+    fn check(&self, _store: &dyn Storage, _pk: &[u8], _data: &T) -> StdResult<()> {
+        Ok(())
+    }
+
+    fn save(&self, store: &mut dyn Storage, pk: &[u8], data: &T) -> StdResult<()>;
+
+    fn remove(&self, store: &mut dyn Storage, pk: &[u8], old_data: &T) -> StdResult<()>;
+}
+
+/// The list of all indexes of an [`IndexedMap`](crate::IndexedMap).
+///
+/// This is usually implemented for a struct that holds one field per index.
+pub trait IndexList<T> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<T>> + '_>;
+}
+
+/// An index that maps each index key to at most one primary key.
+///
+/// Saving an entry whose index key is already used by another primary key fails.
+/// The index key is stored like a key of a [`Map`](crate::Map), such that entries can be
+/// selected by a prefix of the index key. `PK` is the primary key type of the
+/// primary map, which is used to deserialize the primary keys of query results.
+pub struct UniqueIndex<'a, IK, T, PK> {
+    index: fn(&T) -> IK,
+    pk_namespace: &'a [u8],
+    idx_namespace: &'a [u8],
+    pk_type: PhantomData<PK>,
+}
+
+impl<'a, IK, T, PK> UniqueIndex<'a, IK, T, PK> {
+    /// Creates a unique index. `pk_namespace` must be the namespace of the primary map.
+    pub const fn new(index: fn(&T) -> IK, pk_namespace: &'a str, idx_namespace: &'a str) -> Self {
+        UniqueIndex {
+            index,
+            pk_namespace: pk_namespace.as_bytes(),
+            idx_namespace: idx_namespace.as_bytes(),
+            pk_type: PhantomData,
+        }
+    }
+}
+
+impl<'a, IK, T, PK> Index<T> for UniqueIndex<'a, IK, T, PK>
+where
+    IK: PrimaryKey + Prefixer,
+{
+    fn check(&self, store: &dyn Storage, pk: &[u8], data: &T) -> StdResult<()> {
+        let idx = (self.index)(data).joined_key();
+        match get_with_prefix(store, &to_length_prefixed(self.idx_namespace), &idx) {
+            Some(existing) if existing != pk => Err(StdError::generic_err(format!(
+                "Violates unique constraint on index {}",
+                String::from_utf8_lossy(self.idx_namespace)
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn save(&self, store: &mut dyn Storage, pk: &[u8], data: &T) -> StdResult<()> {
+        self.check(store, pk, data)?;
+        let idx = (self.index)(data).joined_key();
+        set_with_prefix(store, &to_length_prefixed(self.idx_namespace), &idx, pk);
+        Ok(())
+    }
+
+    fn remove(&self, store: &mut dyn Storage, _pk: &[u8], old_data: &T) -> StdResult<()> {
+        let idx = (self.index)(old_data).joined_key();
+        remove_with_prefix(store, &to_length_prefixed(self.idx_namespace), &idx);
+        Ok(())
+    }
+}
+
+impl<'a, IK, T, PK> UniqueIndex<'a, IK, T, PK>
+where
+    IK: PrimaryKey + Prefixer,
+    T: DeserializeOwned,
+    PK: KeyDeserialize,
+{
+    /// Returns the primary key and value of the entry with the given index key, if any
+    pub fn item(&self, store: &dyn Storage, idx: IK) -> StdResult<Option<(PK::Output, T)>> {
+        let namespace = to_length_prefixed(self.idx_namespace);
+        match get_with_prefix(store, &namespace, &idx.joined_key()) {
+            Some(pk) => {
+                let value = load_primary(store, self.pk_namespace, &pk)?;
+                Ok(Some((PK::from_vec(pk)?, value)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Selects all entries whose index key starts with the given prefix, which consists
+    /// of all index key elements but the last one. Bounds are the last index key element.
+    pub fn prefix(&self, p: IK::Prefix) -> IndexPrefix<IK::Suffix, T, PK> {
+        IndexPrefix::new(self.pk_namespace, self.idx_namespace, &p)
+    }
+
+    /// Selects all entries of this index. Bounds are index keys.
+    pub fn all(&self) -> IndexPrefix<IK, T, PK> {
+        IndexPrefix::new(self.pk_namespace, self.idx_namespace, &())
+    }
+}
+
+/// An index that maps each index key to any number of primary keys.
+///
+/// The index key is stored with all elements length prefixed, followed by the primary key.
+/// `PK` is the primary key type of the primary map, see [`UniqueIndex`].
+pub struct MultiIndex<'a, IK, T, PK> {
+    index: fn(&T) -> IK,
+    pk_namespace: &'a [u8],
+    idx_namespace: &'a [u8],
+    pk_type: PhantomData<PK>,
+}
+
+impl<'a, IK, T, PK> MultiIndex<'a, IK, T, PK> {
+    /// Creates a multi index. `pk_namespace` must be the namespace of the primary map.
+    pub const fn new(index: fn(&T) -> IK, pk_namespace: &'a str, idx_namespace: &'a str) -> Self {
+        MultiIndex {
+            index,
+            pk_namespace: pk_namespace.as_bytes(),
+            idx_namespace: idx_namespace.as_bytes(),
+            pk_type: PhantomData,
+        }
+    }
+}
+
+impl<'a, IK, T, PK> MultiIndex<'a, IK, T, PK>
+where
+    IK: PrimaryKey + Prefixer,
+{
+    fn index_key(&self, pk: &[u8], data: &T) -> Vec<u8> {
+        let mut out = (self.index)(data).joined_prefix();
+        out.extend_from_slice(pk);
+        out
+    }
+}
+
+impl<'a, IK, T, PK> Index<T> for MultiIndex<'a, IK, T, PK>
+where
+    IK: PrimaryKey + Prefixer,
+{
+    fn save(&self, store: &mut dyn Storage, pk: &[u8], data: &T) -> StdResult<()> {
+        let key = self.index_key(pk, data);
+        set_with_prefix(store, &to_length_prefixed(self.idx_namespace), &key, pk);
+        Ok(())
+    }
+
+    fn remove(&self, store: &mut dyn Storage, pk: &[u8], old_data: &T) -> StdResult<()> {
+        let key = self.index_key(pk, old_data);
+        remove_with_prefix(store, &to_length_prefixed(self.idx_namespace), &key);
+        Ok(())
+    }
+}
+
+impl<'a, IK, T, PK> MultiIndex<'a, IK, T, PK>
+where
+    IK: PrimaryKey + Prefixer,
+    T: DeserializeOwned,
+    PK: KeyDeserialize,
+{
+    /// Selects all entries with the given index key. Bounds are primary keys.
+    pub fn prefix(&self, idx: IK) -> IndexPrefix<PK, T, PK> {
+        IndexPrefix::new(self.pk_namespace, self.idx_namespace, &idx)
+    }
+
+    /// Selects all entries whose index key starts with the given prefix, which consists
+    /// of all index key elements but the last one.
+    ///
+    /// Bounds are raw keys relative to the prefix, i.e. the length prefixed last index key
+    /// element followed by the primary key.
+    pub fn sub_prefix(&self, p: IK::Prefix) -> IndexPrefix<Vec<u8>, T, PK> {
+        IndexPrefix::new(self.pk_namespace, self.idx_namespace, &p)
+    }
+
+    /// Selects all entries of this index.
+    ///
+    /// Bounds are raw keys, i.e. the length prefixed index key elements followed by the
+    /// primary key.
+    pub fn all(&self) -> IndexPrefix<Vec<u8>, T, PK> {
+        IndexPrefix::new(self.pk_namespace, self.idx_namespace, &())
+    }
+}
+
+/// A sub-range of an index. Iterating it returns the primary keys and values
+/// of the matching entries, ordered by index key.
+///
+/// `B` is the type of the index keys relative to the prefix, which are used as bounds,
+/// and `PK` is the primary key type.
+pub struct IndexPrefix<B, T, PK> {
+    pk_namespace: Vec<u8>,
+    storage_prefix: Vec<u8>,
+    // see https://doc.rust-lang.org/std/marker/struct.PhantomData.html#unused-type-parameters for why this is needed
+    bound_type: PhantomData<B>,
+    data_type: PhantomData<T>,
+    pk_type: PhantomData<PK>,
+}
+
+impl<B, T, PK> IndexPrefix<B, T, PK> {
+    fn new(pk_namespace: &[u8], idx_namespace: &[u8], p: &impl Prefixer) -> Self {
+        let mut storage_prefix = to_length_prefixed(idx_namespace);
+        storage_prefix.extend(p.joined_prefix());
+        IndexPrefix {
+            pk_namespace: pk_namespace.to_vec(),
+            storage_prefix,
+            bound_type: PhantomData,
+            data_type: PhantomData,
+            pk_type: PhantomData,
+        }
+    }
+}
+
+impl<B, T, PK> IndexPrefix<B, T, PK>
+where
+    B: PrimaryKey,
+    T: DeserializeOwned,
+    PK: KeyDeserialize,
+{
+    /// Iterates over the raw index keys relative to the prefix and the raw primary keys
+    /// between the given raw bounds.
+    fn range_index<'b>(
+        &self,
+        store: &'b dyn Storage,
+        start: Option<Vec<u8>>,
+        end: Option<Vec<u8>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'b> {
+        range_with_prefix(
+            store,
+            &self.storage_prefix,
+            start.as_deref(),
+            end.as_deref(),
+            order,
+        )
+    }
+
+    /// Iterates over the raw primary keys and values of the selected entries.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn range_raw<'b>(
+        &self,
+        store: &'b dyn Storage,
+        start: Option<B>,
+        end: Option<B>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(Vec<u8>, T)>> + 'b>
+    where
+        T: 'b,
+    {
+        let pk_namespace = self.pk_namespace.clone();
+        let start = start.map(|k| k.joined_key());
+        let end = end.map(|k| k.joined_key());
+        let mapped = self
+            .range_index(store, start, end, order)
+            .map(move |(_, pk)| {
+                let value = load_primary(store, &pk_namespace, &pk)?;
+                Ok((pk, value))
+            });
+        Box::new(mapped)
+    }
+
+    /// Iterates over the typed primary keys and values of the selected entries.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn range<'b>(
+        &self,
+        store: &'b dyn Storage,
+        start: Option<B>,
+        end: Option<B>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(PK::Output, T)>> + 'b>
+    where
+        T: 'b,
+        PK::Output: 'b,
+    {
+        let mapped = self
+            .range_raw(store, start, end, order)
+            .map(|item| item.and_then(|(pk, v)| Ok((PK::from_vec(pk)?, v))));
+        Box::new(mapped)
+    }
+
+    /// Iterates over the typed primary keys of the selected entries without loading
+    /// the values.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn keys<'b>(
+        &self,
+        store: &'b dyn Storage,
+        start: Option<B>,
+        end: Option<B>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<PK::Output>> + 'b>
+    where
+        PK::Output: 'b,
+    {
+        let start = start.map(|k| k.joined_key());
+        let end = end.map(|k| k.joined_key());
+        let mapped = self
+            .range_index(store, start, end, order)
+            .map(|(_, pk)| PK::from_vec(pk));
+        Box::new(mapped)
+    }
+
+    /// Loads a page of typed primary keys and values of the selected entries.
+    ///
+    /// The page starts after the index key `start_after` in the given order and its
+    /// `next_key` is an index key as well. At most `limit` entries are returned, but never
+    /// more than `max_limit`. If `limit` is not set, `max_limit` is used.
+    /// A limit of 0 is treated as 1, see [`page_limit`](cosmwasm_std::page_limit).
+    pub fn paginate(
+        &self,
+        store: &dyn Storage,
+        start_after: Option<B>,
+        limit: Option<u32>,
+        max_limit: u32,
+        order: Order,
+    ) -> StdResult<IndexPage<B, T, PK>>
+    where
+        B: KeyDeserialize,
+    {
+        let after = start_after.map(|k| Bound::Exclusive(k.joined_key()));
+        let (start, end) = match order {
+            Order::Ascending => (after.map(|b| b.to_start_key()), None),
+            Order::Descending => (None, after.map(|b| b.to_end_key())),
+        };
+        let pk_namespace = &self.pk_namespace;
+        let iter = self
+            .range_index(store, start, end, order)
+            .map(|(key, pk)| -> StdResult<_> {
+                let value = load_primary(store, pk_namespace, &pk)?;
+                Ok((key, PK::from_vec(pk)?, value))
+            });
+        let page = collect_page(iter, page_limit(limit, max_limit), |(key, _, _)| {
+            key.clone()
+        })?;
+        Ok(Page {
+            items: page.items.into_iter().map(|(_, pk, v)| (pk, v)).collect(),
+            next_key: page.next_key.map(B::from_vec).transpose()?,
+        })
+    }
+}
+
+fn load_primary<T: DeserializeOwned>(
+    store: &dyn Storage,
+    pk_namespace: &[u8],
+    pk: &[u8],
+) -> StdResult<T> {
+    let value = get_with_prefix(store, &to_length_prefixed(pk_namespace), pk);
+    must_deserialize(&value)
+}
@@ -0,0 +1,209 @@
+use serde::{de::DeserializeOwned, ser::Serialize};
+use std::marker::PhantomData;
+
+use cosmwasm_std::{to_vec, StdError, StdResult, Storage};
+
+use crate::length_prefixed::to_length_prefixed;
+use crate::type_helpers::{may_deserialize, must_deserialize};
+
+/// Item stores a single value of type `T` under a fixed storage key.
+///
+/// In contrast to [`Singleton`](crate::Singleton), an Item does not hold a reference
+/// to the storage and can therefore be declared as a constant. It uses the same storage
+/// layout as a Singleton with the same key.
+///
+/// # Examples
+///
+/// ```
+/// # use cosmwasm_std::testing::MockStorage;
+/// use cosmwasm_storage::Item;
+///
+/// const OWNER: Item<String> = Item::new("owner");
+///
+/// let mut storage = MockStorage::new();
+/// assert_eq!(OWNER.may_load(&storage).unwrap(), None);
+/// OWNER.save(&mut storage, &"creator".to_string()).unwrap();
+/// assert_eq!(OWNER.load(&storage).unwrap(), "creator");
+/// ```
+pub struct Item<'a, T> {
+    storage_key: &'a [u8],
+    // see https://doc.rust-lang.org/std/marker/struct.PhantomData.html#unused-type-parameters for why this is needed
+    data_type: PhantomData<T>,
+}
+
+impl<'a, T> Item<'a, T> {
+    pub const fn new(storage_key: &'a str) -> Self {
+        Item {
+            storage_key: storage_key.as_bytes(),
+            data_type: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Item<'a, T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    /// Returns the raw key under which the value is stored
+    pub fn as_slice(&self) -> Vec<u8> {
+        to_length_prefixed(self.storage_key)
+    }
+
+    /// save will serialize the model and store, returns an error on serialization issues
+    pub fn save(&self, store: &mut dyn Storage, data: &T) -> StdResult<()> {
+        store.set(&self.as_slice(), &to_vec(data)?);
+        Ok(())
+    }
+
+    pub fn remove(&self, store: &mut dyn Storage) {
+        store.remove(&self.as_slice());
+    }
+
+    /// load will return an error if no data is set at the given key, or on parse error
+    pub fn load(&self, store: &dyn Storage) -> StdResult<T> {
+        let value = store.get(&self.as_slice());
+        must_deserialize(&value)
+    }
+
+    /// may_load will parse the data stored at the key if present, returns Ok(None) if no data there.
+    /// returns an error on issues parsing
+    pub fn may_load(&self, store: &dyn Storage) -> StdResult<Option<T>> {
+        let value = store.get(&self.as_slice());
+        may_deserialize(&value)
+    }
+
+    /// Returns true if a value is stored, without parsing it
+    pub fn exists(&self, store: &dyn Storage) -> bool {
+        store.get(&self.as_slice()).is_some()
+    }
+
+    /// Loads the data, perform the specified action, and store the result
+    /// in the database. This is shorthand for some common sequences, which may be useful.
+    ///
+    /// It assumes, that data was initialized before, and if it doesn't exist, `Err(StdError::NotFound)`
+    /// is returned.
+    pub fn update<A, E>(&self, store: &mut dyn Storage, action: A) -> Result<T, E>
+    where
+        A: FnOnce(T) -> Result<T, E>,
+        E: From<StdError>,
+    {
+        let input = self.load(store)?;
+        let output = action(input)?;
+        self.save(store, &output)?;
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::singleton::{singleton, singleton_read};
+    use cosmwasm_std::testing::MockStorage;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Config {
+        pub owner: String,
+        pub max_tokens: i32,
+    }
+
+    const CONFIG: Item<Config> = Item::new("config");
+
+    #[test]
+    fn save_and_load() {
+        let mut store = MockStorage::new();
+
+        assert!(CONFIG.load(&store).is_err());
+        assert_eq!(CONFIG.may_load(&store).unwrap(), None);
+        assert!(!CONFIG.exists(&store));
+
+        let cfg = Config {
+            owner: "admin".to_string(),
+            max_tokens: 1234,
+        };
+        CONFIG.save(&mut store, &cfg).unwrap();
+
+        assert_eq!(cfg, CONFIG.load(&store).unwrap());
+        assert_eq!(Some(cfg), CONFIG.may_load(&store).unwrap());
+        assert!(CONFIG.exists(&store));
+    }
+
+    #[test]
+    fn remove_works() {
+        let mut store = MockStorage::new();
+
+        let cfg = Config {
+            owner: "admin".to_string(),
+            max_tokens: 1234,
+        };
+        CONFIG.save(&mut store, &cfg).unwrap();
+        CONFIG.remove(&mut store);
+        assert_eq!(CONFIG.may_load(&store).unwrap(), None);
+    }
+
+    #[test]
+    fn update_success() {
+        let mut store = MockStorage::new();
+
+        let cfg = Config {
+            owner: "admin".to_string(),
+            max_tokens: 1234,
+        };
+        CONFIG.save(&mut store, &cfg).unwrap();
+
+        let output = CONFIG.update(&mut store, |mut c| -> StdResult<_> {
+            c.max_tokens *= 2;
+            Ok(c)
+        });
+        let expected = Config {
+            owner: "admin".to_string(),
+            max_tokens: 2468,
+        };
+        assert_eq!(output.unwrap(), expected);
+        assert_eq!(CONFIG.load(&store).unwrap(), expected);
+    }
+
+    #[test]
+    fn update_does_not_change_data_on_error() {
+        let mut store = MockStorage::new();
+
+        let cfg = Config {
+            owner: "admin".to_string(),
+            max_tokens: 1234,
+        };
+        CONFIG.save(&mut store, &cfg).unwrap();
+
+        let output = CONFIG.update(&mut store, |_c| Err(StdError::generic_err("boom")));
+        assert!(matches!(output.unwrap_err(), StdError::GenericErr { .. }));
+        assert_eq!(CONFIG.load(&store).unwrap(), cfg);
+
+        // update of missing data fails
+        let missing: Item<Config> = Item::new("missing");
+        let output = missing.update(&mut store, |c| -> StdResult<_> { Ok(c) });
+        assert!(matches!(output.unwrap_err(), StdError::NotFound { .. }));
+    }
+
+    #[test]
+    fn compatible_with_singleton() {
+        let mut store = MockStorage::new();
+
+        let cfg = Config {
+            owner: "admin".to_string(),
+            max_tokens: 1234,
+        };
+        CONFIG.save(&mut store, &cfg).unwrap();
+        assert_eq!(
+            singleton_read::<Config>(&store, b"config").load().unwrap(),
+            cfg
+        );
+
+        let cfg2 = Config {
+            owner: "other".to_string(),
+            max_tokens: 1,
+        };
+        singleton::<Config>(&mut store, b"config")
+            .save(&cfg2)
+            .unwrap();
+        assert_eq!(CONFIG.load(&store).unwrap(), cfg2);
+    }
+}
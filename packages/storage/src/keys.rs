@@ -0,0 +1,388 @@
+//! Typed keys for [`Map`](crate::Map).
+//!
+//! A key consists of one or more elements. When stored, all elements but the last
+//! one are length prefixed as documented in
+//! https://github.com/webmaster128/key-namespacing#nesting, which makes a
+//! `Map<(A, B), T>` compatible with a [`Bucket`](crate::Bucket) created via
+//! `Bucket::multilevel(storage, &[namespace, a])`.
+
+use cosmwasm_std::{Addr, StdError, StdResult};
+
+use crate::length_prefixed::{to_length_prefixed, to_length_prefixed_nested};
+
+/// A key of a [`Map`](crate::Map).
+///
+/// The associated types describe how a key can be split for prefix iteration:
+/// `Prefix` is the key without the last element and `Suffix` is the remaining element.
+/// `SubPrefix` is the key without the last two elements and `SuperSuffix` the
+/// remaining elements.
+pub trait PrimaryKey: Sized {
+    type Prefix: Prefixer;
+    type SubPrefix: Prefixer;
+    type Suffix: KeyDeserialize;
+    type SuperSuffix: KeyDeserialize;
+
+    /// Returns the raw bytes of all elements of this key
+    fn key(&self) -> Vec<Vec<u8>>;
+
+    /// Returns the storage representation of this key, which length prefixes
+    /// all elements but the last one.
+    fn joined_key(&self) -> Vec<u8> {
+        let elements = self.key();
+        let (last, prefixes) = match elements.split_last() {
+            Some(split) => split,
+            None => return vec![],
+        };
+        let prefixes: Vec<&[u8]> = prefixes.iter().map(|e| e.as_slice()).collect();
+        let mut out = to_length_prefixed_nested(&prefixes);
+        out.extend_from_slice(last);
+        out
+    }
+}
+
+/// A (partial) key that is used to select a sub-range of a [`Map`](crate::Map).
+/// All elements of a prefix are length prefixed.
+pub trait Prefixer {
+    /// Returns the raw bytes of all elements of this prefix
+    fn prefix(&self) -> Vec<Vec<u8>>;
+
+    /// Returns the storage representation of this prefix
+    fn joined_prefix(&self) -> Vec<u8> {
+        let elements = self.prefix();
+        let elements: Vec<&[u8]> = elements.iter().map(|e| e.as_slice()).collect();
+        to_length_prefixed_nested(&elements)
+    }
+}
+
+/// Converts the storage representation of a key back into a typed value.
+pub trait KeyDeserialize {
+    /// The owned type that is returned, e.g. `String` for `&str`
+    type Output: Sized;
+
+    fn from_vec(value: Vec<u8>) -> StdResult<Self::Output>;
+}
+
+impl Prefixer for () {
+    fn prefix(&self) -> Vec<Vec<u8>> {
+        vec![]
+    }
+}
+
+/// Splits off the first length prefixed element of a joined key
+fn split_first_element(value: &[u8]) -> StdResult<(&[u8], &[u8])> {
+    if value.len() < 2 {
+        return Err(StdError::generic_err("Key too short for length prefix"));
+    }
+    let len = u16::from_be_bytes([value[0], value[1]]) as usize;
+    let rest = &value[2..];
+    if rest.len() < len {
+        return Err(StdError::generic_err("Key too short for element length"));
+    }
+    Ok(rest.split_at(len))
+}
+
+/// Implements all key traits for types that form a single key element
+macro_rules! impl_single_element_key {
+    ($t:ty, $output:ty, |$self_:ident| $to_bytes:expr, |$value:ident| $from_bytes:expr) => {
+        impl PrimaryKey for $t {
+            type Prefix = ();
+            type SubPrefix = ();
+            type Suffix = Self;
+            type SuperSuffix = Self;
+
+            fn key(&$self_) -> Vec<Vec<u8>> {
+                vec![$to_bytes]
+            }
+        }
+
+        impl Prefixer for $t {
+            fn prefix(&$self_) -> Vec<Vec<u8>> {
+                vec![$to_bytes]
+            }
+        }
+
+        impl KeyDeserialize for $t {
+            type Output = $output;
+
+            fn from_vec($value: Vec<u8>) -> StdResult<Self::Output> {
+                $from_bytes
+            }
+        }
+    };
+}
+
+fn string_from_vec(value: Vec<u8>) -> StdResult<String> {
+    String::from_utf8(value).map_err(StdError::invalid_utf8)
+}
+
+impl_single_element_key!(&str, String, |self| self.as_bytes().to_vec(), |value| {
+    string_from_vec(value)
+});
+impl_single_element_key!(String, String, |self| self.as_bytes().to_vec(), |value| {
+    string_from_vec(value)
+});
+impl_single_element_key!(&[u8], Vec<u8>, |self| self.to_vec(), |value| Ok(value));
+impl_single_element_key!(Vec<u8>, Vec<u8>, |self| self.clone(), |value| Ok(value));
+impl_single_element_key!(Addr, Addr, |self| self.as_bytes().to_vec(), |value| {
+    string_from_vec(value).map(Addr::unchecked)
+});
+impl_single_element_key!(&Addr, Addr, |self| self.as_bytes().to_vec(), |value| {
+    string_from_vec(value).map(Addr::unchecked)
+});
+
+/// Unsigned integers are stored in big endian, such that the byte order
+/// matches the numeric order.
+macro_rules! impl_uint_key {
+    ($($t:ty),+) => {
+        $(
+            impl_single_element_key!($t, $t, |self| self.to_be_bytes().to_vec(), |value| {
+                let bytes = value.as_slice().try_into().map_err(|_| {
+                    StdError::parse_err(stringify!($t), "wrong number of bytes")
+                })?;
+                Ok(<$t>::from_be_bytes(bytes))
+            });
+        )+
+    };
+}
+
+impl_uint_key!(u8, u16, u32, u64, u128);
+
+/// Signed integers are stored in big endian with a flipped sign bit, such that
+/// negative values are sorted before positive ones.
+macro_rules! impl_int_key {
+    ($($t:ty),+) => {
+        $(
+            impl_single_element_key!($t, $t, |self| (*self ^ <$t>::MIN).to_be_bytes().to_vec(), |value| {
+                let bytes = value.as_slice().try_into().map_err(|_| {
+                    StdError::parse_err(stringify!($t), "wrong number of bytes")
+                })?;
+                Ok(<$t>::from_be_bytes(bytes) ^ <$t>::MIN)
+            });
+        )+
+    };
+}
+
+impl_int_key!(i8, i16, i32, i64, i128);
+
+// Tuple keys are built from single element keys, which are recognized by their empty prefix.
+
+impl<A, B> PrimaryKey for (A, B)
+where
+    A: PrimaryKey<Prefix = ()> + Prefixer + KeyDeserialize,
+    B: PrimaryKey<Prefix = ()> + Prefixer + KeyDeserialize,
+{
+    type Prefix = A;
+    type SubPrefix = ();
+    type Suffix = B;
+    type SuperSuffix = Self;
+
+    fn key(&self) -> Vec<Vec<u8>> {
+        let mut keys = self.0.key();
+        keys.extend(self.1.key());
+        keys
+    }
+}
+
+impl<A, B> Prefixer for (A, B)
+where
+    A: Prefixer,
+    B: Prefixer,
+{
+    fn prefix(&self) -> Vec<Vec<u8>> {
+        let mut prefixes = self.0.prefix();
+        prefixes.extend(self.1.prefix());
+        prefixes
+    }
+}
+
+impl<A, B> KeyDeserialize for (A, B)
+where
+    A: KeyDeserialize,
+    B: KeyDeserialize,
+{
+    type Output = (A::Output, B::Output);
+
+    fn from_vec(value: Vec<u8>) -> StdResult<Self::Output> {
+        let (a, rest) = split_first_element(&value)?;
+        Ok((A::from_vec(a.to_vec())?, B::from_vec(rest.to_vec())?))
+    }
+}
+
+impl<A, B, C> PrimaryKey for (A, B, C)
+where
+    A: PrimaryKey<Prefix = ()> + Prefixer + KeyDeserialize,
+    B: PrimaryKey<Prefix = ()> + Prefixer + KeyDeserialize,
+    C: PrimaryKey<Prefix = ()> + Prefixer + KeyDeserialize,
+{
+    type Prefix = (A, B);
+    type SubPrefix = A;
+    type Suffix = C;
+    type SuperSuffix = (B, C);
+
+    fn key(&self) -> Vec<Vec<u8>> {
+        let mut keys = self.0.key();
+        keys.extend(self.1.key());
+        keys.extend(self.2.key());
+        keys
+    }
+}
+
+impl<A, B, C> Prefixer for (A, B, C)
+where
+    A: Prefixer,
+    B: Prefixer,
+    C: Prefixer,
+{
+    fn prefix(&self) -> Vec<Vec<u8>> {
+        let mut prefixes = self.0.prefix();
+        prefixes.extend(self.1.prefix());
+        prefixes.extend(self.2.prefix());
+        prefixes
+    }
+}
+
+impl<A, B, C> KeyDeserialize for (A, B, C)
+where
+    A: KeyDeserialize,
+    B: KeyDeserialize,
+    C: KeyDeserialize,
+{
+    type Output = (A::Output, B::Output, C::Output);
+
+    fn from_vec(value: Vec<u8>) -> StdResult<Self::Output> {
+        let (a, rest) = split_first_element(&value)?;
+        let (b, c) = split_first_element(rest)?;
+        Ok((
+            A::from_vec(a.to_vec())?,
+            B::from_vec(b.to_vec())?,
+            C::from_vec(c.to_vec())?,
+        ))
+    }
+}
+
+/// Returns the full storage key for the given namespace and key, i.e. the
+/// length prefixed namespace followed by the joined key.
+pub(crate) fn namespaced_key<K: PrimaryKey>(namespace: &[u8], key: &K) -> Vec<u8> {
+    let mut out = to_length_prefixed(namespace);
+    out.extend(key.joined_key());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_keys_work() {
+        assert_eq!("hello".joined_key(), b"hello");
+        assert_eq!("hello".to_string().joined_key(), b"hello");
+        assert_eq!(<&str>::from_vec(b"hello".to_vec()).unwrap(), "hello");
+        assert_eq!(String::from_vec(b"hello".to_vec()).unwrap(), "hello");
+        assert!(matches!(
+            String::from_vec(vec![0xff]).unwrap_err(),
+            StdError::InvalidUtf8 { .. }
+        ));
+    }
+
+    #[test]
+    fn addr_keys_work() {
+        let addr = Addr::unchecked("creator");
+        assert_eq!(addr.joined_key(), b"creator");
+        assert_eq!(<&Addr>::joined_key(&&addr), b"creator");
+        assert_eq!(Addr::from_vec(b"creator".to_vec()).unwrap(), addr);
+    }
+
+    #[test]
+    fn bytes_keys_work() {
+        assert_eq!(b"raw".as_slice().joined_key(), b"raw");
+        assert_eq!(b"raw".to_vec().joined_key(), b"raw");
+        assert_eq!(<&[u8]>::from_vec(b"raw".to_vec()).unwrap(), b"raw");
+    }
+
+    #[test]
+    fn uint_keys_are_big_endian() {
+        assert_eq!(42u8.joined_key(), [42]);
+        assert_eq!(0x1234u16.joined_key(), [0x12, 0x34]);
+        assert_eq!(1u32.joined_key(), [0, 0, 0, 1]);
+        assert_eq!(256u64.joined_key(), [0, 0, 0, 0, 0, 0, 1, 0]);
+        assert_eq!(u128::MAX.joined_key(), [0xff; 16]);
+
+        assert_eq!(u64::from_vec(256u64.joined_key()).unwrap(), 256);
+        assert!(matches!(
+            u64::from_vec(vec![1, 2, 3]).unwrap_err(),
+            StdError::ParseErr { .. }
+        ));
+    }
+
+    #[test]
+    fn int_keys_preserve_order() {
+        assert_eq!((-1i8).joined_key(), [0x7f]);
+        assert_eq!(0i8.joined_key(), [0x80]);
+        assert_eq!(1i32.joined_key(), [0x80, 0, 0, 1]);
+        assert_eq!(i32::MIN.joined_key(), [0, 0, 0, 0]);
+
+        let values = [i64::MIN, -300, -1, 0, 1, 256, i64::MAX];
+        for pair in values.windows(2) {
+            assert!(pair[0].joined_key() < pair[1].joined_key());
+        }
+        for value in values {
+            assert_eq!(i64::from_vec(value.joined_key()).unwrap(), value);
+        }
+        assert_eq!(i128::from_vec((-5i128).joined_key()).unwrap(), -5);
+    }
+
+    #[test]
+    fn tuple_keys_length_prefix_all_but_last_element() {
+        let key = ("abc", 7u16);
+        assert_eq!(key.key(), vec![b"abc".to_vec(), vec![0, 7]]);
+        assert_eq!(key.joined_key(), b"\x00\x03abc\x00\x07");
+        assert_eq!(
+            key.joined_key(),
+            [to_length_prefixed(b"abc"), 7u16.joined_key()].concat()
+        );
+
+        let key = (Addr::unchecked("owner"), "spender", 1u8);
+        assert_eq!(key.joined_key(), b"\x00\x05owner\x00\x07spender\x01");
+        assert_eq!(
+            key.joined_key(),
+            [to_length_prefixed_nested(&[b"owner", b"spender"]), vec![1]].concat()
+        );
+    }
+
+    #[test]
+    fn tuple_keys_can_be_deserialized() {
+        let key = ("abc", 7u16);
+        assert_eq!(
+            <(&str, u16)>::from_vec(key.joined_key()).unwrap(),
+            ("abc".to_string(), 7)
+        );
+
+        let key = (Addr::unchecked("owner"), "spender", -1i64);
+        assert_eq!(
+            <(Addr, &str, i64)>::from_vec(key.joined_key()).unwrap(),
+            (Addr::unchecked("owner"), "spender".to_string(), -1)
+        );
+
+        assert!(<(&str, u16)>::from_vec(vec![0]).is_err());
+        assert!(<(&str, u16)>::from_vec(b"\x00\x05abc".to_vec()).is_err());
+    }
+
+    #[test]
+    fn prefixes_length_prefix_all_elements() {
+        assert_eq!(().joined_prefix(), b"");
+        assert_eq!("abc".joined_prefix(), b"\x00\x03abc");
+        assert_eq!(
+            ("abc", 7u16).joined_prefix(),
+            b"\x00\x03abc\x00\x02\x00\x07"
+        );
+    }
+
+    #[test]
+    fn namespaced_key_works() {
+        assert_eq!(namespaced_key(b"ns", &"key"), b"\x00\x02nskey");
+        assert_eq!(
+            namespaced_key(b"ns", &("a", "b")),
+            [to_length_prefixed_nested(&[b"ns", b"a"]), b"b".to_vec()].concat()
+        );
+    }
+}
@@ -1,13 +1,39 @@
 mod bucket;
+mod deque;
+#[cfg(feature = "iterator")]
+mod indexed_map;
+#[cfg(feature = "iterator")]
+mod indexes;
+mod item;
+mod keys;
 mod length_prefixed;
+mod map;
 mod namespace_helpers;
+#[cfg(feature = "iterator")]
+mod prefix;
 mod prefixed_storage;
 mod sequence;
 mod singleton;
+#[cfg(feature = "iterator")]
+mod snapshot_map;
 mod type_helpers;
 
 pub use bucket::{bucket, bucket_read, Bucket, ReadonlyBucket};
+#[cfg(feature = "iterator")]
+pub use cosmwasm_std::{Bound, Page};
+pub use deque::{Deque, DequeIter};
+#[cfg(feature = "iterator")]
+pub use indexed_map::IndexedMap;
+#[cfg(feature = "iterator")]
+pub use indexes::{Index, IndexList, IndexPage, IndexPrefix, MultiIndex, UniqueIndex};
+pub use item::Item;
+pub use keys::{KeyDeserialize, Prefixer, PrimaryKey};
 pub use length_prefixed::{to_length_prefixed, to_length_prefixed_nested};
+pub use map::Map;
+#[cfg(feature = "iterator")]
+pub use prefix::{Prefix, TypedPage};
 pub use prefixed_storage::{prefixed, prefixed_read, PrefixedStorage, ReadonlyPrefixedStorage};
 pub use sequence::{currval, nextval, sequence};
 pub use singleton::{singleton, singleton_read, ReadonlySingleton, Singleton};
+#[cfg(feature = "iterator")]
+pub use snapshot_map::{ChangeSet, SnapshotMap, Strategy};
@@ -0,0 +1,670 @@
+use serde::{de::DeserializeOwned, ser::Serialize};
+use std::marker::PhantomData;
+
+use cosmwasm_std::{to_vec, StdError, StdResult, Storage};
+#[cfg(feature = "iterator")]
+use cosmwasm_std::{Bound, Order};
+
+use crate::keys::{namespaced_key, PrimaryKey};
+#[cfg(feature = "iterator")]
+use crate::keys::{KeyDeserialize, Prefixer};
+#[cfg(feature = "iterator")]
+use crate::length_prefixed::to_length_prefixed;
+#[cfg(feature = "iterator")]
+use crate::prefix::{Prefix, TypedPage};
+use crate::type_helpers::{may_deserialize, must_deserialize};
+
+/// Map stores values of type `T` under typed keys of type `K` in a namespace.
+///
+/// In contrast to [`Bucket`](crate::Bucket), a Map does not hold a reference to the
+/// storage and can therefore be declared as a constant. A `Map<&[u8], T>` uses the
+/// same storage layout as a Bucket with the same namespace. For composite keys, all
+/// key elements but the last one are length prefixed, see [`PrimaryKey`].
+///
+/// # Examples
+///
+/// ```
+/// # use cosmwasm_std::{testing::MockStorage, Addr};
+/// use cosmwasm_storage::Map;
+///
+/// const ALLOWANCES: Map<(&Addr, &Addr), u64> = Map::new("allowances");
+///
+/// let mut storage = MockStorage::new();
+/// let owner = Addr::unchecked("owner");
+/// let spender = Addr::unchecked("spender");
+/// ALLOWANCES.save(&mut storage, (&owner, &spender), &42).unwrap();
+/// assert_eq!(ALLOWANCES.load(&storage, (&owner, &spender)).unwrap(), 42);
+/// assert!(!ALLOWANCES.has(&storage, (&spender, &owner)));
+/// ```
+pub struct Map<'a, K, T> {
+    namespace: &'a [u8],
+    // see https://doc.rust-lang.org/std/marker/struct.PhantomData.html#unused-type-parameters for why this is needed
+    key_type: PhantomData<K>,
+    data_type: PhantomData<T>,
+}
+
+impl<'a, K, T> Map<'a, K, T> {
+    pub const fn new(namespace: &'a str) -> Self {
+        Map {
+            namespace: namespace.as_bytes(),
+            key_type: PhantomData,
+            data_type: PhantomData,
+        }
+    }
+
+    pub fn namespace(&self) -> &'a [u8] {
+        self.namespace
+    }
+}
+
+impl<'a, K, T> Map<'a, K, T>
+where
+    K: PrimaryKey,
+    T: Serialize + DeserializeOwned,
+{
+    /// Returns the raw storage key of the given key
+    pub fn key(&self, k: K) -> Vec<u8> {
+        namespaced_key(self.namespace, &k)
+    }
+
+    /// save will serialize the model and store, returns an error on serialization issues
+    pub fn save(&self, store: &mut dyn Storage, k: K, data: &T) -> StdResult<()> {
+        store.set(&self.key(k), &to_vec(data)?);
+        Ok(())
+    }
+
+    pub fn remove(&self, store: &mut dyn Storage, k: K) {
+        store.remove(&self.key(k));
+    }
+
+    /// load will return an error if no data is set at the given key, or on parse error
+    pub fn load(&self, store: &dyn Storage, k: K) -> StdResult<T> {
+        let value = store.get(&self.key(k));
+        must_deserialize(&value)
+    }
+
+    /// may_load will parse the data stored at the key if present, returns Ok(None) if no data there.
+    /// returns an error on issues parsing
+    pub fn may_load(&self, store: &dyn Storage, k: K) -> StdResult<Option<T>> {
+        let value = store.get(&self.key(k));
+        may_deserialize(&value)
+    }
+
+    /// Returns true if a value is stored at the given key, without parsing it
+    pub fn has(&self, store: &dyn Storage, k: K) -> bool {
+        store.get(&self.key(k)).is_some()
+    }
+
+    /// Loads the data, perform the specified action, and store the result
+    /// in the database. This is shorthand for some common sequences, which may be useful.
+    ///
+    /// If the data exists, `action(Some(value))` is called. Otherwise `action(None)` is called.
+    pub fn update<A, E>(&self, store: &mut dyn Storage, k: K, action: A) -> Result<T, E>
+    where
+        A: FnOnce(Option<T>) -> Result<T, E>,
+        E: From<StdError>,
+    {
+        let key = self.key(k);
+        let input = may_deserialize(&store.get(&key))?;
+        let output = action(input)?;
+        store.set(&key, &to_vec(&output)?);
+        Ok(output)
+    }
+}
+
+#[cfg(feature = "iterator")]
+impl<'a, K, T> Map<'a, K, T>
+where
+    K: PrimaryKey,
+    K::Suffix: PrimaryKey,
+    K::SuperSuffix: PrimaryKey,
+    T: Serialize + DeserializeOwned,
+{
+    /// Selects all entries whose key starts with the given prefix, which consists of
+    /// all key elements but the last one.
+    pub fn prefix(&self, p: K::Prefix) -> Prefix<K::Suffix, T> {
+        Prefix::new(self.storage_prefix(&p))
+    }
+
+    /// Selects all entries whose key starts with the given prefix, which consists of
+    /// all key elements but the last two.
+    pub fn sub_prefix(&self, p: K::SubPrefix) -> Prefix<K::SuperSuffix, T> {
+        Prefix::new(self.storage_prefix(&p))
+    }
+
+    fn storage_prefix(&self, p: &impl Prefixer) -> Vec<u8> {
+        let mut out = to_length_prefixed(self.namespace);
+        out.extend(p.joined_prefix());
+        out
+    }
+}
+
+#[cfg(feature = "iterator")]
+impl<'a, K, T> Map<'a, K, T>
+where
+    K: PrimaryKey + KeyDeserialize,
+    T: Serialize + DeserializeOwned,
+{
+    fn no_prefix(&self) -> Prefix<K, T> {
+        Prefix::new(to_length_prefixed(self.namespace))
+    }
+
+    /// Iterates over the raw keys and typed values of the whole map.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn range_raw<'c>(
+        &self,
+        store: &'c dyn Storage,
+        start: Option<K>,
+        end: Option<K>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(Vec<u8>, T)>> + 'c>
+    where
+        T: 'c,
+    {
+        self.no_prefix().range_raw(store, start, end, order)
+    }
+
+    /// Iterates over the typed keys and values of the whole map.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn range<'c>(
+        &self,
+        store: &'c dyn Storage,
+        start: Option<K>,
+        end: Option<K>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(K::Output, T)>> + 'c>
+    where
+        T: 'c,
+        K::Output: 'c,
+    {
+        self.no_prefix().range(store, start, end, order)
+    }
+
+    /// Iterates over the typed keys of the whole map without parsing the values.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn keys<'c>(
+        &self,
+        store: &'c dyn Storage,
+        start: Option<K>,
+        end: Option<K>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<K::Output>> + 'c>
+    where
+        K::Output: 'c,
+    {
+        self.no_prefix().keys(store, start, end, order)
+    }
+
+    /// Iterates over the typed keys and values of the whole map between the given bounds,
+    /// each of which can be inclusive or exclusive.
+    pub fn range_bounded<'c>(
+        &self,
+        store: &'c dyn Storage,
+        min: Option<Bound<K>>,
+        max: Option<Bound<K>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(K::Output, T)>> + 'c>
+    where
+        T: 'c,
+        K::Output: 'c,
+    {
+        self.no_prefix().range_bounded(store, min, max, order)
+    }
+
+    /// Loads a page of typed keys and values of the whole map, see [`Prefix::paginate`].
+    pub fn paginate(
+        &self,
+        store: &dyn Storage,
+        start_after: Option<K>,
+        limit: Option<u32>,
+        max_limit: u32,
+        order: Order,
+    ) -> StdResult<TypedPage<K, T>>
+    where
+        K::Output: Clone,
+    {
+        self.no_prefix()
+            .paginate(store, start_after, limit, max_limit, order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bucket::{bucket, bucket_read, Bucket};
+    use cosmwasm_std::testing::MockStorage;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+    struct Data {
+        pub name: String,
+        pub age: i32,
+    }
+
+    const PEOPLE: Map<&[u8], Data> = Map::new("people");
+    const ALLOWANCE: Map<(&str, &str), u64> = Map::new("allow");
+    const TRIPLE: Map<(&str, u8, &str), u64> = Map::new("triple");
+
+    fn maria() -> Data {
+        Data {
+            name: "Maria".to_string(),
+            age: 42,
+        }
+    }
+
+    #[test]
+    fn save_and_load() {
+        let mut store = MockStorage::new();
+
+        assert!(PEOPLE.load(&store, b"maria").is_err());
+        assert_eq!(PEOPLE.may_load(&store, b"maria").unwrap(), None);
+        assert!(!PEOPLE.has(&store, b"maria"));
+
+        PEOPLE.save(&mut store, b"maria", &maria()).unwrap();
+        assert_eq!(PEOPLE.load(&store, b"maria").unwrap(), maria());
+        assert_eq!(PEOPLE.may_load(&store, b"maria").unwrap(), Some(maria()));
+        assert!(PEOPLE.has(&store, b"maria"));
+
+        // other keys are not affected
+        assert_eq!(PEOPLE.may_load(&store, b"mari").unwrap(), None);
+
+        PEOPLE.remove(&mut store, b"maria");
+        assert_eq!(PEOPLE.may_load(&store, b"maria").unwrap(), None);
+    }
+
+    #[test]
+    fn composite_keys() {
+        let mut store = MockStorage::new();
+
+        ALLOWANCE
+            .save(&mut store, ("owner", "spender"), &1234)
+            .unwrap();
+        ALLOWANCE
+            .save(&mut store, ("owners", "pender"), &5678)
+            .unwrap();
+
+        // no collision even though the concatenation of both parts is equal
+        assert_eq!(ALLOWANCE.load(&store, ("owner", "spender")).unwrap(), 1234);
+        assert_eq!(ALLOWANCE.load(&store, ("owners", "pender")).unwrap(), 5678);
+        assert_eq!(
+            ALLOWANCE.may_load(&store, ("owner", "pender")).unwrap(),
+            None
+        );
+
+        TRIPLE
+            .save(&mut store, ("owner", 10, "recipient"), &1)
+            .unwrap();
+        assert_eq!(TRIPLE.load(&store, ("owner", 10, "recipient")).unwrap(), 1);
+        assert!(!TRIPLE.has(&store, ("owner", 11, "recipient")));
+    }
+
+    #[test]
+    fn update_works() {
+        let mut store = MockStorage::new();
+
+        let add_ten = |a: Option<u64>| -> StdResult<_> { Ok(a.unwrap_or_default() + 10) };
+
+        assert_eq!(
+            ALLOWANCE.update(&mut store, ("a", "b"), add_ten).unwrap(),
+            10
+        );
+        assert_eq!(
+            ALLOWANCE.update(&mut store, ("a", "b"), add_ten).unwrap(),
+            20
+        );
+        assert_eq!(ALLOWANCE.load(&store, ("a", "b")).unwrap(), 20);
+
+        // errors do not change the data
+        let output = ALLOWANCE.update(&mut store, ("a", "b"), |_| {
+            Err(StdError::generic_err("boom"))
+        });
+        assert!(output.is_err());
+        assert_eq!(ALLOWANCE.load(&store, ("a", "b")).unwrap(), 20);
+    }
+
+    #[test]
+    fn compatible_with_bucket() {
+        let mut store = MockStorage::new();
+
+        PEOPLE.save(&mut store, b"maria", &maria()).unwrap();
+        assert_eq!(
+            bucket_read::<Data>(&store, b"people")
+                .load(b"maria")
+                .unwrap(),
+            maria()
+        );
+
+        bucket::<Data>(&mut store, b"people")
+            .save(b"john", &maria())
+            .unwrap();
+        assert_eq!(PEOPLE.load(&store, b"john").unwrap(), maria());
+
+        // composite keys are compatible with multilevel buckets
+        ALLOWANCE
+            .save(&mut store, ("owner", "spender"), &7)
+            .unwrap();
+        let multilevel = Bucket::<u64>::multilevel(&mut store, &[b"allow", b"owner"]);
+        assert_eq!(multilevel.load(b"spender").unwrap(), 7);
+    }
+
+    #[test]
+    #[cfg(feature = "iterator")]
+    fn range_works() {
+        let mut store = MockStorage::new();
+
+        const AGES: Map<&str, u32> = Map::new("ages");
+        AGES.save(&mut store, "john", &32).unwrap();
+        AGES.save(&mut store, "maria", &42).unwrap();
+        AGES.save(&mut store, "alice", &23).unwrap();
+
+        // other namespaces are not included
+        const OTHER: Map<&str, u32> = Map::new("age");
+        OTHER.save(&mut store, "sbob", &1).unwrap();
+
+        let all = AGES
+            .range(&store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            all,
+            vec![
+                ("alice".to_string(), 23),
+                ("john".to_string(), 32),
+                ("maria".to_string(), 42)
+            ]
+        );
+
+        // start is inclusive, end is exclusive
+        let some = AGES
+            .range(&store, Some("john"), Some("maria"), Order::Descending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(some, vec![("john".to_string(), 32)]);
+
+        let keys = AGES
+            .keys(&store, None, None, Order::Descending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(keys, vec!["maria", "john", "alice"]);
+
+        let raw = AGES
+            .range_raw(&store, Some("b"), None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(raw, vec![(b"john".to_vec(), 32), (b"maria".to_vec(), 42)]);
+    }
+
+    #[test]
+    #[cfg(feature = "iterator")]
+    fn range_uses_numeric_order() {
+        let mut store = MockStorage::new();
+
+        const BY_HEIGHT: Map<u64, String> = Map::new("heights");
+        const BY_DELTA: Map<i32, String> = Map::new("deltas");
+        for n in [256u64, 1, 65536, 2] {
+            BY_HEIGHT.save(&mut store, n, &n.to_string()).unwrap();
+        }
+        for n in [-256i32, 1, -1, 0] {
+            BY_DELTA.save(&mut store, n, &n.to_string()).unwrap();
+        }
+
+        let heights = BY_HEIGHT
+            .keys(&store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(heights, vec![1, 2, 256, 65536]);
+
+        let deltas = BY_DELTA
+            .keys(&store, Some(-1), None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(deltas, vec![-1, 0, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "iterator")]
+    fn range_composite_keys() {
+        let mut store = MockStorage::new();
+
+        ALLOWANCE
+            .save(&mut store, ("owner", "spender"), &1)
+            .unwrap();
+        ALLOWANCE.save(&mut store, ("owner", "other"), &2).unwrap();
+        ALLOWANCE
+            .save(&mut store, ("owners", "pender"), &3)
+            .unwrap();
+
+        let all = ALLOWANCE
+            .range(&store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            all,
+            vec![
+                (("owner".to_string(), "other".to_string()), 2),
+                (("owner".to_string(), "spender".to_string()), 1),
+                (("owners".to_string(), "pender".to_string()), 3),
+            ]
+        );
+
+        // prefix only returns the entries of the owner, with the remaining key
+        let owner = ALLOWANCE
+            .prefix("owner")
+            .range(&store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            owner,
+            vec![("other".to_string(), 2), ("spender".to_string(), 1)]
+        );
+
+        let owner_keys = ALLOWANCE
+            .prefix("owner")
+            .keys(&store, Some("p"), None, Order::Descending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(owner_keys, vec!["spender".to_string()]);
+
+        // sub_prefix with an empty prefix iterates over everything
+        let count = ALLOWANCE
+            .sub_prefix(())
+            .range(&store, None, None, Order::Ascending)
+            .count();
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    #[cfg(feature = "iterator")]
+    fn range_triple_keys() {
+        let mut store = MockStorage::new();
+
+        TRIPLE
+            .save(&mut store, ("owner", 9, "recipient"), &1)
+            .unwrap();
+        TRIPLE.save(&mut store, ("owner", 9, "other"), &2).unwrap();
+        TRIPLE
+            .save(&mut store, ("owner", 10, "recipient"), &3)
+            .unwrap();
+        TRIPLE
+            .save(&mut store, ("owners", 9, "recipient"), &4)
+            .unwrap();
+
+        let prefixed = TRIPLE
+            .prefix(("owner", 9))
+            .range(&store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            prefixed,
+            vec![("other".to_string(), 2), ("recipient".to_string(), 1)]
+        );
+
+        let sub_prefixed = TRIPLE
+            .sub_prefix("owner")
+            .range(&store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            sub_prefixed,
+            vec![
+                ((9, "other".to_string()), 2),
+                ((9, "recipient".to_string()), 1),
+                ((10, "recipient".to_string()), 3),
+            ]
+        );
+
+        let all_keys = TRIPLE
+            .keys(&store, None, None, Order::Descending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            all_keys[0],
+            ("owners".to_string(), 9, "recipient".to_string())
+        );
+        assert_eq!(all_keys.len(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "iterator")]
+    fn range_bounded_works() {
+        let mut store = MockStorage::new();
+
+        const HEIGHTS: Map<u64, u64> = Map::new("heights");
+        for n in [1u64, 2, 3, 256, 257] {
+            HEIGHTS.save(&mut store, n, &(n * 10)).unwrap();
+        }
+
+        let keys = |min: Option<Bound<u64>>, max: Option<Bound<u64>>, order: Order| {
+            HEIGHTS
+                .range_bounded(&store, min, max, order)
+                .map(|item| item.map(|(k, _)| k))
+                .collect::<StdResult<Vec<_>>>()
+                .unwrap()
+        };
+        assert_eq!(
+            keys(
+                Some(Bound::Inclusive(2)),
+                Some(Bound::Inclusive(256)),
+                Order::Ascending
+            ),
+            vec![2, 3, 256]
+        );
+        assert_eq!(
+            keys(
+                Some(Bound::Exclusive(2)),
+                Some(Bound::Exclusive(256)),
+                Order::Ascending
+            ),
+            vec![3]
+        );
+        assert_eq!(
+            keys(Some(Bound::Exclusive(3)), None, Order::Descending),
+            vec![257, 256]
+        );
+        assert_eq!(
+            keys(None, Some(Bound::Inclusive(2)), Order::Descending),
+            vec![2, 1]
+        );
+
+        // bounds work on prefixes as well
+        let owner = ALLOWANCE
+            .prefix("owner")
+            .range_bounded(&store, Some(Bound::Exclusive("a")), None, Order::Ascending)
+            .count();
+        assert_eq!(owner, 0);
+        ALLOWANCE.save(&mut store, ("owner", "a"), &1).unwrap();
+        ALLOWANCE.save(&mut store, ("owner", "b"), &2).unwrap();
+        let owner = ALLOWANCE
+            .prefix("owner")
+            .range_bounded(&store, Some(Bound::Exclusive("a")), None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(owner, vec![("b".to_string(), 2)]);
+    }
+
+    #[test]
+    #[cfg(feature = "iterator")]
+    fn paginate_works() {
+        let mut store = MockStorage::new();
+
+        const AGES: Map<&str, u32> = Map::new("ages");
+        for (name, age) in [("alice", 23), ("bob", 31), ("john", 32), ("maria", 42)] {
+            AGES.save(&mut store, name, &age).unwrap();
+        }
+
+        let page = AGES
+            .paginate(&store, None, Some(2), 10, Order::Ascending)
+            .unwrap();
+        assert_eq!(
+            page.items,
+            vec![("alice".to_string(), 23), ("bob".to_string(), 31)]
+        );
+        assert_eq!(page.next_key, Some("bob".to_string()));
+
+        // typed keys can be passed on as start_after
+        let page = AGES
+            .paginate(
+                &store,
+                page.next_key.as_deref(),
+                Some(2),
+                10,
+                Order::Ascending,
+            )
+            .unwrap();
+        assert_eq!(
+            page.items,
+            vec![("john".to_string(), 32), ("maria".to_string(), 42)]
+        );
+        assert_eq!(page.next_key, None);
+
+        let page = AGES
+            .paginate(&store, Some("john"), None, 10, Order::Descending)
+            .unwrap();
+        let names: Vec<_> = page.items.into_iter().map(|(k, _)| k).collect();
+        assert_eq!(names, vec!["bob", "alice"]);
+
+        // pagination over composite keys
+        ALLOWANCE.save(&mut store, ("owner", "a"), &1).unwrap();
+        ALLOWANCE.save(&mut store, ("owner", "b"), &2).unwrap();
+        ALLOWANCE.save(&mut store, ("owner", "c"), &3).unwrap();
+        ALLOWANCE.save(&mut store, ("other", "d"), &4).unwrap();
+        let page = ALLOWANCE
+            .prefix("owner")
+            .paginate(&store, Some("a"), Some(1), 10, Order::Ascending)
+            .unwrap();
+        assert_eq!(page.items, vec![("b".to_string(), 2)]);
+        assert_eq!(page.next_key, Some("b".to_string()));
+
+        let page = ALLOWANCE
+            .paginate(&store, Some(("owner", "a")), None, 2, Order::Descending)
+            .unwrap();
+        assert_eq!(
+            page.items,
+            vec![(("other".to_string(), "d".to_string()), 4)]
+        );
+        assert_eq!(page.next_key, None);
+    }
+
+    #[test]
+    #[cfg(feature = "iterator")]
+    fn range_with_addr_keys() {
+        use cosmwasm_std::Addr;
+
+        let mut store = MockStorage::new();
+
+        const BALANCES: Map<&Addr, u128> = Map::new("balances");
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+        BALANCES.save(&mut store, &bob, &5).unwrap();
+        BALANCES.save(&mut store, &alice, &7).unwrap();
+
+        let all = BALANCES
+            .range(&store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(all, vec![(alice, 7), (bob, 5)]);
+    }
+}
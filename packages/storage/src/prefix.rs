@@ -0,0 +1,161 @@
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+use cosmwasm_std::{collect_page, from_slice, page_limit, Bound, Order, Page, StdResult, Storage};
+
+use crate::keys::{KeyDeserialize, PrimaryKey};
+use crate::namespace_helpers::range_with_prefix;
+
+/// A page of typed keys and values, as returned by [`Prefix::paginate`] and
+/// [`Map::paginate`](crate::Map::paginate).
+pub type TypedPage<K, T> = Page<(<K as KeyDeserialize>::Output, T), <K as KeyDeserialize>::Output>;
+
+/// A sub-range of a [`Map`](crate::Map), selected by the first elements of the key.
+///
+/// The keys returned by the iterators are the remaining elements of the key, i.e.
+/// the key without the prefix.
+pub struct Prefix<K, T> {
+    /// the full storage prefix, including the length prefixed namespace
+    storage_prefix: Vec<u8>,
+    // see https://doc.rust-lang.org/std/marker/struct.PhantomData.html#unused-type-parameters for why this is needed
+    key_type: PhantomData<K>,
+    data_type: PhantomData<T>,
+}
+
+impl<K, T> Prefix<K, T>
+where
+    K: PrimaryKey + KeyDeserialize,
+    T: DeserializeOwned,
+{
+    pub(crate) fn new(storage_prefix: Vec<u8>) -> Self {
+        Prefix {
+            storage_prefix,
+            key_type: PhantomData,
+            data_type: PhantomData,
+        }
+    }
+
+    /// Iterates over the raw keys and values of this prefix, where keys
+    /// are relative to the prefix.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn range_raw<'a>(
+        &self,
+        store: &'a dyn Storage,
+        start: Option<K>,
+        end: Option<K>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(Vec<u8>, T)>> + 'a>
+    where
+        T: 'a,
+    {
+        let start = start.map(|k| k.joined_key());
+        let end = end.map(|k| k.joined_key());
+        let mapped = range_with_prefix(
+            store,
+            &self.storage_prefix,
+            start.as_deref(),
+            end.as_deref(),
+            order,
+        )
+        .map(|(k, v)| Ok((k, from_slice::<T>(&v)?)));
+        Box::new(mapped)
+    }
+
+    /// Iterates over the typed keys and values of this prefix.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn range<'a>(
+        &self,
+        store: &'a dyn Storage,
+        start: Option<K>,
+        end: Option<K>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(K::Output, T)>> + 'a>
+    where
+        T: 'a,
+        K::Output: 'a,
+    {
+        let mapped = self
+            .range_raw(store, start, end, order)
+            .map(|item| item.and_then(|(k, v)| Ok((K::from_vec(k)?, v))));
+        Box::new(mapped)
+    }
+
+    /// Iterates over the typed keys of this prefix without parsing the values.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn keys<'a>(
+        &self,
+        store: &'a dyn Storage,
+        start: Option<K>,
+        end: Option<K>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<K::Output>> + 'a>
+    where
+        K::Output: 'a,
+    {
+        let start = start.map(|k| k.joined_key());
+        let end = end.map(|k| k.joined_key());
+        let mapped = range_with_prefix(
+            store,
+            &self.storage_prefix,
+            start.as_deref(),
+            end.as_deref(),
+            order,
+        )
+        .map(|(k, _)| K::from_vec(k));
+        Box::new(mapped)
+    }
+
+    /// Iterates over the typed keys and values of this prefix between the given bounds,
+    /// each of which can be inclusive or exclusive.
+    pub fn range_bounded<'a>(
+        &self,
+        store: &'a dyn Storage,
+        min: Option<Bound<K>>,
+        max: Option<Bound<K>>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(K::Output, T)>> + 'a>
+    where
+        T: 'a,
+        K::Output: 'a,
+    {
+        let start = min.map(|b| b.map(|k| k.joined_key()).to_start_key());
+        let end = max.map(|b| b.map(|k| k.joined_key()).to_end_key());
+        let mapped = range_with_prefix(
+            store,
+            &self.storage_prefix,
+            start.as_deref(),
+            end.as_deref(),
+            order,
+        )
+        .map(|(k, v)| Ok((K::from_vec(k)?, from_slice::<T>(&v)?)));
+        Box::new(mapped)
+    }
+
+    /// Loads a page of typed keys and values of this prefix.
+    ///
+    /// The page starts after the key `start_after` in the given order, i.e. for descending
+    /// order it contains keys lower than `start_after`. At most `limit` entries are returned,
+    /// but never more than `max_limit`. If `limit` is not set, `max_limit` is used.
+    /// A limit of 0 is treated as 1, see [`page_limit`](cosmwasm_std::page_limit).
+    pub fn paginate(
+        &self,
+        store: &dyn Storage,
+        start_after: Option<K>,
+        limit: Option<u32>,
+        max_limit: u32,
+        order: Order,
+    ) -> StdResult<TypedPage<K, T>>
+    where
+        K::Output: Clone,
+    {
+        let after = start_after.map(Bound::Exclusive);
+        let iter = match order {
+            Order::Ascending => self.range_bounded(store, after, None, order),
+            Order::Descending => self.range_bounded(store, None, after, order),
+        };
+        collect_page(iter, page_limit(limit, max_limit), |(k, _)| k.clone())
+    }
+}
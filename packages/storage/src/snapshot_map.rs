@@ -0,0 +1,454 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use cosmwasm_std::{to_vec, Order, StdError, StdResult, Storage};
+
+use crate::keys::{KeyDeserialize, PrimaryKey};
+use crate::map::Map;
+use crate::type_helpers::may_deserialize;
+
+/// Defines at which heights a [`SnapshotMap`] records its changelog.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// Record the value of every key at the beginning of every block in which it changes
+    EveryBlock,
+    /// Never record a changelog. Historical queries are not possible.
+    Never,
+    /// Only record the changelog at heights that were registered via
+    /// [`SnapshotMap::add_checkpoint`]
+    Selected,
+}
+
+/// The value of a key before the first change at a given height.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ChangeSet<T> {
+    pub old: Option<T>,
+}
+
+/// SnapshotMap is a [`Map`] that can also be queried at past block heights.
+///
+/// Every write takes the current block height (usually `env.block.height`). Before
+/// the first change of a key at a height, the previous value is stored in a changelog
+/// if the [`Strategy`] asks for it. The value "at height H" is the value at the
+/// beginning of block H, i.e. before any changes made in that block.
+///
+/// # Examples
+///
+/// ```
+/// # use cosmwasm_std::{testing::MockStorage, Addr};
+/// use cosmwasm_storage::{SnapshotMap, Strategy};
+///
+/// const BALANCES: SnapshotMap<&Addr, u128> =
+///     SnapshotMap::new("balances", "balances__check", "balances__change", Strategy::EveryBlock);
+///
+/// let mut storage = MockStorage::new();
+/// let alice = Addr::unchecked("alice");
+/// BALANCES.save(&mut storage, &alice, &100, 10).unwrap();
+/// BALANCES.save(&mut storage, &alice, &50, 20).unwrap();
+///
+/// assert_eq!(BALANCES.may_load_at_height(&storage, &alice, 10).unwrap(), None);
+/// assert_eq!(BALANCES.may_load_at_height(&storage, &alice, 15).unwrap(), Some(100));
+/// assert_eq!(BALANCES.may_load_at_height(&storage, &alice, 21).unwrap(), Some(50));
+/// ```
+pub struct SnapshotMap<'a, K, T> {
+    primary: Map<'a, K, T>,
+    /// the number of times a checkpoint was added at a given height
+    checkpoints: Map<'a, u64, u32>,
+    /// the changelog, indexed by the raw primary key and the height
+    changelog: Map<'a, (Vec<u8>, u64), ChangeSet<T>>,
+    strategy: Strategy,
+}
+
+impl<'a, K, T> SnapshotMap<'a, K, T> {
+    pub const fn new(
+        pk_namespace: &'a str,
+        checkpoints_namespace: &'a str,
+        changelog_namespace: &'a str,
+        strategy: Strategy,
+    ) -> Self {
+        SnapshotMap {
+            primary: Map::new(pk_namespace),
+            checkpoints: Map::new(checkpoints_namespace),
+            changelog: Map::new(changelog_namespace),
+            strategy,
+        }
+    }
+
+    /// Registers a checkpoint at the given height. Checkpoints are counted, such that
+    /// multiple users of the same height can add and remove them independently.
+    pub fn add_checkpoint(&self, store: &mut dyn Storage, height: u64) -> StdResult<()> {
+        self.checkpoints
+            .update::<_, StdError>(store, height, |count| Ok(count.unwrap_or_default() + 1))?;
+        Ok(())
+    }
+
+    /// Removes a checkpoint that was added via [`SnapshotMap::add_checkpoint`].
+    pub fn remove_checkpoint(&self, store: &mut dyn Storage, height: u64) -> StdResult<()> {
+        let count = self
+            .checkpoints
+            .may_load(store, height)?
+            .unwrap_or_default();
+        if count <= 1 {
+            self.checkpoints.remove(store, height);
+        } else {
+            self.checkpoints.save(store, height, &(count - 1))?;
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the values at the given height cannot be queried
+    /// with the strategy of this map.
+    pub fn assert_checkpointed(&self, store: &dyn Storage, height: u64) -> StdResult<()> {
+        let has = match self.strategy {
+            Strategy::EveryBlock => true,
+            Strategy::Never => false,
+            Strategy::Selected => self.checkpoints.has(store, height),
+        };
+        if has {
+            Ok(())
+        } else {
+            Err(StdError::not_found("checkpoint"))
+        }
+    }
+}
+
+impl<'a, K, T> SnapshotMap<'a, K, T>
+where
+    K: PrimaryKey,
+    T: Serialize + DeserializeOwned,
+{
+    /// save will serialize the model and store, recording the previous value in the
+    /// changelog if required. Returns an error on serialization issues.
+    pub fn save(&self, store: &mut dyn Storage, k: K, data: &T, height: u64) -> StdResult<()> {
+        let pk = k.joined_key();
+        let key = self.primary.key(k);
+        self.write_changelog(store, &pk, &key, height)?;
+        store.set(&key, &to_vec(data)?);
+        Ok(())
+    }
+
+    /// remove deletes the value, recording the previous value in the changelog if required
+    pub fn remove(&self, store: &mut dyn Storage, k: K, height: u64) -> StdResult<()> {
+        let pk = k.joined_key();
+        let key = self.primary.key(k);
+        self.write_changelog(store, &pk, &key, height)?;
+        store.remove(&key);
+        Ok(())
+    }
+
+    /// Loads the data, perform the specified action, and store the result
+    /// in the database, recording the previous value in the changelog if required.
+    ///
+    /// If the data exists, `action(Some(value))` is called. Otherwise `action(None)` is called.
+    pub fn update<A, E>(
+        &self,
+        store: &mut dyn Storage,
+        k: K,
+        height: u64,
+        action: A,
+    ) -> Result<T, E>
+    where
+        A: FnOnce(Option<T>) -> Result<T, E>,
+        E: From<StdError>,
+    {
+        let pk = k.joined_key();
+        let key = self.primary.key(k);
+        let input = may_deserialize(&store.get(&key))?;
+        let output = action(input)?;
+        self.write_changelog(store, &pk, &key, height)?;
+        store.set(&key, &to_vec(&output)?);
+        Ok(output)
+    }
+
+    /// load will return an error if no data is set at the given key, or on parse error
+    pub fn load(&self, store: &dyn Storage, k: K) -> StdResult<T> {
+        self.primary.load(store, k)
+    }
+
+    /// may_load will parse the data stored at the key if present, returns Ok(None) if no data there.
+    /// returns an error on issues parsing
+    pub fn may_load(&self, store: &dyn Storage, k: K) -> StdResult<Option<T>> {
+        self.primary.may_load(store, k)
+    }
+
+    /// Returns true if a value is stored at the given key, without parsing it
+    pub fn has(&self, store: &dyn Storage, k: K) -> bool {
+        self.primary.has(store, k)
+    }
+
+    /// Returns the value at the beginning of the given height, i.e. before any
+    /// changes that were made at that height.
+    ///
+    /// Returns an error if the height cannot be queried, see [`SnapshotMap::assert_checkpointed`].
+    pub fn may_load_at_height(
+        &self,
+        store: &dyn Storage,
+        k: K,
+        height: u64,
+    ) -> StdResult<Option<T>> {
+        self.assert_checkpointed(store, height)?;
+
+        let pk = k.joined_key();
+        // the first change at or after the given height holds the value at that height
+        let first_change = self
+            .changelog
+            .prefix(pk)
+            .range(store, Some(height), None, Order::Ascending)
+            .next()
+            .transpose()?;
+        match first_change {
+            Some((_, changeset)) => Ok(changeset.old),
+            None => self.primary.may_load(store, k),
+        }
+    }
+
+    /// Stores the current value in the changelog, unless this is not required by
+    /// the strategy or the value at this height was already recorded.
+    fn write_changelog(
+        &self,
+        store: &mut dyn Storage,
+        pk: &[u8],
+        key: &[u8],
+        height: u64,
+    ) -> StdResult<()> {
+        let should_write = match self.strategy {
+            Strategy::EveryBlock => !self.changelog.has(store, (pk.to_vec(), height)),
+            Strategy::Never => false,
+            Strategy::Selected => self.should_checkpoint_selected(store, pk)?,
+        };
+        if should_write {
+            let old = may_deserialize(&store.get(key))?;
+            self.changelog
+                .save(store, (pk.to_vec(), height), &ChangeSet { old })?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if there is a checkpoint and the key was not changed since
+    /// the most recent one.
+    fn should_checkpoint_selected(&self, store: &dyn Storage, pk: &[u8]) -> StdResult<bool> {
+        let latest_checkpoint = self
+            .checkpoints
+            .keys(store, None, None, Order::Descending)
+            .next()
+            .transpose()?;
+        match latest_checkpoint {
+            Some(checkpoint) => {
+                let changed_since = self
+                    .changelog
+                    .prefix(pk.to_vec())
+                    .keys(store, Some(checkpoint), None, Order::Ascending)
+                    .next()
+                    .is_some();
+                Ok(!changed_since)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+impl<'a, K, T> SnapshotMap<'a, K, T>
+where
+    K: PrimaryKey + KeyDeserialize,
+    T: Serialize + DeserializeOwned,
+{
+    /// Iterates over the current typed keys and values of the whole map.
+    ///
+    /// `start` is inclusive and `end` is exclusive.
+    pub fn range<'c>(
+        &self,
+        store: &'c dyn Storage,
+        start: Option<K>,
+        end: Option<K>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = StdResult<(K::Output, T)>> + 'c>
+    where
+        T: 'c,
+        K::Output: 'c,
+    {
+        self.primary.range(store, start, end, order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::testing::MockStorage;
+
+    const EVERY: SnapshotMap<&str, u64> = SnapshotMap::new(
+        "every",
+        "every__check",
+        "every__change",
+        Strategy::EveryBlock,
+    );
+    const NEVER: SnapshotMap<&str, u64> =
+        SnapshotMap::new("never", "never__check", "never__change", Strategy::Never);
+    const SELECTED: SnapshotMap<&str, u64> = SnapshotMap::new(
+        "selected",
+        "selected__check",
+        "selected__change",
+        Strategy::Selected,
+    );
+
+    // Writes the same history to a map:
+    //
+    // height 1: A = 5
+    // height 2: B = 7
+    // height 3: A = 8, C = 13, A = 9 (twice in the same block)
+    // height 5: B removed, C = 14
+    fn init_data(map: &SnapshotMap<&str, u64>, store: &mut dyn Storage) {
+        map.save(store, "A", &5, 1).unwrap();
+        map.save(store, "B", &7, 2).unwrap();
+        map.save(store, "A", &8, 3).unwrap();
+        map.save(store, "C", &13, 3).unwrap();
+        map.save(store, "A", &9, 3).unwrap();
+        map.remove(store, "B", 5).unwrap();
+        map.update(store, "C", 5, |c| -> StdResult<_> { Ok(c.unwrap() + 1) })
+            .unwrap();
+    }
+
+    fn assert_current(map: &SnapshotMap<&str, u64>, store: &dyn Storage) {
+        assert_eq!(map.load(store, "A").unwrap(), 9);
+        assert_eq!(map.may_load(store, "B").unwrap(), None);
+        assert_eq!(map.load(store, "C").unwrap(), 14);
+        assert!(!map.has(store, "D"));
+    }
+
+    #[test]
+    fn every_block_works() {
+        let mut store = MockStorage::new();
+        init_data(&EVERY, &mut store);
+        assert_current(&EVERY, &store);
+
+        // (key, height, expected value at the beginning of the height)
+        let expected = [
+            ("A", 1, None),
+            ("A", 2, Some(5)),
+            ("A", 3, Some(5)),
+            ("A", 4, Some(9)),
+            ("A", 100, Some(9)),
+            ("B", 2, None),
+            ("B", 3, Some(7)),
+            ("B", 5, Some(7)),
+            ("B", 6, None),
+            ("C", 3, None),
+            ("C", 4, Some(13)),
+            ("C", 5, Some(13)),
+            ("C", 6, Some(14)),
+            ("D", 3, None),
+        ];
+        for (key, height, value) in expected {
+            assert_eq!(
+                EVERY.may_load_at_height(&store, key, height).unwrap(),
+                value,
+                "key {} at height {}",
+                key,
+                height
+            );
+        }
+    }
+
+    #[test]
+    fn never_works() {
+        let mut store = MockStorage::new();
+        init_data(&NEVER, &mut store);
+        assert_current(&NEVER, &store);
+
+        let err = NEVER.may_load_at_height(&store, "A", 3).unwrap_err();
+        assert!(matches!(err, StdError::NotFound { .. }));
+
+        // adding a checkpoint does not change the strategy
+        NEVER.add_checkpoint(&mut store, 3).unwrap();
+        assert!(NEVER.may_load_at_height(&store, "A", 3).is_err());
+    }
+
+    #[test]
+    fn selected_works() {
+        let mut store = MockStorage::new();
+
+        // nothing is recorded without a checkpoint
+        SELECTED.save(&mut store, "A", &1, 1).unwrap();
+        assert!(SELECTED.may_load_at_height(&store, "A", 1).is_err());
+
+        SELECTED.add_checkpoint(&mut store, 3).unwrap();
+        SELECTED.save(&mut store, "A", &2, 3).unwrap();
+        SELECTED.save(&mut store, "A", &3, 4).unwrap();
+        SELECTED.add_checkpoint(&mut store, 6).unwrap();
+        SELECTED.save(&mut store, "B", &10, 6).unwrap();
+        SELECTED.save(&mut store, "A", &4, 7).unwrap();
+        SELECTED.save(&mut store, "A", &5, 8).unwrap();
+
+        assert_eq!(
+            SELECTED.may_load_at_height(&store, "A", 3).unwrap(),
+            Some(1)
+        );
+        assert_eq!(
+            SELECTED.may_load_at_height(&store, "A", 6).unwrap(),
+            Some(3)
+        );
+        assert_eq!(SELECTED.may_load_at_height(&store, "B", 3).unwrap(), None);
+        assert_eq!(SELECTED.may_load_at_height(&store, "B", 6).unwrap(), None);
+        assert_eq!(SELECTED.load(&store, "A").unwrap(), 5);
+
+        // heights without checkpoint cannot be queried
+        let err = SELECTED.may_load_at_height(&store, "A", 4).unwrap_err();
+        assert!(matches!(err, StdError::NotFound { .. }));
+    }
+
+    #[test]
+    fn checkpoints_are_counted() {
+        let mut store = MockStorage::new();
+
+        SELECTED.add_checkpoint(&mut store, 3).unwrap();
+        SELECTED.add_checkpoint(&mut store, 3).unwrap();
+        SELECTED.assert_checkpointed(&store, 3).unwrap();
+
+        SELECTED.remove_checkpoint(&mut store, 3).unwrap();
+        SELECTED.assert_checkpointed(&store, 3).unwrap();
+        SELECTED.remove_checkpoint(&mut store, 3).unwrap();
+        assert!(SELECTED.assert_checkpointed(&store, 3).is_err());
+
+        // removing a missing checkpoint is a no-op
+        SELECTED.remove_checkpoint(&mut store, 3).unwrap();
+    }
+
+    #[test]
+    fn composite_keys_work() {
+        let mut store = MockStorage::new();
+
+        const ALLOWANCES: SnapshotMap<(&str, &str), u64> = SnapshotMap::new(
+            "allow",
+            "allow__check",
+            "allow__change",
+            Strategy::EveryBlock,
+        );
+        ALLOWANCES.save(&mut store, ("a", "b"), &1, 1).unwrap();
+        ALLOWANCES.save(&mut store, ("a", "bc"), &2, 1).unwrap();
+        ALLOWANCES.save(&mut store, ("a", "b"), &3, 2).unwrap();
+
+        assert_eq!(
+            ALLOWANCES
+                .may_load_at_height(&store, ("a", "b"), 2)
+                .unwrap(),
+            Some(1)
+        );
+        assert_eq!(
+            ALLOWANCES
+                .may_load_at_height(&store, ("a", "bc"), 2)
+                .unwrap(),
+            Some(2)
+        );
+
+        let all = ALLOWANCES
+            .range(&store, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            all,
+            vec![
+                (("a".to_string(), "b".to_string()), 3),
+                (("a".to_string(), "bc".to_string()), 2)
+            ]
+        );
+    }
+}
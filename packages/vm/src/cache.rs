@@ -14,7 +14,10 @@ use crate::filesystem::mkdir_p;
 use crate::instance::{Instance, InstanceOptions};
 use crate::modules::{FileSystemCache, InMemoryCache, PinnedMemoryCache};
 use crate::size::Size;
-use crate::static_analysis::{deserialize_wasm, has_ibc_entry_points};
+use crate::static_analysis::{
+    deserialize_wasm, has_ibc_destination_callback_entry_point, has_ibc_entry_points,
+    has_ibc_source_callback_entry_point,
+};
 use crate::wasm_backend::{compile, make_runtime_store};
 
 const STATE_DIR: &str = "state";
@@ -83,6 +86,10 @@ pub struct Cache<A: BackendApi, S: Storage, Q: Querier> {
 #[derive(PartialEq, Eq, Debug)]
 pub struct AnalysisReport {
     pub has_ibc_entry_points: bool,
+    /// `true` if and only if the contract exports the `ibc_source_callback` entry point
+    pub has_ibc_source_callback_entry_point: bool,
+    /// `true` if and only if the contract exports the `ibc_destination_callback` entry point
+    pub has_ibc_destination_callback_entry_point: bool,
     pub required_capabilities: HashSet<String>,
 }
 
@@ -190,6 +197,10 @@ where
         let module = deserialize_wasm(&wasm)?;
         Ok(AnalysisReport {
             has_ibc_entry_points: has_ibc_entry_points(&module),
+            has_ibc_source_callback_entry_point: has_ibc_source_callback_entry_point(&module),
+            has_ibc_destination_callback_entry_point: has_ibc_destination_callback_entry_point(
+                &module,
+            ),
             required_capabilities: required_capabilities_from_module(&module),
         })
     }
@@ -999,6 +1010,8 @@ mod tests {
             report1,
             AnalysisReport {
                 has_ibc_entry_points: false,
+                has_ibc_source_callback_entry_point: false,
+                has_ibc_destination_callback_entry_point: false,
                 required_capabilities: HashSet::new(),
             }
         );
@@ -1009,6 +1022,8 @@ mod tests {
             report2,
             AnalysisReport {
                 has_ibc_entry_points: true,
+                has_ibc_source_callback_entry_point: false,
+                has_ibc_destination_callback_entry_point: false,
                 required_capabilities: HashSet::from_iter(vec![
                     "iterator".to_string(),
                     "staking".to_string(),
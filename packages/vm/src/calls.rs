@@ -5,8 +5,8 @@ use cosmwasm_std::{ContractResult, CustomMsg, Env, MessageInfo, QueryResponse, R
 #[cfg(feature = "stargate")]
 use cosmwasm_std::{
     Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
-    IbcChannelOpenMsg, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg,
-    IbcReceiveResponse,
+    IbcChannelOpenMsg, IbcDestinationCallbackMsg, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, IbcSourceCallbackMsg,
 };
 
 use crate::backend::{BackendApi, Querier, Storage};
@@ -54,6 +54,12 @@ mod read_limits {
     /// Max length (in bytes) of the result data from a ibc_packet_timeout call.
     #[cfg(feature = "stargate")]
     pub const RESULT_IBC_PACKET_TIMEOUT: usize = 64 * MI;
+    /// Max length (in bytes) of the result data from a ibc_source_callback call.
+    #[cfg(feature = "stargate")]
+    pub const RESULT_IBC_SOURCE_CALLBACK: usize = 64 * MI;
+    /// Max length (in bytes) of the result data from a ibc_destination_callback call.
+    #[cfg(feature = "stargate")]
+    pub const RESULT_IBC_DESTINATION_CALLBACK: usize = 64 * MI;
 }
 
 /// The limits for the JSON deserialization.
@@ -93,6 +99,12 @@ mod deserialization_limits {
     /// Max length (in bytes) of the result data from a ibc_packet_timeout call.
     #[cfg(feature = "stargate")]
     pub const RESULT_IBC_PACKET_TIMEOUT: usize = 256 * KI;
+    /// Max length (in bytes) of the result data from a ibc_source_callback call.
+    #[cfg(feature = "stargate")]
+    pub const RESULT_IBC_SOURCE_CALLBACK: usize = 256 * KI;
+    /// Max length (in bytes) of the result data from a ibc_destination_callback call.
+    #[cfg(feature = "stargate")]
+    pub const RESULT_IBC_DESTINATION_CALLBACK: usize = 256 * KI;
 }
 
 pub fn call_instantiate<A, S, Q, U>(
@@ -328,6 +340,47 @@ where
     Ok(result)
 }
 
+#[cfg(feature = "stargate")]
+pub fn call_ibc_source_callback<A, S, Q, U>(
+    instance: &mut Instance<A, S, Q>,
+    env: &Env,
+    msg: &IbcSourceCallbackMsg,
+) -> VmResult<ContractResult<IbcBasicResponse<U>>>
+where
+    A: BackendApi + 'static,
+    S: Storage + 'static,
+    Q: Querier + 'static,
+    U: DeserializeOwned + CustomMsg,
+{
+    let env = to_vec(env)?;
+    let msg = to_vec(msg)?;
+    let data = call_ibc_source_callback_raw(instance, &env, &msg)?;
+    let result = from_slice(&data, deserialization_limits::RESULT_IBC_SOURCE_CALLBACK)?;
+    Ok(result)
+}
+
+#[cfg(feature = "stargate")]
+pub fn call_ibc_destination_callback<A, S, Q, U>(
+    instance: &mut Instance<A, S, Q>,
+    env: &Env,
+    msg: &IbcDestinationCallbackMsg,
+) -> VmResult<ContractResult<IbcBasicResponse<U>>>
+where
+    A: BackendApi + 'static,
+    S: Storage + 'static,
+    Q: Querier + 'static,
+    U: DeserializeOwned + CustomMsg,
+{
+    let env = to_vec(env)?;
+    let msg = to_vec(msg)?;
+    let data = call_ibc_destination_callback_raw(instance, &env, &msg)?;
+    let result = from_slice(
+        &data,
+        deserialization_limits::RESULT_IBC_DESTINATION_CALLBACK,
+    )?;
+    Ok(result)
+}
+
 /// Calls Wasm export "instantiate" and returns raw data from the contract.
 /// The result is length limited to prevent abuse but otherwise unchecked.
 pub fn call_instantiate_raw<A, S, Q>(
@@ -561,6 +614,46 @@ where
     )
 }
 
+#[cfg(feature = "stargate")]
+pub fn call_ibc_source_callback_raw<A, S, Q>(
+    instance: &mut Instance<A, S, Q>,
+    env: &[u8],
+    msg: &[u8],
+) -> VmResult<Vec<u8>>
+where
+    A: BackendApi + 'static,
+    S: Storage + 'static,
+    Q: Querier + 'static,
+{
+    instance.set_storage_readonly(false);
+    call_raw(
+        instance,
+        "ibc_source_callback",
+        &[env, msg],
+        read_limits::RESULT_IBC_SOURCE_CALLBACK,
+    )
+}
+
+#[cfg(feature = "stargate")]
+pub fn call_ibc_destination_callback_raw<A, S, Q>(
+    instance: &mut Instance<A, S, Q>,
+    env: &[u8],
+    msg: &[u8],
+) -> VmResult<Vec<u8>>
+where
+    A: BackendApi + 'static,
+    S: Storage + 'static,
+    Q: Querier + 'static,
+{
+    instance.set_storage_readonly(false);
+    call_raw(
+        instance,
+        "ibc_destination_callback",
+        &[env, msg],
+        read_limits::RESULT_IBC_DESTINATION_CALLBACK,
+    )
+}
+
 /// Calls a function with the given arguments.
 /// The exported function must return exactly one result (an offset to the result Region).
 pub(crate) fn call_raw<A, S, Q>(
@@ -35,8 +35,10 @@ pub use crate::calls::{
 pub use crate::calls::{
     call_ibc_channel_close, call_ibc_channel_close_raw, call_ibc_channel_connect,
     call_ibc_channel_connect_raw, call_ibc_channel_open, call_ibc_channel_open_raw,
-    call_ibc_packet_ack, call_ibc_packet_ack_raw, call_ibc_packet_receive,
-    call_ibc_packet_receive_raw, call_ibc_packet_timeout, call_ibc_packet_timeout_raw,
+    call_ibc_destination_callback, call_ibc_destination_callback_raw, call_ibc_packet_ack,
+    call_ibc_packet_ack_raw, call_ibc_packet_receive, call_ibc_packet_receive_raw,
+    call_ibc_packet_timeout, call_ibc_packet_timeout_raw, call_ibc_source_callback,
+    call_ibc_source_callback_raw,
 };
 pub use crate::capabilities::capabilities_from_csv;
 pub use crate::checksum::Checksum;
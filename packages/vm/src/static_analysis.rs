@@ -82,6 +82,22 @@ pub fn has_ibc_entry_points(module: &impl ExportInfo) -> bool {
         .all(|required| available_exports.contains(*required))
 }
 
+/// Returns true if and only if the `ibc_source_callback` entry point exists as an
+/// exported function. This does not check the signature of the entry point.
+pub fn has_ibc_source_callback_entry_point(module: &impl ExportInfo) -> bool {
+    module
+        .exported_function_names(None)
+        .contains("ibc_source_callback")
+}
+
+/// Returns true if and only if the `ibc_destination_callback` entry point exists as an
+/// exported function. This does not check the signature of the entry point.
+pub fn has_ibc_destination_callback_entry_point(module: &impl ExportInfo) -> bool {
+    module
+        .exported_function_names(None)
+        .contains("ibc_destination_callback")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -306,4 +322,52 @@ mod tests {
         let module = deserialize_wasm(&wasm).unwrap();
         assert!(!has_ibc_entry_points(&module));
     }
+    #[test]
+    fn has_ibc_callback_entry_points_works() {
+        // Source callback only
+        let wasm = wat::parse_str(
+            r#"(module
+                (memory 3)
+                (export "memory" (memory 0))
+
+                (type (func))
+                (func (type 0) nop)
+                (export "interface_version_8" (func 0))
+                (export "instantiate" (func 0))
+                (export "allocate" (func 0))
+                (export "deallocate" (func 0))
+                (export "ibc_source_callback" (func 0))
+            )"#,
+        )
+        .unwrap();
+        let module = deserialize_wasm(&wasm).unwrap();
+        assert!(has_ibc_source_callback_entry_point(&module));
+        assert!(!has_ibc_destination_callback_entry_point(&module));
+        assert!(!has_ibc_entry_points(&module));
+
+        // Destination callback only
+        let wasm = wat::parse_str(
+            r#"(module
+                (memory 3)
+                (export "memory" (memory 0))
+
+                (type (func))
+                (func (type 0) nop)
+                (export "interface_version_8" (func 0))
+                (export "instantiate" (func 0))
+                (export "allocate" (func 0))
+                (export "deallocate" (func 0))
+                (export "ibc_destination_callback" (func 0))
+            )"#,
+        )
+        .unwrap();
+        let module = deserialize_wasm(&wasm).unwrap();
+        assert!(!has_ibc_source_callback_entry_point(&module));
+        assert!(has_ibc_destination_callback_entry_point(&module));
+
+        // Neither
+        let module = deserialize_wasm(CONTRACT).unwrap();
+        assert!(!has_ibc_source_callback_entry_point(&module));
+        assert!(!has_ibc_destination_callback_entry_point(&module));
+    }
 }
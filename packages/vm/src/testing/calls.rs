@@ -8,8 +8,8 @@ use cosmwasm_std::{ContractResult, CustomMsg, Env, MessageInfo, QueryResponse, R
 #[cfg(feature = "stargate")]
 use cosmwasm_std::{
     Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
-    IbcChannelOpenMsg, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg,
-    IbcReceiveResponse,
+    IbcChannelOpenMsg, IbcDestinationCallbackMsg, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, IbcSourceCallbackMsg,
 };
 
 use crate::calls::{
@@ -17,8 +17,9 @@ use crate::calls::{
 };
 #[cfg(feature = "stargate")]
 use crate::calls::{
-    call_ibc_channel_close, call_ibc_channel_connect, call_ibc_channel_open, call_ibc_packet_ack,
-    call_ibc_packet_receive, call_ibc_packet_timeout,
+    call_ibc_channel_close, call_ibc_channel_connect, call_ibc_channel_open,
+    call_ibc_destination_callback, call_ibc_packet_ack, call_ibc_packet_receive,
+    call_ibc_packet_timeout, call_ibc_source_callback,
 };
 use crate::instance::Instance;
 use crate::serde::to_vec;
@@ -243,3 +244,39 @@ where
 {
     call_ibc_packet_timeout(instance, &env, &msg).expect("VM error")
 }
+
+// ibc_source_callback mimicks the call signature of the smart contracts.
+// thus it moves env and msg rather than take them as reference.
+// this is inefficient here, but only used in test code
+#[cfg(feature = "stargate")]
+pub fn ibc_source_callback<A, S, Q, U>(
+    instance: &mut Instance<A, S, Q>,
+    env: Env,
+    msg: IbcSourceCallbackMsg,
+) -> ContractResult<IbcBasicResponse<U>>
+where
+    A: BackendApi + 'static,
+    S: Storage + 'static,
+    Q: Querier + 'static,
+    U: DeserializeOwned + CustomMsg,
+{
+    call_ibc_source_callback(instance, &env, &msg).expect("VM error")
+}
+
+// ibc_destination_callback mimicks the call signature of the smart contracts.
+// thus it moves env and msg rather than take them as reference.
+// this is inefficient here, but only used in test code
+#[cfg(feature = "stargate")]
+pub fn ibc_destination_callback<A, S, Q, U>(
+    instance: &mut Instance<A, S, Q>,
+    env: Env,
+    msg: IbcDestinationCallbackMsg,
+) -> ContractResult<IbcBasicResponse<U>>
+where
+    A: BackendApi + 'static,
+    S: Storage + 'static,
+    Q: Querier + 'static,
+    U: DeserializeOwned + CustomMsg,
+{
+    call_ibc_destination_callback(instance, &env, &msg).expect("VM error")
+}
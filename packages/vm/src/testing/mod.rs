@@ -9,8 +9,8 @@ mod storage;
 pub use calls::{execute, instantiate, migrate, query, reply, sudo};
 #[cfg(feature = "stargate")]
 pub use calls::{
-    ibc_channel_close, ibc_channel_connect, ibc_channel_open, ibc_packet_ack, ibc_packet_receive,
-    ibc_packet_timeout,
+    ibc_channel_close, ibc_channel_connect, ibc_channel_open, ibc_destination_callback,
+    ibc_packet_ack, ibc_packet_receive, ibc_packet_timeout, ibc_source_callback,
 };
 pub use instance::{
     mock_instance, mock_instance_options, mock_instance_with_balances,
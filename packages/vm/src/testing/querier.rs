@@ -52,6 +52,11 @@ impl<C: CustomQuery + DeserializeOwned> MockQuerier<C> {
         self.querier.update_wasm(handler)
     }
 
+    #[cfg(feature = "stargate")]
+    pub fn update_ibc(&mut self, ibc: cosmwasm_std::testing::IbcQuerier) {
+        self.querier.update_ibc(ibc)
+    }
+
     pub fn with_custom_handler<CH: 'static>(mut self, handler: CH) -> Self
     where
         CH: Fn(&C) -> MockQuerierCustomHandlerResult,